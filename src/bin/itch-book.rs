@@ -0,0 +1,189 @@
+use std::{io::{BufWriter, Write}, process::ExitCode};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, models::{book::{book_builder::BookBuilder, order_book::OrderBook, price_level::PriceLevel}, capture::{capture_filter::CaptureFilter, capture_reader::CaptureReader}, replay_config::ReplayConfig, timestamp::Timestamp}, replay_engine::ReplayEngine, util::{cli_args::CliArgs, format_utils}};
+
+const USAGE: &str = "Usage: itch-book [OPTIONS] (--book <ID> | --symbol <SYMBOL>) [FILE]
+
+Replays a raw length-prefixed message stream, journal, pcap or pcapng capture through the book builder
+and prints the order book as it stood at the requested time.
+Reads standard input when FILE is omitted or '-'.
+
+Options:
+  --book <ID>          The order book id to print
+  --symbol <SYMBOL>    The symbol to print, resolved through the order book directory
+  --at <TIME>          Replay up to and including this time (HH:MM:SS[.fraction]); defaults to the end of the capture
+  --depth <LEVELS>     Print at most this many price levels per side (default 10)
+  --orders             Print every order at each price level instead of aggregated depth
+  -h, --help           Print this help";
+
+const VALUE_OPTIONS: [&str; 4] = ["book", "symbol", "at", "depth"];
+const FLAG_OPTIONS: [&str; 2] = ["orders", "help"];
+const DEFAULT_DEPTH: usize = 10;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => exit_code,
+        Err(error) if error.is_broken_pipe() => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("itch-book: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, DataFeedError> {
+    let cli_args = match CliArgs::parse(std::env::args().skip(1), &VALUE_OPTIONS, &FLAG_OPTIONS) {
+        Ok(cli_args) => cli_args,
+        Err(error) => {
+            eprintln!("itch-book: {error}\n\n{USAGE}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    if cli_args.flag("help") {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let order_book_id = cli_args.parse_value::<u32>("book")?;
+    let symbol = cli_args.value("symbol");
+
+    if order_book_id.is_some() == symbol.is_some() {
+        eprintln!("itch-book: Exactly one of --book or --symbol is required.\n\n{USAGE}");
+        return Ok(ExitCode::from(2));
+    }
+
+    let at = cli_args.parse_value::<Timestamp>("at")?;
+    let depth = cli_args.parse_value::<usize>("depth")?.unwrap_or(DEFAULT_DEPTH);
+
+    let path = match cli_args.positionals() {
+        [] => "-",
+        [path] => path.as_str(),
+        _ => return Err(DataFeedErrorKind::ParseError("At most one input file may be given.".into()).into())
+    };
+
+    let capture_reader = CaptureReader::open(path, CaptureFilter::default())?;
+
+    let mut handler = DataFeedHandler::new();
+    handler.enable_book_builder(BookBuilder::default());
+
+    let mut replay_engine = ReplayEngine::new(
+        capture_reader,
+        handler,
+        ReplayConfig {
+            stop_time: at,
+            ..ReplayConfig::default()
+        }
+    );
+
+    replay_engine.set_decode_error_callback(|error| eprintln!("itch-book: skipping undecodable message: {error}"));
+    replay_engine.run()?;
+
+    let replay_time = replay_engine.current_time();
+    let handler = replay_engine.into_handler();
+    let directory = handler.instrument_directory();
+
+    let instrument = match (order_book_id, symbol) {
+        (Some(order_book_id), _) => directory.instrument(order_book_id),
+        (None, Some(symbol)) => directory.instrument_by_symbol(symbol),
+        (None, None) => None
+    };
+
+    let Some(order_book_id) = order_book_id.or(instrument.map(|instrument| instrument.order_book_id)) else {
+        return Err(DataFeedErrorKind::Other(format!("The symbol '{}' was not found in the order book directory.", symbol.unwrap_or_default())).into());
+    };
+
+    let number_of_decimals = instrument.map_or(0, |instrument| instrument.number_of_decimals_in_price);
+    let format_price = |price: i32| format_utils::format_price(price, number_of_decimals);
+
+    let mut output = BufWriter::new(std::io::stdout().lock());
+
+    match instrument {
+        Some(instrument) => writeln!(output, "{} (order book {order_book_id})", instrument.symbol)?,
+        None => writeln!(output, "Order book {order_book_id}")?
+    }
+
+    match replay_time {
+        Some(replay_time) => writeln!(output, "As of:          {replay_time}")?,
+        None => writeln!(output, "As of:          (no messages replayed)")?
+    }
+
+    let Some(book) = handler.book_builder().and_then(|book_builder| book_builder.book(order_book_id)) else {
+        writeln!(output, "No activity for this order book has been replayed.")?;
+        output.flush()?;
+
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    writeln!(output, "Trading state:  {}", book.trading_state().unwrap_or("-"))?;
+
+    match book.last_trade() {
+        Some(trade) => writeln!(output, "Last trade:     {} x {} at {}", format_price(trade.price), trade.quantity, trade.timestamp)?,
+        None => writeln!(output, "Last trade:     -")?
+    }
+
+    writeln!(output)?;
+
+    if cli_args.flag("orders") {
+        write_orders(&mut output, &book, 'S', "Asks", depth, &format_price)?;
+        write_orders(&mut output, &book, 'B', "Bids", depth, &format_price)?;
+    } else {
+        write_depth(&mut output, &book, depth, &format_price)?;
+    }
+
+    output.flush()?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn write_depth<W: Write, F: Fn(i32) -> String>(output: &mut W, book: &OrderBook, depth: usize, format_price: &F) -> Result<(), DataFeedError> {
+    writeln!(output, "{:>8}  {:>12}  {:>12}  |  {:<12}  {:<12}  Orders", "Orders", "Bid qty", "Bid", "Ask", "Ask qty")?;
+
+    let mut bids = book.bids().take(depth);
+    let mut asks = book.asks().take(depth);
+
+    loop {
+        let (bid, ask) = (bids.next(), asks.next());
+
+        if bid.is_none() && ask.is_none() {
+            break;
+        }
+
+        let [bid_orders, bid_quantity, bid_price] = level_columns(bid, format_price);
+        let [ask_orders, ask_quantity, ask_price] = level_columns(ask, format_price);
+
+        let row = format!("{bid_orders:>8}  {bid_quantity:>12}  {bid_price:>12}  |  {ask_price:<12}  {ask_quantity:<12}  {ask_orders}");
+        writeln!(output, "{}", row.trim_end())?;
+    }
+
+    Ok(())
+}
+
+fn write_orders<W: Write, F: Fn(i32) -> String>(output: &mut W, book: &OrderBook, side: char, title: &str, depth: usize, format_price: &F) -> Result<(), DataFeedError> {
+    writeln!(output, "{title}")?;
+
+    let levels = if side == 'B' { book.bids().take(depth).collect::<Vec<_>>() } else { book.asks().take(depth).collect::<Vec<_>>() };
+
+    if levels.is_empty() {
+        writeln!(output, "  (empty)")?;
+    }
+
+    for level in levels {
+        writeln!(output, "  {:>12}  {:>12} in {} orders", format_price(level.price), level.quantity, level.order_count)?;
+
+        for (queue_position, order) in book.orders(side, level.price).enumerate() {
+            writeln!(output, "      {:>4}. order {:<20}  {:>12}", queue_position + 1, order.order_id, order.quantity)?;
+        }
+    }
+
+    writeln!(output)?;
+
+    Ok(())
+}
+
+fn level_columns<F: Fn(i32) -> String>(level: Option<PriceLevel>, format_price: &F) -> [String; 3] {
+    match level {
+        Some(level) => [level.order_count.to_string(), level.quantity.to_string(), format_price(level.price)],
+        None => [String::new(), String::new(), String::new()]
+    }
+}
\ No newline at end of file
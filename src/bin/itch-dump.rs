@@ -0,0 +1,177 @@
+use std::{io::{BufWriter, Write}, process::ExitCode};
+
+use asx_itch_data_feed_handler::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, json_value::JsonValue}, models::{capture::{capture_filter::CaptureFilter, capture_reader::CaptureReader, captured_message::CapturedMessage}, field_validator::FieldValidator, message_decoder_registry::MessageDecoderRegistry, timestamp::Timestamp, validation_policy::ValidationPolicy}, traits::json::TJson, util::{cli_args::CliArgs, text_format}};
+
+const USAGE: &str = "Usage: itch-dump [OPTIONS] [FILE]
+
+Decodes a raw length-prefixed message stream, journal, pcap or pcapng capture and prints every message.
+Reads standard input when FILE is omitted or '-'.
+
+Options:
+  --type <TYPES>       Only print these message types, e.g. A,E,T or add_order_no_participant_id
+  --book <IDS>         Only print messages for these order book ids (messages without one are kept)
+  --from <TIME>        Only print messages at or after this time (HH:MM:SS[.fraction])
+  --to <TIME>          Only print messages at or before this time (HH:MM:SS[.fraction])
+  --format <FORMAT>    Output format: text (default) or json
+  --hex                Print the message bytes in hex below each decoded message
+  --validation <POLICY>
+                       strict (default), lenient or off, optionally per category, e.g.
+                       lenient,side=strict. Lenient values are decoded and reported as warnings
+  -h, --help           Print this help";
+
+const VALUE_OPTIONS: [&str; 6] = ["type", "book", "from", "to", "format", "validation"];
+const FLAG_OPTIONS: [&str; 2] = ["hex", "help"];
+
+struct DumpOptions {
+    message_types: Vec<char>,
+    order_book_ids: Vec<u32>,
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+    is_json: bool,
+    is_hex: bool
+}
+
+impl DumpOptions {
+    fn accepts(&self, message: &ItchMessage, message_time: Timestamp) -> bool {
+        (self.message_types.is_empty() || self.message_types.contains(&message.message_type()))
+            && message.order_book_id().is_none_or(|order_book_id| self.order_book_ids.is_empty() || self.order_book_ids.contains(&order_book_id))
+            && self.from.is_none_or(|from| message_time >= from)
+            && self.to.is_none_or(|to| message_time <= to)
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => exit_code,
+        Err(error) if error.is_broken_pipe() => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("itch-dump: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, DataFeedError> {
+    let cli_args = match CliArgs::parse(std::env::args().skip(1), &VALUE_OPTIONS, &FLAG_OPTIONS) {
+        Ok(cli_args) => cli_args,
+        Err(error) => {
+            eprintln!("itch-dump: {error}\n\n{USAGE}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    if cli_args.flag("help") {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let options = DumpOptions {
+        message_types: cli_args
+            .values("type")
+            .into_iter()
+            .map(|value| ItchMessage::parse_message_type(value).map(char::from))
+            .collect::<Result<_, _>>()?,
+        order_book_ids: cli_args.parse_values("book")?,
+        from: cli_args.parse_value("from")?,
+        to: cli_args.parse_value("to")?,
+        is_json: match cli_args.value("format").unwrap_or("text") {
+            "text" => false,
+            "json" => true,
+            format => return Err(DataFeedErrorKind::ParseError(format!("Unknown output format: {format}.")).into())
+        },
+        is_hex: cli_args.flag("hex")
+    };
+
+    let path = match cli_args.positionals() {
+        [] => "-",
+        [path] => path.as_str(),
+        _ => return Err(DataFeedErrorKind::ParseError("At most one input file may be given.".into()).into())
+    };
+
+    let validation_policy = cli_args.parse_value::<ValidationPolicy>("validation")?.unwrap_or_default();
+    let mut validation_warnings = Vec::new();
+    let message_decoders = MessageDecoderRegistry::new();
+
+    let mut capture_reader = CaptureReader::open(path, CaptureFilter::default())?;
+    let mut output = BufWriter::new(std::io::stdout().lock());
+
+    let mut seconds = 0;
+    let mut decoded_count = 0u64;
+    let mut printed_count = 0u64;
+    let mut decode_error_count = 0u64;
+    let mut validation_warning_count = 0u64;
+
+    while let Some(captured_message) = capture_reader.next_message()? {
+        let decode_result = captured_message.decode_with(&mut FieldValidator::new(&validation_policy, &mut validation_warnings), &message_decoders);
+
+        if !validation_warnings.is_empty() {
+            output.flush()?;
+        }
+
+        for warning in validation_warnings.drain(..) {
+            validation_warning_count += 1;
+            eprintln!("itch-dump: validation warning: {}", captured_message.with_position(warning));
+        }
+
+        let message = match decode_result {
+            Ok(message) => message,
+            Err(error) => {
+                decode_error_count += 1;
+                output.flush()?;
+                eprintln!("itch-dump: decode error: {error}");
+                continue;
+            }
+        };
+
+        decoded_count += 1;
+
+        if let ItchMessage::Seconds(seconds_message) = &message {
+            seconds = seconds_message.second.value as u32;
+        }
+
+        let message_time = Timestamp::new(seconds, message.nanoseconds().unwrap_or(0));
+
+        if !options.accepts(&message, message_time) {
+            continue;
+        }
+
+        print_message(&mut output, &options, &captured_message, &message, message_time)?;
+        printed_count += 1;
+    }
+
+    output.flush()?;
+    eprintln!("itch-dump: {decoded_count} messages decoded from {} input, {printed_count} printed, {decode_error_count} decode errors, {validation_warning_count} validation warnings", capture_reader.format());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_message<W: Write>(output: &mut W, options: &DumpOptions, captured_message: &CapturedMessage, message: &ItchMessage, message_time: Timestamp) -> Result<(), DataFeedError> {
+    if options.is_json {
+        let line = JsonValue::Object(
+            vec![
+                ("byte_offset".into(), JsonValue::Number(captured_message.byte_offset.to_string())),
+                ("sequence_number".into(), JsonValue::Number(captured_message.sequence_number.to_string())),
+                ("time".into(), JsonValue::String(message_time.to_string())),
+                ("message".into(), message.to_json()?)
+            ]
+        );
+
+        writeln!(output, "{line}")?;
+    } else {
+        writeln!(output, "{:>10}  {message_time}  {}", captured_message.byte_offset, text_format::format_message(message)?)?;
+    }
+
+    if options.is_hex {
+        for (chunk_index, chunk) in captured_message.bytes.chunks(16).enumerate() {
+            let hex = chunk
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(output, "{:>10}  {hex}", format!("+{:x}", chunk_index * 16))?;
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use std::process::ExitCode;
+
+use asx_itch_data_feed_handler::{enums::{capture_file_format::CaptureFileFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, models::{capture::{capture_filter::CaptureFilter, capture_reader::CaptureReader, capture_writer::CaptureWriter, pcap_writer_config::PcapWriterConfig}, subscription_filter::SubscriptionFilter, timestamp::Timestamp}, util::cli_args::CliArgs};
+
+const USAGE: &str = "Usage: itch-filter [OPTIONS] --output <FILE> [FILE]
+
+Copies the matching messages of a raw length-prefixed message stream, journal, pcap or pcapng capture
+into a new capture. Seconds, system event, directory and tick size messages are always kept so the
+output can be replayed on its own.
+Reads standard input when FILE is omitted or '-'.
+
+Options:
+  --output <FILE>      The capture to write, or '-' for standard output
+  --output-format <F>  raw, journal or pcap; defaults to the input format
+  --book <IDS>         Only keep messages for these order book ids
+  --symbol <SYMBOLS>   Only keep messages for these symbols, resolved through the order book directory
+  --type <TYPES>       Only keep these message types, e.g. A,E,D or order_delete
+  --from <TIME>        Only keep messages at or after this time (HH:MM:SS[.fraction])
+  --to <TIME>          Only keep messages at or before this time (HH:MM:SS[.fraction])
+  -h, --help           Print this help";
+
+const VALUE_OPTIONS: [&str; 7] = ["output", "output-format", "book", "symbol", "type", "from", "to"];
+const FLAG_OPTIONS: [&str; 1] = ["help"];
+const ALWAYS_KEPT_MESSAGE_TYPES: [u8; 5] = [b'T', b'S', b'R', b'M', b'L'];
+
+struct SliceOptions {
+    subscription_filter: Option<SubscriptionFilter>,
+    message_types: Vec<u8>,
+    from: Option<Timestamp>,
+    to: Option<Timestamp>
+}
+
+impl SliceOptions {
+    fn accepts(&mut self, message: &ItchMessage, message_time: Timestamp) -> bool {
+        let message_type = message.message_type() as u8;
+
+        if let Some(subscription_filter) = self.subscription_filter.as_mut() {
+            subscription_filter.process_directory_message(message);
+        }
+
+        if ALWAYS_KEPT_MESSAGE_TYPES.contains(&message_type) {
+            return true;
+        }
+
+        (self.message_types.is_empty() || self.message_types.contains(&message_type))
+            && self.subscription_filter.as_ref().is_none_or(|subscription_filter| message.order_book_id().is_some() && subscription_filter.accepts(message))
+            && self.from.is_none_or(|from| message_time >= from)
+            && self.to.is_none_or(|to| message_time <= to)
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("itch-filter: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, DataFeedError> {
+    let cli_args = match CliArgs::parse(std::env::args().skip(1), &VALUE_OPTIONS, &FLAG_OPTIONS) {
+        Ok(cli_args) => cli_args,
+        Err(error) => {
+            eprintln!("itch-filter: {error}\n\n{USAGE}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    if cli_args.flag("help") {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let Some(output_path) = cli_args.value("output") else {
+        eprintln!("itch-filter: The --output option is required.\n\n{USAGE}");
+        return Ok(ExitCode::from(2));
+    };
+
+    let order_book_ids = cli_args.parse_values::<u32>("book")?;
+    let symbols = cli_args.values("symbol");
+
+    let subscription_filter = (!order_book_ids.is_empty() || !symbols.is_empty()).then(|| {
+        let mut subscription_filter = SubscriptionFilter::new();
+        order_book_ids.iter().for_each(|order_book_id| subscription_filter.subscribe_order_book_id(*order_book_id));
+        symbols.iter().for_each(|symbol| subscription_filter.subscribe_symbol(symbol));
+
+        subscription_filter
+    });
+
+    let mut options = SliceOptions {
+        subscription_filter,
+        message_types: cli_args
+            .values("type")
+            .into_iter()
+            .map(ItchMessage::parse_message_type)
+            .collect::<Result<_, _>>()?,
+        from: cli_args.parse_value("from")?,
+        to: cli_args.parse_value("to")?
+    };
+
+    let input_path = match cli_args.positionals() {
+        [] => "-",
+        [path] => path.as_str(),
+        _ => return Err(DataFeedErrorKind::ParseError("At most one input file may be given.".into()).into())
+    };
+
+    let mut capture_reader = CaptureReader::open(input_path, CaptureFilter::default())?;
+    let input_format = capture_reader.format();
+    let output_format = cli_args.parse_value::<CaptureFileFormat>("output-format")?.unwrap_or(input_format);
+    let mut capture_writer = CaptureWriter::create(output_path, output_format, PcapWriterConfig::default())?;
+
+    let mut seconds = 0;
+    let mut read_count = 0u64;
+    let mut decode_error_count = 0u64;
+
+    while let Some(captured_message) = capture_reader.next_message()? {
+        read_count += 1;
+
+        let message = match captured_message.decode() {
+            Ok(message) => message,
+            Err(error) => {
+                decode_error_count += 1;
+                eprintln!("itch-filter: dropping undecodable message: {error}");
+                continue;
+            }
+        };
+
+        if let ItchMessage::Seconds(seconds_message) = &message {
+            seconds = seconds_message.second.value as u32;
+        }
+
+        let message_time = Timestamp::new(seconds, message.nanoseconds().unwrap_or(0));
+
+        if !options.accepts(&message, message_time) {
+            continue;
+        }
+
+        let capture_timestamp = match input_format {
+            CaptureFileFormat::Raw => message_time.as_nanoseconds(),
+            CaptureFileFormat::Journal | CaptureFileFormat::Pcap => captured_message.capture_timestamp
+        };
+
+        capture_writer.write(capture_timestamp, captured_message.sequence_number, &captured_message.bytes)?;
+    }
+
+    let written_count = capture_writer.message_count();
+    capture_writer.finish()?;
+
+    eprintln!("itch-filter: kept {written_count} of {read_count} messages from {input_format} input as {output_format} output, {decode_error_count} decode errors");
+
+    Ok(ExitCode::SUCCESS)
+}
\ No newline at end of file
@@ -0,0 +1,269 @@
+use std::{fs::File, io::{self, BufRead, BufReader}, net::{Ipv4Addr, SocketAddrV4}, process::ExitCode, thread, time::{Duration, Instant}};
+
+use asx_itch_data_feed_handler::{enums::{capture_file_format::CaptureFileFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, order_instruction::OrderInstruction}, exchange_simulator::ExchangeSimulator, models::{capture::{capture_writer::CaptureWriter, mold_udp64_publisher::MoldUdp64Publisher, mold_udp64_publisher_config::MoldUdp64PublisherConfig, pcap_writer_config::PcapWriterConfig}, simulator::{random_order_flow::RandomOrderFlow, random_order_flow_config::RandomOrderFlowConfig, simulated_instrument::SimulatedInstrument}, timestamp::Timestamp}, util::{cli_args::CliArgs, simulator_script}};
+
+const USAGE: &str = "Usage: itch-sim (--script <FILE> | --random) [OPTIONS]
+
+Runs a price-time-priority matching engine per instrument and emits the resulting ITCH feed to a
+capture file, to UDP multicast as MoldUDP64 packets, or both.
+
+Script lines have the form '<time> <command> key=value...'; '#' starts a comment:
+  instrument book=<ID> symbol=<SYMBOL> [long_name= isin= product= currency= decimals= lot=]
+  system code=<O|C>
+  state book=<ID> name=<STATE>
+  add book=<ID> side=<B|S> qty=<QTY> px=<PRICE> [oid=<ID>] [participant=<ID>]
+  replace oid=<ID> qty=<QTY> px=<PRICE>
+  cancel oid=<ID>
+  trade book=<ID> qty=<QTY> px=<PRICE>
+
+Options:
+  --script <FILE>          Replay the instructions in FILE, or '-' for standard input
+  --random                 Generate random order flow with an opening auction
+  --instruments <N>        Number of random instruments, SIM1 to SIMN (default 3)
+  --count <N>              Number of random instructions (default 10000)
+  --seed <N>               Random seed (default 1)
+  --start <TIME>           Random session start time (default 10:00:00)
+  --output <FILE>          Write the feed to FILE, or '-' for standard output
+  --output-format <F>      raw, journal or pcap (default raw)
+  --multicast <ADDR:PORT>  Publish the feed as MoldUDP64 packets to this address
+  --interface <ADDR>       Local interface address to publish from
+  --ttl <N>                Multicast time to live (default 1)
+  --speed <FACTOR>         Pace multicast output at this multiple of feed time, 0 for no pacing (default 0)
+  -h, --help               Print this help";
+
+const VALUE_OPTIONS: [&str; 11] = ["script", "instruments", "count", "seed", "start", "output", "output-format", "multicast", "interface", "ttl", "speed"];
+const FLAG_OPTIONS: [&str; 2] = ["random", "help"];
+const RANDOM_PRE_OPEN_SHARE: u64 = 10;
+
+struct FeedSink {
+    capture_writer: Option<CaptureWriter>,
+    publisher: Option<MoldUdp64Publisher>,
+    speed: f64,
+    pacing_origin: Option<(u64, Instant)>,
+    message_count: u64
+}
+
+impl FeedSink {
+    fn emit(&mut self, messages: Vec<(Timestamp, ItchMessage)>) -> Result<(), DataFeedError> {
+        for (time, message) in messages {
+            let binary_data = message.to_bin()?;
+            self.message_count += 1;
+
+            if let Some(capture_writer) = self.capture_writer.as_mut() {
+                capture_writer.write(time.as_nanoseconds(), self.message_count, &binary_data)?;
+            }
+
+            if self.publisher.is_some() {
+                self.pace(time)?;
+            }
+
+            if let Some(publisher) = self.publisher.as_mut() {
+                publisher.publish_bytes(&binary_data)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pace(&mut self, time: Timestamp) -> Result<(), DataFeedError> {
+        if self.speed <= 0.0 {
+            return Ok(());
+        }
+
+        let (origin_time, origin_instant) = *self.pacing_origin.get_or_insert((time.as_nanoseconds(), Instant::now()));
+        let feed_elapsed = time.as_nanoseconds().saturating_sub(origin_time) as f64 / self.speed;
+        let due = origin_instant + Duration::from_nanos(feed_elapsed as u64);
+        let now = Instant::now();
+
+        if due > now {
+            if let Some(publisher) = self.publisher.as_mut() {
+                publisher.flush()?;
+            }
+
+            thread::sleep(due - now);
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), DataFeedError> {
+        if let Some(capture_writer) = self.capture_writer {
+            capture_writer.finish()?;
+        }
+
+        if let Some(mut publisher) = self.publisher {
+            publisher.end_of_session()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("itch-sim: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, DataFeedError> {
+    let cli_args = match CliArgs::parse(std::env::args().skip(1), &VALUE_OPTIONS, &FLAG_OPTIONS) {
+        Ok(cli_args) => cli_args,
+        Err(error) => {
+            eprintln!("itch-sim: {error}\n\n{USAGE}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    if cli_args.flag("help") {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let script_path = cli_args.value("script");
+
+    if script_path.is_some() == cli_args.flag("random") {
+        eprintln!("itch-sim: Exactly one of --script and --random must be given.\n\n{USAGE}");
+        return Ok(ExitCode::from(2));
+    }
+
+    let multicast = cli_args.parse_value::<SocketAddrV4>("multicast")?;
+
+    if cli_args.value("output").is_none() && multicast.is_none() {
+        eprintln!("itch-sim: At least one of --output and --multicast must be given.\n\n{USAGE}");
+        return Ok(ExitCode::from(2));
+    }
+
+    let capture_writer = cli_args
+        .value("output")
+        .map(|output_path| CaptureWriter::create(output_path, cli_args.parse_value::<CaptureFileFormat>("output-format")?.unwrap_or_default(), PcapWriterConfig::default()))
+        .transpose()?;
+
+    let publisher = multicast
+        .map(|destination| -> Result<MoldUdp64Publisher, DataFeedError> {
+            let default_config = MoldUdp64PublisherConfig::default();
+
+            MoldUdp64Publisher::new(
+                MoldUdp64PublisherConfig {
+                    destination,
+                    interface: cli_args.parse_value::<Ipv4Addr>("interface")?.unwrap_or(default_config.interface),
+                    time_to_live: cli_args.parse_value("ttl")?.unwrap_or(default_config.time_to_live),
+                    ..default_config
+                }
+            )
+        })
+        .transpose()?;
+
+    let mut sink = FeedSink {
+        capture_writer,
+        publisher,
+        speed: cli_args.parse_value("speed")?.unwrap_or(0.0),
+        pacing_origin: None,
+        message_count: 0
+    };
+
+    let mut simulator = ExchangeSimulator::new();
+
+    match script_path {
+        Some(script_path) => run_script(script_path, &mut simulator, &mut sink)?,
+        None => run_random(&cli_args, &mut simulator, &mut sink)?
+    }
+
+    let message_count = sink.message_count;
+    sink.finish()?;
+
+    eprintln!("itch-sim: emitted {message_count} messages for {} instruments", simulator.engines().count());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_script(script_path: &str, simulator: &mut ExchangeSimulator, sink: &mut FeedSink) -> Result<(), DataFeedError> {
+    let reader: Box<dyn BufRead> = match script_path {
+        "-" => Box::new(BufReader::new(io::stdin())),
+        _ => Box::new(BufReader::new(File::open(script_path)?))
+    };
+
+    for (line_index, line) in reader.lines().enumerate() {
+        let line_number = line_index + 1;
+
+        let Some((time, instruction)) = simulator_script::parse_instruction(&line?).map_err(|error| DataFeedErrorKind::ParseError(format!("Line {line_number}: {error}")))? else {
+            continue;
+        };
+
+        if let Err(error) = simulator.submit(time, instruction) {
+            eprintln!("itch-sim: line {line_number} was rejected: {error}");
+        }
+
+        sink.emit(simulator.drain_messages())?;
+    }
+
+    Ok(())
+}
+
+fn run_random(cli_args: &CliArgs, simulator: &mut ExchangeSimulator, sink: &mut FeedSink) -> Result<(), DataFeedError> {
+    let instrument_count = cli_args.parse_value::<u32>("instruments")?.unwrap_or(3).max(1);
+    let instruction_count = cli_args.parse_value::<u64>("count")?.unwrap_or(10_000);
+    let start_time = cli_args.parse_value::<Timestamp>("start")?.unwrap_or(Timestamp::new(36_000, 0));
+    let order_book_ids = (1..=instrument_count).collect::<Vec<_>>();
+
+    let mut random_order_flow = RandomOrderFlow::new(
+        RandomOrderFlowConfig {
+            seed: cli_args.parse_value("seed")?.unwrap_or(RandomOrderFlowConfig::default().seed),
+            ..RandomOrderFlowConfig::default()
+        },
+        order_book_ids.clone(),
+        start_time
+    );
+
+    submit(simulator, sink, start_time, OrderInstruction::SystemEvent('O'))?;
+
+    for order_book_id in &order_book_ids {
+        submit(simulator, sink, start_time, OrderInstruction::AddInstrument(SimulatedInstrument::new(*order_book_id, &format!("SIM{order_book_id}"))))?;
+    }
+
+    set_trading_states(simulator, sink, &order_book_ids, "PRE_OPEN")?;
+
+    for instruction_index in 0..instruction_count {
+        if instruction_index == instruction_count / RANDOM_PRE_OPEN_SHARE {
+            set_trading_states(simulator, sink, &order_book_ids, "OPEN")?;
+        }
+
+        let (time, instruction) = random_order_flow.next_instruction(simulator);
+        submit(simulator, sink, time, instruction)?;
+    }
+
+    if instruction_count == 0 {
+        set_trading_states(simulator, sink, &order_book_ids, "OPEN")?;
+    }
+
+    set_trading_states(simulator, sink, &order_book_ids, "CLOSED")?;
+
+    let end_time = simulator.current_time().unwrap_or(start_time);
+    submit(simulator, sink, end_time, OrderInstruction::SystemEvent('C'))
+}
+
+fn submit(simulator: &mut ExchangeSimulator, sink: &mut FeedSink, time: Timestamp, instruction: OrderInstruction) -> Result<(), DataFeedError> {
+    simulator.submit(time, instruction)?;
+    sink.emit(simulator.drain_messages())
+}
+
+fn set_trading_states(simulator: &mut ExchangeSimulator, sink: &mut FeedSink, order_book_ids: &[u32], state_name: &str) -> Result<(), DataFeedError> {
+    let time = simulator.current_time().unwrap_or_default();
+
+    for order_book_id in order_book_ids {
+        submit(
+            simulator,
+            sink,
+            time,
+            OrderInstruction::SetTradingState {
+                order_book_id: *order_book_id,
+                state_name: state_name.into()
+            }
+        )?;
+    }
+
+    Ok(())
+}
\ No newline at end of file
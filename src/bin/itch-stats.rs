@@ -0,0 +1,316 @@
+use std::{io::{BufWriter, Write}, process::ExitCode};
+
+use asx_itch_data_feed_handler::{enums::{capture_file_format::CaptureFileFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, json_value::JsonValue}, models::{capture::{capture_filter::CaptureFilter, capture_reader::CaptureReader}, directory::instrument_directory::InstrumentDirectory, feed_statistics::FeedStatistics, order_book_statistics::OrderBookStatistics, timestamp::Timestamp}, util::cli_args::CliArgs};
+
+const USAGE: &str = "Usage: itch-stats [OPTIONS] [FILE]
+
+Scans a raw length-prefixed message stream, journal, pcap or pcapng capture and reports feed statistics.
+Reads standard input when FILE is omitted or '-'.
+
+Options:
+  --format <FORMAT>    Output format: table (default) or json
+  --top <COUNT>        Only list the COUNT busiest order books in the table
+  --per-second         Include the message count for every second
+  -h, --help           Print this help";
+
+const VALUE_OPTIONS: [&str; 2] = ["format", "top"];
+const FLAG_OPTIONS: [&str; 2] = ["per-second", "help"];
+
+struct StatsReport<'a> {
+    statistics: &'a FeedStatistics,
+    directory: &'a InstrumentDirectory,
+    format: CaptureFileFormat,
+    top: Option<usize>,
+    is_per_second: bool
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => exit_code,
+        Err(error) if error.is_broken_pipe() => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("itch-stats: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, DataFeedError> {
+    let cli_args = match CliArgs::parse(std::env::args().skip(1), &VALUE_OPTIONS, &FLAG_OPTIONS) {
+        Ok(cli_args) => cli_args,
+        Err(error) => {
+            eprintln!("itch-stats: {error}\n\n{USAGE}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    if cli_args.flag("help") {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let is_json = match cli_args.value("format").unwrap_or("table") {
+        "table" => false,
+        "json" => true,
+        format => return Err(DataFeedErrorKind::ParseError(format!("Unknown output format: {format}.")).into())
+    };
+
+    let path = match cli_args.positionals() {
+        [] => "-",
+        [path] => path.as_str(),
+        _ => return Err(DataFeedErrorKind::ParseError("At most one input file may be given.".into()).into())
+    };
+
+    let mut capture_reader = CaptureReader::open(path, CaptureFilter::default())?;
+    let mut statistics = FeedStatistics::new();
+    let mut directory = InstrumentDirectory::new();
+
+    while let Some(captured_message) = capture_reader.next_message()? {
+        match captured_message.decode() {
+            Ok(message) => {
+                directory.process_message(&message);
+                statistics.record(&message);
+            },
+            Err(error) => statistics.record_decode_error(&error)
+        }
+    }
+
+    let report = StatsReport {
+        statistics: &statistics,
+        directory: &directory,
+        format: capture_reader.format(),
+        top: cli_args.parse_value("top")?,
+        is_per_second: cli_args.flag("per-second")
+    };
+
+    let mut output = BufWriter::new(std::io::stdout().lock());
+
+    if is_json {
+        writeln!(output, "{}", report.to_json())?;
+    } else {
+        report.write_table(&mut output)?;
+    }
+
+    output.flush()?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+impl StatsReport<'_> {
+    fn write_table<W: Write>(&self, output: &mut W) -> Result<(), DataFeedError> {
+        let statistics = self.statistics;
+
+        writeln!(output, "Input:             {} ({} messages, {} decode errors)", self.format, statistics.message_count, statistics.decode_error_count())?;
+
+        if let (Some(first_time), Some(last_time)) = (statistics.first_time, statistics.last_time) {
+            writeln!(output, "Time range:        {first_time} - {last_time}")?;
+        }
+
+        writeln!(output, "Average rate:      {:.1} messages per active second", statistics.average_messages_per_second())?;
+
+        if let Some((second, message_count)) = statistics.peak_second() {
+            writeln!(output, "Peak second:       {message_count} messages at {}", format_second(second))?;
+        }
+
+        if let Some(peak_millisecond) = statistics.peak_millisecond {
+            writeln!(output, "Peak millisecond:  {} messages at {}", statistics.peak_millisecond_message_count, &peak_millisecond.to_string()[..12])?;
+        }
+
+        writeln!(output, "\nMessage types")?;
+        writeln!(output, "  {:<4}  {:<40}  {:>12}", "Type", "Name", "Count")?;
+
+        for (message_type, message_count) in statistics.message_counts_by_type.iter() {
+            writeln!(output, "  {:<4}  {:<40}  {:>12}", message_type, ItchMessage::name_for_type(*message_type as u8).unwrap_or("unknown"), message_count)?;
+        }
+
+        if !statistics.decode_error_counts.is_empty() {
+            writeln!(output, "\nDecode errors")?;
+
+            for (variant_name, error_count) in statistics.decode_error_counts.iter() {
+                writeln!(output, "  {variant_name:<46}  {error_count:>12}")?;
+            }
+        }
+
+        if !statistics.decode_error_counts_by_field.is_empty() {
+            writeln!(output, "\nDecode errors by field")?;
+
+            for (field_name, error_count) in statistics.decode_error_counts_by_field.iter() {
+                writeln!(output, "  {field_name:<46}  {error_count:>12}")?;
+            }
+        }
+
+        writeln!(output, "\nOrder books")?;
+        writeln!(output, "  {:>10}  {:<12}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>14}  {:>12}", "Book", "Symbol", "Messages", "Adds", "Replaces", "Cancels", "Trades", "Traded qty", "Cancel/trade")?;
+
+        for (order_book_id, order_book_statistics) in self.busiest_order_books() {
+            let symbol = self.directory
+                .instrument(order_book_id)
+                .map_or("", |instrument| instrument.symbol.as_str());
+
+            write_order_book_row(output, &order_book_id.to_string(), symbol, &order_book_statistics)?;
+        }
+
+        write_order_book_row(output, "Total", "", &statistics.totals())?;
+
+        if self.is_per_second {
+            writeln!(output, "\nMessages per second")?;
+
+            for (second, message_count) in statistics.message_counts_by_second.iter() {
+                writeln!(output, "  {}  {message_count:>12}", format_second(*second))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let statistics = self.statistics;
+
+        let mut members = vec![
+            ("input_format".into(), JsonValue::String(self.format.to_string())),
+            ("message_count".into(), json_number(statistics.message_count)),
+            ("decode_error_count".into(), json_number(statistics.decode_error_count())),
+            ("first_time".into(), statistics.first_time.map_or(JsonValue::Null, |first_time| JsonValue::String(first_time.to_string()))),
+            ("last_time".into(), statistics.last_time.map_or(JsonValue::Null, |last_time| JsonValue::String(last_time.to_string()))),
+            ("average_messages_per_second".into(), JsonValue::Number(format!("{:.3}", statistics.average_messages_per_second()))),
+            ("peak_second".into(), json_peak(statistics.peak_second().map(|(second, message_count)| (Timestamp::new(second, 0), message_count)))),
+            ("peak_millisecond".into(), json_peak(statistics.peak_millisecond.map(|peak_millisecond| (peak_millisecond, statistics.peak_millisecond_message_count)))),
+            (
+                "message_counts_by_type".into(),
+                JsonValue::Object(
+                    statistics.message_counts_by_type
+                        .iter()
+                        .map(|(message_type, message_count)| (message_type.to_string(), json_number(*message_count)))
+                        .collect()
+                )
+            ),
+            (
+                "decode_error_counts".into(),
+                JsonValue::Object(
+                    statistics.decode_error_counts
+                        .iter()
+                        .map(|(variant_name, error_count)| (variant_name.to_string(), json_number(*error_count)))
+                        .collect()
+                )
+            ),
+            (
+                "decode_error_counts_by_field".into(),
+                JsonValue::Object(
+                    statistics.decode_error_counts_by_field
+                        .iter()
+                        .map(|(field_name, error_count)| (field_name.to_string(), json_number(*error_count)))
+                        .collect()
+                )
+            ),
+            (
+                "order_books".into(),
+                JsonValue::Array(
+                    self.busiest_order_books()
+                        .into_iter()
+                        .map(|(order_book_id, order_book_statistics)| {
+                            let symbol = self.directory
+                                .instrument(order_book_id)
+                                .map_or(JsonValue::Null, |instrument| JsonValue::String(instrument.symbol.clone()));
+
+                            let mut members = vec![
+                                ("order_book_id".into(), json_number(order_book_id as u64)),
+                                ("symbol".into(), symbol)
+                            ];
+
+                            members.extend(json_order_book_members(&order_book_statistics));
+                            JsonValue::Object(members)
+                        })
+                        .collect()
+                )
+            ),
+            ("totals".into(), JsonValue::Object(json_order_book_members(&statistics.totals())))
+        ];
+
+        if self.is_per_second {
+            members.push(
+                (
+                    "message_counts_by_second".into(),
+                    JsonValue::Object(
+                        statistics.message_counts_by_second
+                            .iter()
+                            .map(|(second, message_count)| (format_second(*second), json_number(*message_count)))
+                            .collect()
+                    )
+                )
+            );
+        }
+
+        JsonValue::Object(members)
+    }
+
+    fn busiest_order_books(&self) -> Vec<(u32, OrderBookStatistics)> {
+        let mut order_books = self.statistics.order_book_statistics
+            .iter()
+            .map(|(order_book_id, order_book_statistics)| (*order_book_id, *order_book_statistics))
+            .collect::<Vec<_>>();
+
+        order_books.sort_by(|(left_id, left), (right_id, right)| right.message_count.cmp(&left.message_count).then(left_id.cmp(right_id)));
+        order_books.truncate(self.top.unwrap_or(usize::MAX));
+
+        order_books
+    }
+}
+
+fn write_order_book_row<W: Write>(output: &mut W, label: &str, symbol: &str, order_book_statistics: &OrderBookStatistics) -> Result<(), DataFeedError> {
+    let cancel_to_trade_ratio = order_book_statistics
+        .cancel_to_trade_ratio()
+        .map_or("-".to_string(), |ratio| format!("{ratio:.2}"));
+
+    writeln!(
+        output,
+        "  {:>10}  {:<12}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>14}  {:>12}",
+        label,
+        symbol,
+        order_book_statistics.message_count,
+        order_book_statistics.add_count,
+        order_book_statistics.replace_count,
+        order_book_statistics.cancel_count,
+        order_book_statistics.trade_count,
+        order_book_statistics.traded_quantity,
+        cancel_to_trade_ratio
+    )?;
+
+    Ok(())
+}
+
+fn json_order_book_members(order_book_statistics: &OrderBookStatistics) -> Vec<(String, JsonValue)> {
+    vec![
+        ("message_count".into(), json_number(order_book_statistics.message_count)),
+        ("add_count".into(), json_number(order_book_statistics.add_count)),
+        ("replace_count".into(), json_number(order_book_statistics.replace_count)),
+        ("cancel_count".into(), json_number(order_book_statistics.cancel_count)),
+        ("trade_count".into(), json_number(order_book_statistics.trade_count)),
+        ("traded_quantity".into(), json_number(order_book_statistics.traded_quantity)),
+        (
+            "cancel_to_trade_ratio".into(),
+            order_book_statistics
+                .cancel_to_trade_ratio()
+                .map_or(JsonValue::Null, |ratio| JsonValue::Number(format!("{ratio:.4}")))
+        )
+    ]
+}
+
+fn json_peak(peak: Option<(Timestamp, u64)>) -> JsonValue {
+    match peak {
+        Some((time, message_count)) => JsonValue::Object(
+            vec![
+                ("time".into(), JsonValue::String(time.to_string())),
+                ("message_count".into(), json_number(message_count))
+            ]
+        ),
+        None => JsonValue::Null
+    }
+}
+
+fn json_number(value: u64) -> JsonValue {
+    JsonValue::Number(value.to_string())
+}
+
+fn format_second(second: u32) -> String {
+    Timestamp::new(second, 0).to_string()[..8].to_string()
+}
\ No newline at end of file
@@ -1,3 +1,445 @@
+use std::{collections::BTreeMap, thread};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, session_state::SessionState}, models::{book::book_builder::BookBuilder, directory::{instrument_directory::InstrumentDirectory, tick_size_tables::TickSizeTables}, custom_message_decoder::CustomMessageDecoder, field_validator::FieldValidator, history::order_history::OrderHistory, integrity::integrity_checker::IntegrityChecker, message_decoder_registry::MessageDecoderRegistry, messages::unknown_message::UnknownMessage, pipeline_config::PipelineConfig, raw_message::RawMessage, ring_buffer_statistics::RingBufferStatistics, session_statistics::SessionStatistics, session_summary::SessionSummary, subscription_filter::SubscriptionFilter, subscription_handle::SubscriptionHandle, timestamp::Timestamp, validation_policy::ValidationPolicy}, traits::message_sink::TMessageSink, util::{spsc_ring_buffer::{self, RingProducer}, thread_affinity}};
+
+type EndOfDayCallback = Box<dyn FnMut(&SessionSummary) + Send>;
+type UnknownMessageCallback = Box<dyn FnMut(&UnknownMessage) + Send>;
+type ValidationWarningCallback = Box<dyn FnMut(&DataFeedError) + Send>;
+
+#[derive(Default)]
 pub struct DataFeedHandler {
-    
+    seconds: u32,
+    book_builder: Option<BookBuilder>,
+    integrity_checker: Option<IntegrityChecker>,
+    order_history: Option<OrderHistory>,
+    subscription_filter: Option<SubscriptionFilter>,
+    subscription_handle: Option<SubscriptionHandle>,
+    subscription_generation: u64,
+    filtered_message_count: u64,
+    instrument_directory: InstrumentDirectory,
+    tick_size_tables: TickSizeTables,
+    session_state: SessionState,
+    session_start: Option<Timestamp>,
+    session_statistics: SessionStatistics,
+    session_summaries: Vec<SessionSummary>,
+    end_of_day_callback: Option<EndOfDayCallback>,
+    outputs: Vec<Box<dyn TMessageSink>>,
+    validation_policy: ValidationPolicy,
+    validation_warnings: Vec<DataFeedError>,
+    validation_warning_counts: BTreeMap<&'static str, u64>,
+    validation_warning_callback: Option<ValidationWarningCallback>,
+    message_decoders: MessageDecoderRegistry,
+    unknown_message_counts: BTreeMap<char, u64>,
+    unknown_message_callback: Option<UnknownMessageCallback>
+}
+
+impl DataFeedHandler {
+    pub fn new() -> Self {
+        DataFeedHandler::default()
+    }
+
+    pub fn enable_book_builder(&mut self, book_builder: BookBuilder) {
+        self.book_builder = Some(book_builder);
+    }
+
+    pub fn book_builder(&self) -> Option<&BookBuilder> {
+        self.book_builder.as_ref()
+    }
+
+    pub fn enable_integrity_checker(&mut self, integrity_checker: IntegrityChecker) {
+        if self.book_builder.is_none() {
+            self.book_builder = Some(BookBuilder::default());
+        }
+
+        self.integrity_checker = Some(integrity_checker);
+    }
+
+    pub fn disable_integrity_checker(&mut self) -> Option<IntegrityChecker> {
+        self.integrity_checker.take()
+    }
+
+    pub fn integrity_checker(&self) -> Option<&IntegrityChecker> {
+        self.integrity_checker.as_ref()
+    }
+
+    pub fn integrity_checker_mut(&mut self) -> Option<&mut IntegrityChecker> {
+        self.integrity_checker.as_mut()
+    }
+
+    pub fn enable_order_history(&mut self, order_history: OrderHistory) {
+        self.order_history = Some(order_history);
+    }
+
+    pub fn disable_order_history(&mut self) -> Option<OrderHistory> {
+        self.order_history.take()
+    }
+
+    pub fn order_history(&self) -> Option<&OrderHistory> {
+        self.order_history.as_ref()
+    }
+
+    pub fn enable_subscription_filter(&mut self, subscription_filter: SubscriptionFilter) -> SubscriptionHandle {
+        let subscription_handle = SubscriptionHandle::new(&subscription_filter);
+        self.subscription_filter = Some(subscription_filter);
+        self.subscription_handle = Some(subscription_handle.clone());
+        self.subscription_generation = subscription_handle.generation();
+
+        subscription_handle
+    }
+
+    pub fn disable_subscription_filter(&mut self) -> Option<SubscriptionFilter> {
+        self.subscription_handle = None;
+        self.subscription_filter.take()
+    }
+
+    pub fn subscription_filter(&self) -> Option<&SubscriptionFilter> {
+        self.subscription_filter.as_ref()
+    }
+
+    pub fn filtered_message_count(&self) -> u64 {
+        self.filtered_message_count
+    }
+
+    pub fn set_validation_policy(&mut self, validation_policy: ValidationPolicy) {
+        self.validation_policy = validation_policy;
+    }
+
+    pub fn validation_policy(&self) -> ValidationPolicy {
+        self.validation_policy
+    }
+
+    pub fn validation_warning_counts(&self) -> &BTreeMap<&'static str, u64> {
+        &self.validation_warning_counts
+    }
+
+    pub fn validation_warning_count(&self) -> u64 {
+        self.validation_warning_counts.values().sum()
+    }
+
+    pub fn set_validation_warning_callback<F>(&mut self, validation_warning_callback: F)
+    where
+        F: FnMut(&DataFeedError) + Send + 'static
+    {
+        self.validation_warning_callback = Some(Box::new(validation_warning_callback));
+    }
+
+    pub fn clear_validation_warning_callback(&mut self) {
+        self.validation_warning_callback = None;
+    }
+
+    pub fn register_message_decoder(&mut self, decoder: CustomMessageDecoder) -> Result<(), DataFeedError> {
+        self.message_decoders.register(decoder)
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn message_decoders_mut(&mut self) -> &mut MessageDecoderRegistry {
+        &mut self.message_decoders
+    }
+
+    pub fn unknown_message_counts(&self) -> &BTreeMap<char, u64> {
+        &self.unknown_message_counts
+    }
+
+    pub fn unknown_message_count(&self) -> u64 {
+        self.unknown_message_counts.values().sum()
+    }
+
+    pub fn set_unknown_message_callback<F>(&mut self, unknown_message_callback: F)
+    where
+        F: FnMut(&UnknownMessage) + Send + 'static
+    {
+        self.unknown_message_callback = Some(Box::new(unknown_message_callback));
+    }
+
+    pub fn clear_unknown_message_callback(&mut self) {
+        self.unknown_message_callback = None;
+    }
+
+    pub fn instrument_directory(&self) -> &InstrumentDirectory {
+        &self.instrument_directory
+    }
+
+    pub fn tick_size_tables(&self) -> &TickSizeTables {
+        &self.tick_size_tables
+    }
+
+    pub fn session_state(&self) -> SessionState {
+        self.session_state
+    }
+
+    pub fn session_statistics(&self) -> &SessionStatistics {
+        &self.session_statistics
+    }
+
+    pub fn session_summaries(&self) -> &[SessionSummary] {
+        &self.session_summaries
+    }
+
+    pub fn drain_session_summaries(&mut self) -> Vec<SessionSummary> {
+        std::mem::take(&mut self.session_summaries)
+    }
+
+    pub fn set_end_of_day_callback<F>(&mut self, end_of_day_callback: F)
+    where
+        F: FnMut(&SessionSummary) + Send + 'static
+    {
+        self.end_of_day_callback = Some(Box::new(end_of_day_callback));
+    }
+
+    pub fn clear_end_of_day_callback(&mut self) {
+        self.end_of_day_callback = None;
+    }
+
+    pub fn add_output<S>(&mut self, output: S) where S: TMessageSink + 'static {
+        self.outputs.push(Box::new(output));
+    }
+
+    pub fn take_outputs(&mut self) -> Vec<Box<dyn TMessageSink>> {
+        std::mem::take(&mut self.outputs)
+    }
+
+    pub fn flush_outputs(&mut self) -> Result<(), DataFeedError> {
+        for output in self.outputs.iter_mut() {
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn timestamp(&self, nanoseconds: u32) -> Timestamp {
+        Timestamp::new(self.seconds, nanoseconds)
+    }
+
+    pub fn process_bin(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        self.apply_subscription_updates();
+
+        if let Some(subscription_filter) = self.subscription_filter.as_ref() {
+            let is_directory_message = binary_data
+                .first()
+                .is_some_and(|message_type| ItchMessage::is_directory_message_type(*message_type));
+
+            if !is_directory_message && !subscription_filter.accepts_bin(binary_data, &self.message_decoders) {
+                self.filtered_message_count += 1;
+                self.session_statistics.record_filtered();
+                return Ok(());
+            }
+        }
+
+        let message = self.decode_bin(binary_data)?;
+
+        self.process_message(&message)
+    }
+
+    pub fn decode_bin(&mut self, binary_data: &[u8]) -> Result<ItchMessage, DataFeedError> {
+        let decode_result = self.message_decoders.decode_with(binary_data, &mut FieldValidator::new(&self.validation_policy, &mut self.validation_warnings));
+
+        for warning in self.validation_warnings.drain(..) {
+            *self.validation_warning_counts.entry(warning.field_name().unwrap_or(warning.variant_name())).or_default() += 1;
+
+            if let Some(validation_warning_callback) = self.validation_warning_callback.as_mut() {
+                validation_warning_callback(&warning);
+            }
+        }
+
+        decode_result
+    }
+
+    pub fn process_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        if let ItchMessage::Unknown(unknown_message) = message {
+            *self.unknown_message_counts.entry(unknown_message.message_type).or_default() += 1;
+
+            if let Some(unknown_message_callback) = self.unknown_message_callback.as_mut() {
+                unknown_message_callback(unknown_message);
+            }
+
+            return Ok(());
+        }
+
+        if let ItchMessage::Seconds(seconds_message) = message {
+            self.seconds = seconds_message.second.value as u32;
+        }
+
+        self.apply_subscription_updates();
+
+        if let Some(subscription_filter) = self.subscription_filter.as_mut() {
+            if let ItchMessage::OrderBookDirectory(_) | ItchMessage::CombinationOrderBookDirectory(_) = message {
+                subscription_filter.process_directory_message(message);
+            }
+
+            let is_accepted = subscription_filter.accepts(message);
+
+            if !is_accepted {
+                self.filtered_message_count += 1;
+                self.session_statistics.record_filtered();
+                return Ok(());
+            }
+        }
+
+        let timestamp = self.timestamp(message.nanoseconds().unwrap_or(0));
+
+        let system_event_code = match message {
+            ItchMessage::SystemEvent(system_event_message) => Some(system_event_message.event_code.as_char()),
+            _ => None
+        };
+
+        if system_event_code == Some('O') {
+            self.start_session(timestamp)?;
+        }
+
+        self.session_statistics.record(message);
+        self.instrument_directory.process_message(message);
+        self.tick_size_tables.process_message(message);
+
+        if let Some(book_builder) = self.book_builder.as_mut() {
+            if let Some(integrity_checker) = self.integrity_checker.as_mut() {
+                integrity_checker.check_before_apply(book_builder, message, timestamp)?;
+            }
+
+            book_builder.process_message(message, timestamp)?;
+
+            if let Some(integrity_checker) = self.integrity_checker.as_mut() {
+                integrity_checker.check_after_apply(book_builder, message, timestamp)?;
+            }
+        }
+
+        if let Some(order_history) = self.order_history.as_mut() {
+            order_history.process_message(message, timestamp);
+        }
+
+        for output in self.outputs.iter_mut() {
+            output.write_message(message)?;
+        }
+
+        if system_event_code == Some('C') {
+            self.end_session(timestamp)?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_subscription_updates(&mut self) {
+        if let (Some(subscription_handle), Some(subscription_filter)) = (self.subscription_handle.as_ref(), self.subscription_filter.as_mut()) {
+            subscription_handle.apply_if_newer(&mut self.subscription_generation, subscription_filter);
+        }
+    }
+
+    fn start_session(&mut self, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        if self.session_state == SessionState::Open {
+            self.end_session(timestamp)?;
+        }
+
+        if let Some(book_builder) = self.book_builder.as_mut() {
+            book_builder.clear();
+        }
+
+        if let Some(integrity_checker) = self.integrity_checker.as_mut() {
+            integrity_checker.clear();
+        }
+
+        if let Some(order_history) = self.order_history.as_mut() {
+            order_history.clear();
+        }
+
+        if let Some(subscription_filter) = self.subscription_filter.as_mut() {
+            subscription_filter.clear_directory();
+        }
+
+        self.instrument_directory.clear();
+        self.tick_size_tables.clear();
+        self.session_statistics = SessionStatistics::new();
+        self.session_start = Some(timestamp);
+        self.session_state = SessionState::Open;
+
+        Ok(())
+    }
+
+    fn end_session(&mut self, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        self.flush_outputs()?;
+
+        let session_summary = SessionSummary {
+            session_start: self.session_start.take(),
+            session_end: timestamp,
+            statistics: std::mem::take(&mut self.session_statistics),
+            instrument_count: self.instrument_directory.len(),
+            order_book_count: self.book_builder.as_ref().map_or(0, |book_builder| book_builder.book_count()),
+            resting_order_count: self.book_builder.as_ref().map_or(0, |book_builder| book_builder.order_count()),
+            anomaly_count: self.integrity_checker.as_ref().map_or(0, |integrity_checker| integrity_checker.anomaly_count())
+        };
+
+        if let Some(end_of_day_callback) = self.end_of_day_callback.as_mut() {
+            end_of_day_callback(&session_summary);
+        }
+
+        self.session_summaries.push(session_summary);
+        self.session_state = SessionState::Closed;
+
+        Ok(())
+    }
+
+    pub fn run_pipelined<R>(self, config: PipelineConfig, receive: R) -> Result<(DataFeedHandler, RingBufferStatistics), DataFeedError>
+    where
+        R: FnOnce(&mut RingProducer<RawMessage>) -> Result<(), DataFeedError> + Send + 'static
+    {
+        let (mut producer, mut consumer) = spsc_ring_buffer::spsc_ring_buffer::<RawMessage>(config.ring_buffer_capacity);
+
+        let receive_worker = thread::Builder::new()
+            .name("itch-receive".into())
+            .spawn(move || {
+                if let Some(core) = config.receive_core {
+                    thread_affinity::pin_current_thread(core)?;
+                }
+
+                receive(&mut producer)
+            })
+            .map_err(|error| DataFeedErrorKind::Other(format!("Failed to spawn the receive thread: {error}")))?;
+
+        let processing_worker = thread::Builder::new()
+            .name("itch-process".into())
+            .spawn(move || {
+                if let Some(core) = config.processing_core {
+                    thread_affinity::pin_current_thread(core)?;
+                }
+
+                let mut handler = self;
+                let mut result = Ok(());
+                let mut spins = 0;
+
+                loop {
+                    let popped_count = consumer.pop_batch(config.batch_size, |raw_message| {
+                        if result.is_ok() {
+                            result = handler.process_bin(raw_message.as_bytes());
+                        }
+                    });
+
+                    std::mem::replace(&mut result, Ok(()))?;
+
+                    if popped_count > 0 {
+                        spins = 0;
+                    } else if consumer.is_finished() {
+                        break;
+                    } else {
+                        spsc_ring_buffer::backoff(&mut spins);
+                    }
+                }
+
+                Ok((handler, consumer.statistics()))
+            })
+            .map_err(|error| DataFeedErrorKind::Other(format!("Failed to spawn the processing thread: {error}")))?;
+
+        let processing_result: Result<(DataFeedHandler, RingBufferStatistics), DataFeedError> = processing_worker
+            .join()
+            .map_err(|_| DataFeedErrorKind::Other("The processing thread panicked.".into()))?;
+
+        let receive_result = receive_worker
+            .join()
+            .map_err(|_| DataFeedErrorKind::Other("The receive thread panicked.".into()))?;
+
+        let outcome = processing_result?;
+        receive_result?;
+
+        Ok(outcome)
+    }
 }
\ No newline at end of file
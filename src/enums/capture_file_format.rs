@@ -0,0 +1,34 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CaptureFileFormat {
+    #[default]
+    Raw,
+    Journal,
+    Pcap
+}
+
+impl Display for CaptureFileFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Raw => write!(f, "raw"),
+            Self::Journal => write!(f, "journal"),
+            Self::Pcap => write!(f, "pcap")
+        }
+    }
+}
+
+impl FromStr for CaptureFileFormat {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "raw" => Ok(Self::Raw),
+            "journal" => Ok(Self::Journal),
+            "pcap" => Ok(Self::Pcap),
+            _ => Err(DataFeedErrorKind::ParseError(format!("'{text}' is not a capture file format. Expected raw, journal or pcap.")).into())
+        }
+    }
+}
\ No newline at end of file
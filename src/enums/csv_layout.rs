@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CsvLayout {
+    #[default]
+    PerMessageType,
+    Unified
+}
\ No newline at end of file
@@ -1,28 +1,119 @@
-use std::fmt::{Debug, Display};
+use std::{error::Error, fmt::Display};
 
-pub enum DataFeedError {
+use crate::models::error_context::ErrorContext;
+
+#[derive(Debug)]
+pub struct DataFeedError {
+    kind: DataFeedErrorKind,
+    context: ErrorContext
+}
+
+#[derive(Debug)]
+pub enum DataFeedErrorKind {
     NonISO88591Value(char),
     InvalidNumericSize(usize),
     InvalidMessageSize(usize, usize),
     InvalidMessageType(char, char),
+    UnknownMessageType(char),
     InvalidSideValue(char),
     InvalidExchangeOrderTypeValue(u128),
     InvalidLotTypeValue(u128),
     InvalidFinancialProductValue(u128),
-    InvalidLegRatioValues(u128, u128, u128, u128),
+    InvalidLegRatioValues(u32, u32, u32, u32),
     InvalidOccurredAtCrossValue(char),
     InvalidPrintableValue(char),
     InvalidEventCodeValue(char),
+    IntegrityViolation(String),
+    BookCapacityExceeded(&'static str, usize),
+    ShardDisconnected(usize),
+    MalformedCapture(String),
+    InvalidFieldValue(&'static str, String),
+    ParseError(String),
+    Io(std::io::Error),
     Other(String)
 }
 
-impl Display for DataFeedError {
+impl DataFeedError {
+    pub fn kind(&self) -> &DataFeedErrorKind {
+        &self.kind
+    }
+
+    pub fn context(&self) -> ErrorContext {
+        self.context
+    }
+
+    pub fn variant_name(&self) -> &'static str {
+        self.kind.variant_name()
+    }
+
+    pub fn field_name(&self) -> Option<&'static str> {
+        match &self.kind {
+            DataFeedErrorKind::InvalidFieldValue(field_name, _) => self.context.field_name.or(Some(field_name)),
+            _ => self.context.field_name
+        }
+    }
+
+    pub fn is_broken_pipe(&self) -> bool {
+        matches!(&self.kind, DataFeedErrorKind::Io(error) if error.kind() == std::io::ErrorKind::BrokenPipe)
+    }
+
+    pub fn with_message_type(mut self, message_type: char) -> Self {
+        self.context.message_type.get_or_insert(message_type);
+        self
+    }
+
+    pub fn with_field(mut self, field_name: &'static str) -> Self {
+        self.context.field_name.get_or_insert(field_name);
+        self
+    }
+
+    pub fn with_byte_offset(mut self, byte_offset: u64) -> Self {
+        self.context.byte_offset.get_or_insert(byte_offset);
+        self
+    }
+
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.context.sequence_number.get_or_insert(sequence_number);
+        self
+    }
+}
+
+impl DataFeedErrorKind {
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::NonISO88591Value(..) => "NonISO88591Value",
+            Self::InvalidNumericSize(..) => "InvalidNumericSize",
+            Self::InvalidMessageSize(..) => "InvalidMessageSize",
+            Self::InvalidMessageType(..) => "InvalidMessageType",
+            Self::UnknownMessageType(..) => "UnknownMessageType",
+            Self::InvalidSideValue(..) => "InvalidSideValue",
+            Self::InvalidExchangeOrderTypeValue(..) => "InvalidExchangeOrderTypeValue",
+            Self::InvalidLotTypeValue(..) => "InvalidLotTypeValue",
+            Self::InvalidFinancialProductValue(..) => "InvalidFinancialProductValue",
+            Self::InvalidLegRatioValues(..) => "InvalidLegRatioValues",
+            Self::InvalidOccurredAtCrossValue(..) => "InvalidOccurredAtCrossValue",
+            Self::InvalidPrintableValue(..) => "InvalidPrintableValue",
+            Self::InvalidEventCodeValue(..) => "InvalidEventCodeValue",
+            Self::IntegrityViolation(..) => "IntegrityViolation",
+            Self::BookCapacityExceeded(..) => "BookCapacityExceeded",
+            Self::ShardDisconnected(..) => "ShardDisconnected",
+            Self::MalformedCapture(..) => "MalformedCapture",
+            Self::InvalidFieldValue(..) => "InvalidFieldValue",
+            Self::ParseError(..) => "ParseError",
+            Self::Io(..) => "Io",
+            Self::Other(..) => "Other"
+        }
+    }
+}
+
+impl Display for DataFeedErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NonISO88591Value(c) => write!(f, "A non-ISO 8859-1 value was detected in alpha field value: {c}."),
             Self::InvalidNumericSize(expected_size) => write!(f, "Attempted to set numeric field of size {expected_size} bytes with value too large."),
             Self::InvalidMessageSize(expected_message_size, actual_message_size) => write!(f, "An invalid message size was provided. Expected: {expected_message_size}, but was: {actual_message_size}."),
             Self::InvalidMessageType(expected_message_type, actual_message_type) => write!(f, "Message validation error: An invalid message type was detected. Expected '{expected_message_type}' but was '{actual_message_type}'."),
+            Self::UnknownMessageType(message_type) => write!(f, "Message decoding error: The message type '{message_type}' is not recognised."),
             Self::InvalidSideValue(side_value) => write!(f, "Message validation error: The specified side value '{side_value}' is not valid."),
             Self::InvalidExchangeOrderTypeValue(exchange_order_type_value) => write!(f, "Message validation error: The specified exchange order type value '{exchange_order_type_value}' is not valid."),
             Self::InvalidLotTypeValue(lot_type_value) => write!(f, "Message validation error: The specified lot type value '{lot_type_value}' is not valid."),
@@ -31,27 +122,48 @@ impl Display for DataFeedError {
             Self::InvalidOccurredAtCrossValue(occurred_at_cross_value) => write!(f, "Message validation error: The specified occurred at cross value '{occurred_at_cross_value}' is not valid."),
             Self::InvalidPrintableValue(printable_value) => write!(f, "Message validation error: The specified printable value '{printable_value}' is not valid."),
             Self::InvalidEventCodeValue(event_code_value) => write!(f, "Message validation error: The specified event code value '{event_code_value}' is not valid."),
+            Self::IntegrityViolation(anomaly) => write!(f, "{anomaly}"),
+            Self::BookCapacityExceeded(pool_name, capacity) => write!(f, "Book building error: The {pool_name} capacity of {capacity} has been exceeded."),
+            Self::ShardDisconnected(shard_index) => write!(f, "Sharding error: The worker for shard {shard_index} has stopped."),
+            Self::MalformedCapture(reason) => write!(f, "Capture error: {reason}"),
+            Self::InvalidFieldValue(field_name, reason) => write!(f, "Field error: The value of '{field_name}' is invalid. {reason}"),
+            Self::ParseError(reason) => write!(f, "Parse error: {reason}"),
+            Self::Io(error) => write!(f, "I/O error: {error}."),
             Self::Other(msg) => write!(f, "{msg}")
         }
     }
 }
 
-impl Debug for DataFeedError {
+impl Display for DataFeedError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::NonISO88591Value(c) => write!(f, "A non-ISO 8859-1 value was detected in alpha field value: {c}."),
-            Self::InvalidNumericSize(expected_size) => write!(f, "Attempted to set numeric field of size {expected_size} bytes with value too large."),
-            Self::InvalidMessageSize(expected_message_size, actual_message_size) => write!(f, "An invalid message size was provided. Expected: {expected_message_size}, but was: {actual_message_size}."),
-            Self::InvalidMessageType(expected_message_type, actual_message_type) => write!(f, "Message validation error: An invalid message type was detected. Expected '{expected_message_type}' but was '{actual_message_type}'."),
-            Self::InvalidSideValue(side_value) => write!(f, "Message validation error: The specified side value '{side_value}' is not valid."),
-            Self::InvalidExchangeOrderTypeValue(exchange_order_type_value) => write!(f, "Message validation error: The specified exchange order type value '{exchange_order_type_value}' is not valid."),
-            Self::InvalidLotTypeValue(lot_type_value) => write!(f, "Message validation error: The specified lot type value '{lot_type_value}' is not valid"),
-            Self::InvalidFinancialProductValue(financial_product_value) => write!(f, "Message validation error: The specified financial product value '{financial_product_value}' is not valid"),
-            Self::InvalidLegRatioValues(leg_1_ratio_value, leg_2_ratio_value, leg_3_ratio_value, leg_4_ratio_value) => write!(f, "Message validation error: The provided leg ratios do not add to 1. Leg 1 ratio: {leg_1_ratio_value}, leg 2 ratio: {leg_2_ratio_value}, leg 3 ratio: {leg_3_ratio_value}, leg 4 ratio: {leg_4_ratio_value}."),
-            Self::InvalidOccurredAtCrossValue(occurred_at_cross_value) => write!(f, "Message validation error: The specified occurred at cross value '{occurred_at_cross_value}' is not valid."),
-            Self::InvalidPrintableValue(printable_value) => write!(f, "Message validation error: The specified printable value '{printable_value}' is not valid."),
-            Self::InvalidEventCodeValue(event_code_value) => write!(f, "Message validation error: The specified event code value '{event_code_value}' is not valid."),
-            Self::Other(msg) => write!(f, "{msg}")
+        if self.context == ErrorContext::default() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{} ({})", self.kind, self.context)
         }
     }
+}
+
+impl Error for DataFeedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            DataFeedErrorKind::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<DataFeedErrorKind> for DataFeedError {
+    fn from(kind: DataFeedErrorKind) -> Self {
+        DataFeedError {
+            kind,
+            context: ErrorContext::default()
+        }
+    }
+}
+
+impl From<std::io::Error> for DataFeedError {
+    fn from(error: std::io::Error) -> Self {
+        DataFeedErrorKind::Io(error).into()
+    }
 }
\ No newline at end of file
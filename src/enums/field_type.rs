@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    Alpha,
+    Numeric,
+    Price
+}
\ No newline at end of file
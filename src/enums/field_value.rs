@@ -0,0 +1,18 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldValue {
+    Alpha(String),
+    Numeric(u128),
+    Price(i32)
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alpha(value) => write!(f, "{value}"),
+            Self::Numeric(value) => write!(f, "{value}"),
+            Self::Price(value) => write!(f, "{value}")
+        }
+    }
+}
\ No newline at end of file
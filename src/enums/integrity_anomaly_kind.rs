@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityAnomalyKind {
+    UnknownOrderId(u64),
+    DuplicateOrderId(u64),
+    ExecutionExceedsRemainingQuantity(u64, u64, u64),
+    SideMismatch(u64, char, char),
+    CrossedBook(u32, i32, i32)
+}
+
+impl Display for IntegrityAnomalyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownOrderId(order_id) => write!(f, "Integrity anomaly: No resting order was found for order id {order_id}."),
+            Self::DuplicateOrderId(order_id) => write!(f, "Integrity anomaly: An order was added with order id {order_id}, which is already resting."),
+            Self::ExecutionExceedsRemainingQuantity(order_id, executed_quantity, remaining_quantity) => write!(f, "Integrity anomaly: An execution of {executed_quantity} exceeds the remaining quantity {remaining_quantity} of order id {order_id}."),
+            Self::SideMismatch(order_id, expected_side, actual_side) => write!(f, "Integrity anomaly: Order id {order_id} rests on side '{expected_side}' but the message specified side '{actual_side}'."),
+            Self::CrossedBook(order_book_id, best_bid_price, best_ask_price) => write!(f, "Integrity anomaly: Order book {order_book_id} is crossed outside an auction. Best bid: {best_bid_price}, best ask: {best_ask_price}.")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntegrityCheckMode {
+    #[default]
+    LogAndContinue,
+    FailFast
+}
\ No newline at end of file
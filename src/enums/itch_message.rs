@@ -0,0 +1,351 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_value::FieldValue, json_value::JsonValue}, global_constants, models::{field_layout::FieldLayout, field_validator::FieldValidator, messages::{add_order_no_participant_id_message::{self, AddOrderNoParticipantIdMessage}, add_order_with_participant_id_message::{self, AddOrderWithParticipantIdMessage}, combination_order_book_directory_message::{self, CombinationOrderBookDirectoryMessage}, custom_message::CustomMessage, equilibrium_price_update_message::{self, EquilibriumPriceUpdateMessage}, order_book_directory_message::{self, OrderBookDirectoryMessage}, order_book_state_message::{self, OrderBookStateMessage}, order_delete_message::{self, OrderDeleteMessage}, order_executed_message::{self, OrderExecutedMessage}, order_executed_with_price_message::{self, OrderExecutedWithPriceMessage}, order_replace_message::{self, OrderReplaceMessage}, seconds_message::{self, SecondsMessage}, system_event_message::{self, SystemEventMessage}, tick_size_table_entry_message::{self, TickSizeTableEntryMessage}, trade_message::{self, TradeMessage}, unknown_message::UnknownMessage}, validation_policy::ValidationPolicy}, traits::{json::TJson, message::TMessage}, util::buffer_utils};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItchMessage {
+    AddOrderNoParticipantId(AddOrderNoParticipantIdMessage),
+    AddOrderWithParticipantId(AddOrderWithParticipantIdMessage),
+    CombinationOrderBookDirectory(Box<CombinationOrderBookDirectoryMessage>),
+    Custom(CustomMessage),
+    EquilibriumPriceUpdate(EquilibriumPriceUpdateMessage),
+    OrderBookDirectory(Box<OrderBookDirectoryMessage>),
+    OrderBookState(OrderBookStateMessage),
+    OrderDelete(OrderDeleteMessage),
+    OrderExecuted(OrderExecutedMessage),
+    OrderExecutedWithPrice(OrderExecutedWithPriceMessage),
+    OrderReplace(OrderReplaceMessage),
+    Seconds(SecondsMessage),
+    SystemEvent(SystemEventMessage),
+    TickSizeTableEntry(TickSizeTableEntryMessage),
+    Trade(TradeMessage),
+    Unknown(UnknownMessage)
+}
+
+impl ItchMessage {
+    pub fn byte_count(message_type: u8) -> Option<usize> {
+        let byte_count = match message_type {
+            b'A' => global_constants::ADD_ORDER_NO_PARTICIPANT_ID_MESSAGE_BYTE_COUNT,
+            b'F' => global_constants::ADD_ORDER_WITH_PARTICIPANT_ID_MESSAGE_BYTE_COUNT,
+            b'M' => global_constants::COMBINATION_ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT,
+            b'Z' => global_constants::EQUILIBRIUM_PRICE_UPDATE_MESSAGE_BYTE_COUNT,
+            b'R' => global_constants::ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT,
+            b'O' => global_constants::ORDER_BOOK_STATE_MESSAGE_BYTE_COUNT,
+            b'D' => global_constants::ORDER_DELETE_MESSAGE_BYTE_COUNT,
+            b'E' => global_constants::ORDER_EXECUTED_MESSAGE_BYTE_COUNT,
+            b'C' => global_constants::ORDER_EXECUTED_WITH_PRICE_MESSAGE_BYTE_COUNT,
+            b'U' => global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT,
+            b'T' => global_constants::SECONDS_MESSAGE_BYTE_COUNT,
+            b'S' => global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT,
+            b'L' => global_constants::TICK_SIZE_TABLE_ENTRY_MESSAGE_BYTE_COUNT,
+            b'P' => global_constants::TRADE_MESSAGE_BYTE_COUNT,
+            _ => return None
+        };
+
+        Some(byte_count)
+    }
+
+    pub fn peek_order_book_id(binary_data: &[u8]) -> Option<u32> {
+        let order_book_id_byte_offset = match *binary_data.first()? {
+            b'A' => add_order_no_participant_id_message::offsets::order_book_id,
+            b'F' => add_order_with_participant_id_message::offsets::order_book_id,
+            b'M' => combination_order_book_directory_message::offsets::order_book_id,
+            b'Z' => equilibrium_price_update_message::offsets::order_book_id,
+            b'R' => order_book_directory_message::offsets::order_book_id,
+            b'O' => order_book_state_message::offsets::order_book_id,
+            b'D' => order_delete_message::offsets::order_book_id,
+            b'E' => order_executed_message::offsets::order_book_id,
+            b'C' => order_executed_with_price_message::offsets::order_book_id,
+            b'U' => order_replace_message::offsets::order_book_id,
+            b'L' => tick_size_table_entry_message::offsets::order_book_id,
+            b'P' => trade_message::offsets::order_book_id,
+            _ => return None
+        };
+
+        let order_book_id_bytes = binary_data.get(order_book_id_byte_offset..order_book_id_byte_offset + 4)?;
+
+        Some(u32::from_be_bytes([order_book_id_bytes[0], order_book_id_bytes[1], order_book_id_bytes[2], order_book_id_bytes[3]]))
+    }
+
+    pub fn is_directory_message_type(message_type: u8) -> bool {
+        matches!(message_type, b'R' | b'M')
+    }
+
+    pub fn from_bin(binary_data: &[u8]) -> Result<Self, DataFeedError> {
+        ItchMessage::from_bin_with(binary_data, &mut FieldValidator::new(&ValidationPolicy::strict(), &mut Vec::new()))
+    }
+
+    pub fn from_bin_with(binary_data: &[u8], validator: &mut FieldValidator) -> Result<Self, DataFeedError> {
+        let Some(&message_type) = binary_data.first() else {
+            return Err(DataFeedErrorKind::InvalidMessageSize(1, 0).into());
+        };
+
+        ItchMessage::decode(message_type, binary_data, validator).map_err(|error| error.with_message_type(message_type as char))
+    }
+
+    fn decode(message_type: u8, binary_data: &[u8], validator: &mut FieldValidator) -> Result<Self, DataFeedError> {
+        let message = match message_type {
+            b'A' => Self::AddOrderNoParticipantId(AddOrderNoParticipantIdMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ADD_ORDER_NO_PARTICIPANT_ID_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'F' => Self::AddOrderWithParticipantId(AddOrderWithParticipantIdMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ADD_ORDER_WITH_PARTICIPANT_ID_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'M' => Self::CombinationOrderBookDirectory(Box::new(CombinationOrderBookDirectoryMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::COMBINATION_ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?)),
+            b'Z' => Self::EquilibriumPriceUpdate(EquilibriumPriceUpdateMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::EQUILIBRIUM_PRICE_UPDATE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'R' => Self::OrderBookDirectory(Box::new(OrderBookDirectoryMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?)),
+            b'O' => Self::OrderBookState(OrderBookStateMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_BOOK_STATE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'D' => Self::OrderDelete(OrderDeleteMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_DELETE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'E' => Self::OrderExecuted(OrderExecutedMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_EXECUTED_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'C' => Self::OrderExecutedWithPrice(OrderExecutedWithPriceMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_EXECUTED_WITH_PRICE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'U' => Self::OrderReplace(OrderReplaceMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'T' => Self::Seconds(SecondsMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::SECONDS_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'S' => Self::SystemEvent(SystemEventMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'L' => Self::TickSizeTableEntry(TickSizeTableEntryMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::TICK_SIZE_TABLE_ENTRY_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            b'P' => Self::Trade(TradeMessage::from_bin_with(buffer_utils::as_fixed_size::<{ global_constants::TRADE_MESSAGE_BYTE_COUNT }>(binary_data)?, validator)?),
+            _ => Self::Unknown(UnknownMessage::new(binary_data)?)
+        };
+
+        Ok(message)
+    }
+
+    pub fn to_bin(&self) -> Result<Vec<u8>, DataFeedError> {
+        let binary = match self {
+            Self::AddOrderNoParticipantId(message) => message.to_bin::<{ global_constants::ADD_ORDER_NO_PARTICIPANT_ID_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::AddOrderWithParticipantId(message) => message.to_bin::<{ global_constants::ADD_ORDER_WITH_PARTICIPANT_ID_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::CombinationOrderBookDirectory(message) => message.to_bin::<{ global_constants::COMBINATION_ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::EquilibriumPriceUpdate(message) => message.to_bin::<{ global_constants::EQUILIBRIUM_PRICE_UPDATE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderBookDirectory(message) => message.to_bin::<{ global_constants::ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderBookState(message) => message.to_bin::<{ global_constants::ORDER_BOOK_STATE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderDelete(message) => message.to_bin::<{ global_constants::ORDER_DELETE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderExecuted(message) => message.to_bin::<{ global_constants::ORDER_EXECUTED_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderExecutedWithPrice(message) => message.to_bin::<{ global_constants::ORDER_EXECUTED_WITH_PRICE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::OrderReplace(message) => message.to_bin::<{ global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::Seconds(message) => message.to_bin::<{ global_constants::SECONDS_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::SystemEvent(message) => message.to_bin::<{ global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::TickSizeTableEntry(message) => message.to_bin::<{ global_constants::TICK_SIZE_TABLE_ENTRY_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::Trade(message) => message.to_bin::<{ global_constants::TRADE_MESSAGE_BYTE_COUNT }>()?.to_vec(),
+            Self::Custom(CustomMessage { bytes, .. }) | Self::Unknown(UnknownMessage { bytes, .. }) => bytes.clone()
+        };
+
+        Ok(binary)
+    }
+
+    pub fn message_types() -> impl Iterator<Item = u8> {
+        [b'A', b'F', b'M', b'Z', b'R', b'O', b'D', b'E', b'C', b'U', b'T', b'S', b'L', b'P'].into_iter()
+    }
+
+    pub fn fields_for_type(message_type: u8) -> Option<&'static [FieldLayout]> {
+        match message_type {
+            b'A' => Some(&add_order_no_participant_id_message::FIELDS),
+            b'F' => Some(&add_order_with_participant_id_message::FIELDS),
+            b'M' => Some(&combination_order_book_directory_message::FIELDS),
+            b'Z' => Some(&equilibrium_price_update_message::FIELDS),
+            b'R' => Some(&order_book_directory_message::FIELDS),
+            b'O' => Some(&order_book_state_message::FIELDS),
+            b'D' => Some(&order_delete_message::FIELDS),
+            b'E' => Some(&order_executed_message::FIELDS),
+            b'C' => Some(&order_executed_with_price_message::FIELDS),
+            b'U' => Some(&order_replace_message::FIELDS),
+            b'T' => Some(&seconds_message::FIELDS),
+            b'S' => Some(&system_event_message::FIELDS),
+            b'L' => Some(&tick_size_table_entry_message::FIELDS),
+            b'P' => Some(&trade_message::FIELDS),
+            _ => None
+        }
+    }
+
+    pub fn name_for_type(message_type: u8) -> Option<&'static str> {
+        match message_type {
+            b'A' => Some("add_order_no_participant_id"),
+            b'F' => Some("add_order_with_participant_id"),
+            b'M' => Some("combination_order_book_directory"),
+            b'Z' => Some("equilibrium_price_update"),
+            b'R' => Some("order_book_directory"),
+            b'O' => Some("order_book_state"),
+            b'D' => Some("order_delete"),
+            b'E' => Some("order_executed"),
+            b'C' => Some("order_executed_with_price"),
+            b'U' => Some("order_replace"),
+            b'T' => Some("seconds"),
+            b'S' => Some("system_event"),
+            b'L' => Some("tick_size_table_entry"),
+            b'P' => Some("trade"),
+            _ => None
+        }
+    }
+
+    pub fn parse_message_type(text: &str) -> Result<u8, DataFeedError> {
+        ItchMessage::message_types()
+            .find(|message_type| text.as_bytes() == [*message_type] || ItchMessage::name_for_type(*message_type) == Some(text))
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Unknown message type: {text}.")).into())
+    }
+
+    pub fn fields(&self) -> &'static [FieldLayout] {
+        match self {
+            Self::Custom(message) => message.fields,
+            _ => ItchMessage::fields_for_type(self.message_type() as u8).unwrap_or_default()
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Custom(message) => message.name,
+            Self::Unknown(_) => "unknown",
+            _ => ItchMessage::name_for_type(self.message_type() as u8).unwrap_or_default()
+        }
+    }
+
+    pub fn field_values(&self) -> Result<Vec<(&'static FieldLayout, FieldValue)>, DataFeedError> {
+        let binary = self.to_bin()?;
+
+        Ok(
+            self.fields()
+                .iter()
+                .map(|field| (field, field.read(&binary)))
+                .collect()
+        )
+    }
+
+    pub fn message_type(&self) -> char {
+        match self {
+            Self::AddOrderNoParticipantId(message) => message.message_type.as_char(),
+            Self::AddOrderWithParticipantId(message) => message.message_type.as_char(),
+            Self::CombinationOrderBookDirectory(message) => message.message_type.as_char(),
+            Self::Custom(message) => message.message_type(),
+            Self::EquilibriumPriceUpdate(message) => message.message_type.as_char(),
+            Self::OrderBookDirectory(message) => message.message_type.as_char(),
+            Self::OrderBookState(message) => message.message_type.as_char(),
+            Self::OrderDelete(message) => message.message_type.as_char(),
+            Self::OrderExecuted(message) => message.message_type.as_char(),
+            Self::OrderExecutedWithPrice(message) => message.message_type.as_char(),
+            Self::OrderReplace(message) => message.message_type.as_char(),
+            Self::Seconds(message) => message.message_type.as_char(),
+            Self::SystemEvent(message) => message.message_type.as_char(),
+            Self::TickSizeTableEntry(message) => message.message_type.as_char(),
+            Self::Trade(message) => message.message_type.as_char(),
+            Self::Unknown(message) => message.message_type
+        }
+    }
+
+    pub fn nanoseconds(&self) -> Option<u32> {
+        let nanoseconds = match self {
+            Self::AddOrderNoParticipantId(message) => message.nanoseconds.value,
+            Self::AddOrderWithParticipantId(message) => message.nanoseconds.value,
+            Self::CombinationOrderBookDirectory(message) => message.nanoseconds.value,
+            Self::Custom(message) => return message.numeric_field("nanoseconds").map(|nanoseconds| nanoseconds as u32),
+            Self::EquilibriumPriceUpdate(message) => message.nanoseconds.value,
+            Self::OrderBookDirectory(message) => message.nanoseconds.value,
+            Self::OrderBookState(message) => message.nanoseconds.value,
+            Self::OrderDelete(message) => message.nanoseconds.value,
+            Self::OrderExecuted(message) => message.nanoseconds.value,
+            Self::OrderExecutedWithPrice(message) => message.nanoseconds.value,
+            Self::OrderReplace(message) => message.nanoseconds.value,
+            Self::Seconds(_) => return None,
+            Self::SystemEvent(message) => message.nanoseconds.value,
+            Self::TickSizeTableEntry(message) => message.nanoseconds.value,
+            Self::Trade(message) => message.nanoseconds.value,
+            Self::Unknown(_) => return None
+        };
+
+        Some(nanoseconds as u32)
+    }
+
+    pub fn order_book_id(&self) -> Option<u32> {
+        let order_book_id = match self {
+            Self::AddOrderNoParticipantId(message) => message.order_book_id.value,
+            Self::AddOrderWithParticipantId(message) => message.order_book_id.value,
+            Self::CombinationOrderBookDirectory(message) => message.order_book_id.value,
+            Self::Custom(message) => return message.numeric_field("order_book_id").map(|order_book_id| order_book_id as u32),
+            Self::EquilibriumPriceUpdate(message) => message.order_book_id.value,
+            Self::OrderBookDirectory(message) => message.order_book_id.value,
+            Self::OrderBookState(message) => message.order_book_id.value,
+            Self::OrderDelete(message) => message.order_book_id.value,
+            Self::OrderExecuted(message) => message.order_book_id.value,
+            Self::OrderExecutedWithPrice(message) => message.order_book_id.value,
+            Self::OrderReplace(message) => message.order_book_id.value,
+            Self::Seconds(_) | Self::SystemEvent(_) | Self::Unknown(_) => return None,
+            Self::TickSizeTableEntry(message) => message.order_book_id.value,
+            Self::Trade(message) => message.order_book_id.value
+        };
+
+        Some(order_book_id as u32)
+    }
+
+    pub fn order_id(&self) -> Option<u64> {
+        let order_id = match self {
+            Self::AddOrderNoParticipantId(message) => message.order_id.value,
+            Self::AddOrderWithParticipantId(message) => message.order_id.value,
+            Self::OrderDelete(message) => message.order_id.value,
+            Self::OrderExecuted(message) => message.order_id.value,
+            Self::OrderExecutedWithPrice(message) => message.order_id.value,
+            Self::OrderReplace(message) => message.order_id.value,
+            _ => return None
+        };
+
+        Some(order_id as u64)
+    }
+
+    pub fn side(&self) -> Option<char> {
+        let side = match self {
+            Self::AddOrderNoParticipantId(message) => message.side.as_char(),
+            Self::AddOrderWithParticipantId(message) => message.side.as_char(),
+            Self::OrderDelete(message) => message.side.as_char(),
+            Self::OrderExecuted(message) => message.side.as_char(),
+            Self::OrderExecutedWithPrice(message) => message.side.as_char(),
+            Self::OrderReplace(message) => message.side.as_char(),
+            Self::Trade(message) => message.side.as_char(),
+            _ => return None
+        };
+
+        Some(side)
+    }
+}
+
+impl TJson for ItchMessage {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError> {
+        match self {
+            Self::AddOrderNoParticipantId(message) => message.to_json(),
+            Self::AddOrderWithParticipantId(message) => message.to_json(),
+            Self::CombinationOrderBookDirectory(message) => message.to_json(),
+            Self::Custom(message) => Ok(message.to_json()),
+            Self::EquilibriumPriceUpdate(message) => message.to_json(),
+            Self::OrderBookDirectory(message) => message.to_json(),
+            Self::OrderBookState(message) => message.to_json(),
+            Self::OrderDelete(message) => message.to_json(),
+            Self::OrderExecuted(message) => message.to_json(),
+            Self::OrderExecutedWithPrice(message) => message.to_json(),
+            Self::OrderReplace(message) => message.to_json(),
+            Self::Seconds(message) => message.to_json(),
+            Self::SystemEvent(message) => message.to_json(),
+            Self::TickSizeTableEntry(message) => message.to_json(),
+            Self::Trade(message) => message.to_json(),
+            Self::Unknown(message) => message.to_json()
+        }
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized {
+        let message_type = json
+            .get("message_type")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| DataFeedErrorKind::ParseError("The JSON object has no string 'message_type' member.".into()))?;
+
+        let message = match message_type.chars().next() {
+            Some('A') => Self::AddOrderNoParticipantId(AddOrderNoParticipantIdMessage::from_json(json)?),
+            Some('F') => Self::AddOrderWithParticipantId(AddOrderWithParticipantIdMessage::from_json(json)?),
+            Some('M') => Self::CombinationOrderBookDirectory(Box::new(CombinationOrderBookDirectoryMessage::from_json(json)?)),
+            Some('Z') => Self::EquilibriumPriceUpdate(EquilibriumPriceUpdateMessage::from_json(json)?),
+            Some('R') => Self::OrderBookDirectory(Box::new(OrderBookDirectoryMessage::from_json(json)?)),
+            Some('O') => Self::OrderBookState(OrderBookStateMessage::from_json(json)?),
+            Some('D') => Self::OrderDelete(OrderDeleteMessage::from_json(json)?),
+            Some('E') => Self::OrderExecuted(OrderExecutedMessage::from_json(json)?),
+            Some('C') => Self::OrderExecutedWithPrice(OrderExecutedWithPriceMessage::from_json(json)?),
+            Some('U') => Self::OrderReplace(OrderReplaceMessage::from_json(json)?),
+            Some('T') => Self::Seconds(SecondsMessage::from_json(json)?),
+            Some('S') => Self::SystemEvent(SystemEventMessage::from_json(json)?),
+            Some('L') => Self::TickSizeTableEntry(TickSizeTableEntryMessage::from_json(json)?),
+            Some('P') => Self::Trade(TradeMessage::from_json(json)?),
+            Some(message_type) => match json.get("bytes") {
+                Some(_) => Self::Unknown(UnknownMessage::from_json(json)?),
+                None => return Err(DataFeedErrorKind::UnknownMessageType(message_type).into())
+            },
+            None => return Err(DataFeedErrorKind::ParseError("The 'message_type' member is empty.".into()).into())
+        };
+
+        Ok(message)
+    }
+}
\ No newline at end of file
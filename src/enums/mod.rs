@@ -1 +1,17 @@
-pub mod data_feed_error;
\ No newline at end of file
+pub mod capture_file_format;
+pub mod capture_format;
+pub mod csv_layout;
+pub mod data_feed_error;
+pub mod field_type;
+pub mod field_value;
+pub mod integrity_anomaly_kind;
+pub mod integrity_check_mode;
+pub mod itch_message;
+pub mod json_value;
+pub mod order_event_kind;
+pub mod order_instruction;
+pub mod replay_mode;
+pub mod session_state;
+pub mod shard_input;
+pub mod validation_category;
+pub mod validation_mode;
\ No newline at end of file
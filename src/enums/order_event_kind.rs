@@ -0,0 +1,23 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderEventKind {
+    Added {
+        side: char,
+        order_book_position: u32,
+        quantity: u64,
+        price: i32,
+        exchange_order_type: u16,
+        participant_id: Option<String>
+    },
+    Replaced {
+        new_order_book_position: u32,
+        quantity: u64,
+        price: i32,
+        exchange_order_type: u16
+    },
+    Executed {
+        executed_quantity: u64,
+        match_id: u128,
+        trade_price: Option<i32>
+    },
+    Deleted
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use crate::models::simulator::simulated_instrument::SimulatedInstrument;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderInstruction {
+    AddInstrument(SimulatedInstrument),
+    SystemEvent(char),
+    SetTradingState {
+        order_book_id: u32,
+        state_name: String
+    },
+    AddOrder {
+        order_id: Option<u64>,
+        order_book_id: u32,
+        side: char,
+        price: i32,
+        quantity: u64,
+        participant_id: Option<String>
+    },
+    ReplaceOrder {
+        order_id: u64,
+        price: i32,
+        quantity: u64
+    },
+    CancelOrder {
+        order_id: u64
+    },
+    ReportTrade {
+        order_book_id: u32,
+        price: i32,
+        quantity: u64
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ReplayMode {
+    RealTime,
+    Accelerated(f64),
+    #[default]
+    AsFastAsPossible,
+    Step
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SessionState {
+    #[default]
+    NotStarted,
+    Open,
+    Closed
+}
\ No newline at end of file
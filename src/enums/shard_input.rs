@@ -0,0 +1,6 @@
+use crate::{enums::itch_message::ItchMessage, models::raw_message::RawMessage};
+
+pub enum ShardInput {
+    Message(ItchMessage),
+    Bin(RawMessage)
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationCategory {
+    Side,
+    ExchangeOrderType,
+    LotType,
+    FinancialProduct,
+    LegRatio,
+    OccurredAtCross,
+    Printable,
+    EventCode
+}
+
+impl ValidationCategory {
+    pub const ALL: [ValidationCategory; 8] = [
+        Self::Side,
+        Self::ExchangeOrderType,
+        Self::LotType,
+        Self::FinancialProduct,
+        Self::LegRatio,
+        Self::OccurredAtCross,
+        Self::Printable,
+        Self::EventCode
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Side => "side",
+            Self::ExchangeOrderType => "exchange_order_type",
+            Self::LotType => "lot_type",
+            Self::FinancialProduct => "financial_product",
+            Self::LegRatio => "leg_ratio",
+            Self::OccurredAtCross => "occurred_at_cross",
+            Self::Printable => "printable",
+            Self::EventCode => "event_code"
+        }
+    }
+}
+
+impl Display for ValidationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for ValidationCategory {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        ValidationCategory::ALL
+            .into_iter()
+            .find(|category| category.name() == text)
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("'{text}' is not a validation category. Expected one of {}.", ValidationCategory::ALL.map(|category| category.name()).join(", "))).into())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    #[default]
+    Strict,
+    Lenient,
+    Off
+}
+
+impl Display for ValidationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Lenient => write!(f, "lenient"),
+            Self::Off => write!(f, "off")
+        }
+    }
+}
+
+impl FromStr for ValidationMode {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "strict" => Ok(Self::Strict),
+            "lenient" => Ok(Self::Lenient),
+            "off" => Ok(Self::Off),
+            _ => Err(DataFeedErrorKind::ParseError(format!("'{text}' is not a validation mode. Expected strict, lenient or off.")).into())
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, order_instruction::OrderInstruction}, models::{data_types::{alpha::Alpha, numeric::Numeric}, messages::{order_book_directory_message::OrderBookDirectoryMessage, seconds_message::SecondsMessage, system_event_message::SystemEventMessage}, simulator::{matching_engine::MatchingEngine, resting_order::RestingOrder, simulated_instrument::SimulatedInstrument}, timestamp::Timestamp}};
+
+pub struct ExchangeSimulator {
+    engines: BTreeMap<u32, MatchingEngine>,
+    current_time: Option<Timestamp>,
+    next_order_id: u64,
+    next_match_id: u64,
+    pending_messages: Vec<(Timestamp, ItchMessage)>,
+    emitted_message_count: u64
+}
+
+impl Default for ExchangeSimulator {
+    fn default() -> Self {
+        ExchangeSimulator::new()
+    }
+}
+
+impl ExchangeSimulator {
+    pub fn new() -> Self {
+        ExchangeSimulator {
+            engines: BTreeMap::new(),
+            current_time: None,
+            next_order_id: 1,
+            next_match_id: 1,
+            pending_messages: Vec::new(),
+            emitted_message_count: 0
+        }
+    }
+
+    pub fn current_time(&self) -> Option<Timestamp> {
+        self.current_time
+    }
+
+    pub fn emitted_message_count(&self) -> u64 {
+        self.emitted_message_count
+    }
+
+    pub fn engine(&self, order_book_id: u32) -> Option<&MatchingEngine> {
+        self.engines.get(&order_book_id)
+    }
+
+    pub fn engines(&self) -> impl Iterator<Item = &MatchingEngine> {
+        self.engines.values()
+    }
+
+    pub fn engine_for_order(&self, order_id: u64) -> Option<&MatchingEngine> {
+        self.engines
+            .values()
+            .find(|engine| engine.order(order_id).is_some())
+    }
+
+    pub fn submit(&mut self, time: Timestamp, instruction: OrderInstruction) -> Result<(), DataFeedError> {
+        if self.current_time.is_some_and(|current_time| time < current_time) {
+            return Err(DataFeedErrorKind::Other(format!("Instructions must be submitted in time order, but {time} is before {}.", self.current_time.unwrap_or_default())).into());
+        }
+
+        let mut messages = Vec::new();
+
+        if self.current_time.is_none_or(|current_time| current_time.seconds != time.seconds) {
+            messages.push(
+                ItchMessage::Seconds(
+                    SecondsMessage::new(
+                        Alpha { value: [b'T'] },
+                        Numeric { value: time.seconds as u128 }
+                    )?
+                )
+            );
+        }
+
+        self.current_time = Some(time);
+
+        let nanoseconds = time.nanoseconds;
+        let result = self.execute(nanoseconds, instruction, &mut messages);
+
+        self.emitted_message_count += messages.len() as u64;
+        self.pending_messages.extend(messages.into_iter().map(|message| (Timestamp::new(time.seconds, message.nanoseconds().unwrap_or(0)), message)));
+
+        result
+    }
+
+    pub fn drain_messages(&mut self) -> Vec<(Timestamp, ItchMessage)> {
+        std::mem::take(&mut self.pending_messages)
+    }
+
+    fn execute(&mut self, nanoseconds: u32, instruction: OrderInstruction, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        match instruction {
+            OrderInstruction::AddInstrument(instrument) => {
+                if self.engines.contains_key(&instrument.order_book_id) {
+                    return Err(DataFeedErrorKind::InvalidFieldValue("order_book_id", format!("The order book {} has already been listed.", instrument.order_book_id)).into());
+                }
+
+                messages.push(ExchangeSimulator::directory_message(nanoseconds, &instrument)?);
+                self.engines.insert(instrument.order_book_id, MatchingEngine::new(instrument));
+            },
+            OrderInstruction::SystemEvent(event_code) => messages.push(
+                ItchMessage::SystemEvent(
+                    SystemEventMessage::new(
+                        Alpha { value: [b'S'] },
+                        Numeric { value: nanoseconds as u128 },
+                        Alpha { value: [event_code as u8] }
+                    )?
+                )
+            ),
+            OrderInstruction::SetTradingState { order_book_id, state_name } => {
+                ExchangeSimulator::engine_mut(&mut self.engines, order_book_id)?.set_trading_state(nanoseconds, &state_name, &mut self.next_match_id, messages)?;
+            },
+            OrderInstruction::AddOrder { order_id, order_book_id, side, price, quantity, participant_id } => {
+                let order_id = match order_id {
+                    Some(order_id) if self.engine_for_order(order_id).is_some() => {
+                        return Err(DataFeedErrorKind::InvalidFieldValue("order_id", format!("The order {order_id} is already resting in a book.")).into());
+                    },
+                    Some(order_id) => order_id,
+                    None => self.allocate_order_id()
+                };
+
+                self.next_order_id = self.next_order_id.max(order_id + 1);
+
+                let order = RestingOrder {
+                    order_id,
+                    side,
+                    price,
+                    quantity,
+                    participant_id
+                };
+
+                let engine = ExchangeSimulator::engine_mut(&mut self.engines, order_book_id)?;
+                engine.add_order(nanoseconds, order, &mut self.next_match_id, messages)?;
+            },
+            OrderInstruction::ReplaceOrder { order_id, price, quantity } => {
+                let order_book_id = self.order_book_id_for_order(order_id)?;
+                let engine = ExchangeSimulator::engine_mut(&mut self.engines, order_book_id)?;
+                engine.replace_order(nanoseconds, order_id, price, quantity, &mut self.next_match_id, messages)?;
+            },
+            OrderInstruction::CancelOrder { order_id } => {
+                let order_book_id = self.order_book_id_for_order(order_id)?;
+                ExchangeSimulator::engine_mut(&mut self.engines, order_book_id)?.cancel_order(nanoseconds, order_id, messages)?;
+            },
+            OrderInstruction::ReportTrade { order_book_id, price, quantity } => {
+                ExchangeSimulator::engine_mut(&mut self.engines, order_book_id)?.report_trade(nanoseconds, price, quantity, &mut self.next_match_id, messages)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn engine_mut(engines: &mut BTreeMap<u32, MatchingEngine>, order_book_id: u32) -> Result<&mut MatchingEngine, DataFeedError> {
+        engines
+            .get_mut(&order_book_id)
+            .ok_or_else(|| ExchangeSimulator::unknown_order_book(order_book_id))
+    }
+
+    fn order_book_id_for_order(&self, order_id: u64) -> Result<u32, DataFeedError> {
+        self.engine_for_order(order_id)
+            .map(|engine| engine.order_book_id())
+            .ok_or_else(|| DataFeedErrorKind::InvalidFieldValue("order_id", format!("The order {order_id} is not resting in any book.")).into())
+    }
+
+    fn allocate_order_id(&mut self) -> u64 {
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+
+        order_id
+    }
+
+    fn unknown_order_book(order_book_id: u32) -> DataFeedError {
+        DataFeedErrorKind::InvalidFieldValue("order_book_id", format!("The order book {order_book_id} has not been listed.")).into()
+    }
+
+    fn directory_message(nanoseconds: u32, instrument: &SimulatedInstrument) -> Result<ItchMessage, DataFeedError> {
+        Ok(
+            ItchMessage::OrderBookDirectory(
+                Box::new(
+                    OrderBookDirectoryMessage::new(
+                        Alpha { value: [b'R'] },
+                        Numeric { value: nanoseconds as u128 },
+                        Numeric { value: instrument.order_book_id as u128 },
+                        Alpha::from_str_padded(&instrument.symbol)?,
+                        Alpha::from_str_padded(&instrument.long_name)?,
+                        Alpha::from_str_padded(&instrument.isin)?,
+                        Numeric { value: instrument.financial_product as u128 },
+                        Alpha::from_str_padded(&instrument.trading_currency)?,
+                        Numeric { value: instrument.number_of_decimals_in_price as u128 },
+                        Numeric { value: 0 },
+                        Numeric { value: 1 },
+                        Numeric { value: instrument.round_lot_size as u128 },
+                        Numeric { value: instrument.round_lot_size as u128 },
+                        Numeric { value: 0 }
+                    )?
+                )
+            )
+        )
+    }
+}
\ No newline at end of file
@@ -11,4 +11,5 @@ pub const ORDER_REPLACE_MESSAGE_BYTE_COUNT: usize = 36;
 pub const SECONDS_MESSAGE_BYTE_COUNT: usize = 5;
 pub const SYSTEM_EVENT_MESSAGE_BYTE_COUNT: usize = 6;
 pub const TICK_SIZE_TABLE_ENTRY_MESSAGE_BYTE_COUNT: usize = 25;
-pub const TRADE_MESSAGE_BYTE_COUNT: usize = 50;
\ No newline at end of file
+pub const TRADE_MESSAGE_BYTE_COUNT: usize = 50;
+pub const MAX_MESSAGE_BYTE_COUNT: usize = COMBINATION_ORDER_BOOK_DIRECTORY_MESSAGE_BYTE_COUNT;
\ No newline at end of file
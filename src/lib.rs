@@ -1,10 +1,9 @@
 pub mod data_feed_handler;
 pub mod enums;
+pub mod exchange_simulator;
 pub mod global_constants;
 pub mod models;
+pub mod replay_engine;
+pub mod sharded_data_feed_handler;
 pub mod traits;
-pub mod util;
-
-fn main() {
-    println!("Hello, world!");
-}
+pub mod util;
\ No newline at end of file
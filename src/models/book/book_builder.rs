@@ -0,0 +1,439 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, models::{book::{book_builder_config::BookBuilderConfig, book_state::BookState, level_node::LevelNode, level_tree, order::Order, order_book::OrderBook, order_node::{NULL_INDEX, OrderNode}, trade::Trade}, timestamp::Timestamp}, util::fixed_index_map::FixedIndexMap};
+
+pub struct BookBuilder {
+    config: BookBuilderConfig,
+    books: Vec<BookState>,
+    book_index: FixedIndexMap,
+    orders: Vec<OrderNode>,
+    order_index: FixedIndexMap<u128>,
+    free_order: u32,
+    levels: Vec<LevelNode>,
+    bid_level_index: FixedIndexMap,
+    ask_level_index: FixedIndexMap,
+    free_level: u32
+}
+
+impl Default for BookBuilder {
+    fn default() -> Self {
+        BookBuilder::new(BookBuilderConfig::default())
+    }
+}
+
+impl BookBuilder {
+    pub fn new(config: BookBuilderConfig) -> Self {
+        let mut book_builder = BookBuilder {
+            config,
+            books: Vec::with_capacity(config.max_order_books),
+            book_index: FixedIndexMap::with_capacity(config.max_order_books),
+            orders: vec![OrderNode::default(); config.max_orders],
+            order_index: FixedIndexMap::with_capacity(config.max_orders),
+            free_order: NULL_INDEX,
+            levels: vec![LevelNode::default(); config.max_price_levels],
+            bid_level_index: FixedIndexMap::with_capacity(config.max_price_levels),
+            ask_level_index: FixedIndexMap::with_capacity(config.max_price_levels),
+            free_level: NULL_INDEX
+        };
+
+        book_builder.reset_free_lists();
+
+        book_builder
+    }
+
+    pub fn config(&self) -> BookBuilderConfig {
+        self.config
+    }
+
+    pub fn process_message(&mut self, message: &ItchMessage, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        match message {
+            ItchMessage::OrderBookDirectory(message) => {
+                self.book_slot(message.order_book_id.value as u32)?;
+            },
+            ItchMessage::CombinationOrderBookDirectory(message) => {
+                self.book_slot(message.order_book_id.value as u32)?;
+            },
+            ItchMessage::OrderBookState(message) => {
+                let trading_state = message.state_name.to_trimmed_string();
+                let book_slot = self.book_slot(message.order_book_id.value as u32)?;
+                self.books[book_slot as usize].trading_state = Some(trading_state);
+            },
+            ItchMessage::AddOrderNoParticipantId(message) => self.add_order(
+                Order {
+                    order_id: message.order_id.value as u64,
+                    order_book_id: message.order_book_id.value as u32,
+                    side: message.side.as_char(),
+                    price: message.price.value,
+                    quantity: message.quantity.value as u64
+                },
+                message.order_book_position.value as u32
+            )?,
+            ItchMessage::AddOrderWithParticipantId(message) => self.add_order(
+                Order {
+                    order_id: message.order_id.value as u64,
+                    order_book_id: message.order_book_id.value as u32,
+                    side: message.side.as_char(),
+                    price: message.price.value,
+                    quantity: message.quantity.value as u64
+                },
+                message.order_book_position.value as u32
+            )?,
+            ItchMessage::OrderReplace(message) => {
+                let Some(order) = self.remove_order(message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64) else {
+                    return Ok(());
+                };
+
+                self.add_order(
+                    Order {
+                        price: message.price.value,
+                        quantity: message.quantity.value as u64,
+                        ..order
+                    },
+                    message.new_order_book_position.value as u32
+                )?;
+            },
+            ItchMessage::OrderExecuted(message) => {
+                if let Some(order) = self.order(message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64).copied() {
+                    self.execute_order(order, message.executed_quantity.value as u64, order.price, timestamp);
+                }
+            },
+            ItchMessage::OrderExecutedWithPrice(message) => {
+                if let Some(order) = self.order(message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64).copied() {
+                    self.execute_order(order, message.executed_quantity.value as u64, message.trade_price.value, timestamp);
+                }
+            },
+            ItchMessage::OrderDelete(message) => {
+                self.remove_order(message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64);
+            },
+            ItchMessage::Trade(message) => {
+                let book_slot = self.book_slot(message.order_book_id.value as u32)?;
+                self.books[book_slot as usize].last_trade = Some(
+                    Trade {
+                        timestamp,
+                        price: message.trade_price.value,
+                        quantity: message.quantity.value as u64
+                    }
+                );
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn book(&self, order_book_id: u32) -> Option<OrderBook<'_>> {
+        let book_slot = self.book_index.get(order_book_id as u64)?;
+
+        Some(OrderBook::new(&self.books[book_slot as usize], &self.levels, &self.orders))
+    }
+
+    pub fn books(&self) -> impl Iterator<Item = OrderBook<'_>> {
+        self.books.iter().map(|state| OrderBook::new(state, &self.levels, &self.orders))
+    }
+
+    pub fn order(&self, order_book_id: u32, side: char, order_id: u64) -> Option<&Order> {
+        let order_slot = self.order_index.get(order_key(order_book_id, side, order_id))?;
+
+        Some(&self.orders[order_slot as usize].order)
+    }
+
+    pub fn order_count(&self) -> usize {
+        self.order_index.len()
+    }
+
+    pub fn book_count(&self) -> usize {
+        self.books.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.books.clear();
+        self.book_index.clear();
+        self.order_index.clear();
+        self.bid_level_index.clear();
+        self.ask_level_index.clear();
+        self.reset_free_lists();
+    }
+
+    fn reset_free_lists(&mut self) {
+        let order_count = self.orders.len() as u32;
+        for (index, node) in self.orders.iter_mut().enumerate() {
+            node.next = if index as u32 + 1 < order_count { index as u32 + 1 } else { NULL_INDEX };
+        }
+        self.free_order = if order_count > 0 { 0 } else { NULL_INDEX };
+
+        let level_count = self.levels.len() as u32;
+        for (index, node) in self.levels.iter_mut().enumerate() {
+            node.next = if index as u32 + 1 < level_count { index as u32 + 1 } else { NULL_INDEX };
+        }
+        self.free_level = if level_count > 0 { 0 } else { NULL_INDEX };
+    }
+
+    fn book_slot(&mut self, order_book_id: u32) -> Result<u32, DataFeedError> {
+        if let Some(book_slot) = self.book_index.get(order_book_id as u64) {
+            return Ok(book_slot);
+        }
+
+        if self.books.len() == self.config.max_order_books {
+            return Err(DataFeedErrorKind::BookCapacityExceeded("order book", self.config.max_order_books).into());
+        }
+
+        let book_slot = self.books.len() as u32;
+        self.books.push(BookState::new(order_book_id));
+        self.book_index.insert(order_book_id as u64, book_slot);
+
+        Ok(book_slot)
+    }
+
+    fn add_order(&mut self, order: Order, order_book_position: u32) -> Result<(), DataFeedError> {
+        let order_key = order_key(order.order_book_id, order.side, order.order_id);
+
+        if self.order_index.get(order_key).is_some() {
+            self.remove_order(order.order_book_id, order.side, order.order_id);
+        }
+
+        if self.free_order == NULL_INDEX {
+            return Err(DataFeedErrorKind::BookCapacityExceeded("order", self.config.max_orders).into());
+        }
+
+        let book_slot = self.book_slot(order.order_book_id)?;
+        let level = self.find_or_insert_level(book_slot, order.side, order.price)?;
+
+        let order_slot = self.free_order;
+        self.free_order = self.orders[order_slot as usize].next;
+
+        self.orders[order_slot as usize] = OrderNode {
+            order,
+            level,
+            previous: NULL_INDEX,
+            next: NULL_INDEX
+        };
+
+        self.link_order(book_slot, order.side, level, order_slot, order_book_position);
+        self.order_index.insert(order_key, order_slot);
+        self.add_level_order_count(book_slot, order.side, order.price, 1);
+        self.levels[level as usize].quantity += order.quantity;
+
+        Ok(())
+    }
+
+    fn remove_order(&mut self, order_book_id: u32, side: char, order_id: u64) -> Option<Order> {
+        let order_slot = self.order_index.remove(order_key(order_book_id, side, order_id))?;
+        let OrderNode { order, level, .. } = self.orders[order_slot as usize];
+
+        self.unlink_order(level, order_slot);
+
+        let level_node = &mut self.levels[level as usize];
+        level_node.quantity = level_node.quantity.saturating_sub(order.quantity);
+
+        if let Some(book_slot) = self.book_index.get(order_book_id as u64) {
+            self.add_level_order_count(book_slot, side, order.price, -1);
+        }
+
+        if self.levels[level as usize].order_count == 0 {
+            self.remove_level(order_book_id, side, level);
+        }
+
+        self.orders[order_slot as usize].next = self.free_order;
+        self.free_order = order_slot;
+
+        Some(order)
+    }
+
+    fn execute_order(&mut self, order: Order, executed_quantity: u64, price: i32, timestamp: Timestamp) {
+        if let Some(book_slot) = self.book_index.get(order.order_book_id as u64) {
+            self.books[book_slot as usize].last_trade = Some(
+                Trade {
+                    timestamp,
+                    price,
+                    quantity: executed_quantity
+                }
+            );
+        }
+
+        if executed_quantity >= order.quantity {
+            self.remove_order(order.order_book_id, order.side, order.order_id);
+            return;
+        }
+
+        let Some(order_slot) = self.order_index.get(order_key(order.order_book_id, order.side, order.order_id)) else {
+            return;
+        };
+
+        let node = &mut self.orders[order_slot as usize];
+        node.order.quantity -= executed_quantity;
+
+        let level_node = &mut self.levels[node.level as usize];
+        level_node.quantity = level_node.quantity.saturating_sub(executed_quantity);
+    }
+
+    fn find_or_insert_level(&mut self, book_slot: u32, side: char, price: i32) -> Result<u32, DataFeedError> {
+        let order_book_id = self.books[book_slot as usize].order_book_id;
+        let level_key = level_key(order_book_id, price);
+
+        if let Some(level) = self.level_index(side).get(level_key) {
+            return Ok(level);
+        }
+
+        if self.free_level == NULL_INDEX {
+            return Err(DataFeedErrorKind::BookCapacityExceeded("price level", self.config.max_price_levels).into());
+        }
+
+        let level = self.free_level;
+        self.free_level = self.levels[level as usize].next;
+
+        let is_bid = side == 'B';
+        let book = &self.books[book_slot as usize];
+        let root = if is_bid { book.bid_level_root } else { book.ask_level_root };
+        let (lower, higher) = level_tree::neighbours(&self.levels, root, price);
+        let (previous, next) = if is_bid { (higher, lower) } else { (lower, higher) };
+
+        self.levels[level as usize] = LevelNode {
+            price,
+            previous,
+            next,
+            ..LevelNode::default()
+        };
+
+        if next != NULL_INDEX {
+            self.levels[next as usize].previous = level;
+        }
+
+        if previous != NULL_INDEX {
+            self.levels[previous as usize].next = level;
+        } else if is_bid {
+            self.books[book_slot as usize].best_bid_level = level;
+        } else {
+            self.books[book_slot as usize].best_ask_level = level;
+        }
+
+        let root = level_tree::insert(&mut self.levels, root, level);
+        self.set_level_root(book_slot, side, root);
+        self.level_index_mut(side).insert(level_key, level);
+
+        Ok(level)
+    }
+
+    fn remove_level(&mut self, order_book_id: u32, side: char, level: u32) {
+        let LevelNode { price, previous, next, .. } = self.levels[level as usize];
+        self.level_index_mut(side).remove(level_key(order_book_id, price));
+
+        if next != NULL_INDEX {
+            self.levels[next as usize].previous = previous;
+        }
+
+        if previous != NULL_INDEX {
+            self.levels[previous as usize].next = next;
+        }
+
+        if let Some(book_slot) = self.book_index.get(order_book_id as u64) {
+            let root = self.level_root(book_slot, side);
+            let root = level_tree::remove(&mut self.levels, root, price);
+            self.set_level_root(book_slot, side, root);
+
+            if previous == NULL_INDEX {
+                let book = &mut self.books[book_slot as usize];
+
+                if side == 'B' {
+                    book.best_bid_level = next;
+                } else {
+                    book.best_ask_level = next;
+                }
+            }
+        }
+
+        self.levels[level as usize].next = self.free_level;
+        self.free_level = level;
+    }
+
+    fn level_index(&self, side: char) -> &FixedIndexMap {
+        if side == 'B' { &self.bid_level_index } else { &self.ask_level_index }
+    }
+
+    fn level_index_mut(&mut self, side: char) -> &mut FixedIndexMap {
+        if side == 'B' { &mut self.bid_level_index } else { &mut self.ask_level_index }
+    }
+
+    fn level_root(&self, book_slot: u32, side: char) -> u32 {
+        let book = &self.books[book_slot as usize];
+
+        if side == 'B' { book.bid_level_root } else { book.ask_level_root }
+    }
+
+    fn set_level_root(&mut self, book_slot: u32, side: char, root: u32) {
+        let book = &mut self.books[book_slot as usize];
+
+        if side == 'B' {
+            book.bid_level_root = root;
+        } else {
+            book.ask_level_root = root;
+        }
+    }
+
+    fn add_level_order_count(&mut self, book_slot: u32, side: char, price: i32, delta: i32) {
+        let root = self.level_root(book_slot, side);
+        level_tree::add_order_count(&mut self.levels, root, price, delta);
+    }
+
+    fn link_order(&mut self, book_slot: u32, side: char, level: u32, order_slot: u32, order_book_position: u32) {
+        let root = self.level_root(book_slot, side);
+        let price = self.levels[level as usize].price;
+        let orders_at_better_levels = if side == 'B' {
+            level_tree::order_count_above(&self.levels, root, price)
+        } else {
+            level_tree::order_count_below(&self.levels, root, price)
+        };
+
+        let queue_position = order_book_position.saturating_sub(orders_at_better_levels).max(1);
+        let (mut previous, mut next) = (NULL_INDEX, self.levels[level as usize].head_order);
+
+        if queue_position > self.levels[level as usize].order_count {
+            (previous, next) = (self.levels[level as usize].tail_order, NULL_INDEX);
+        }
+
+        for _ in 1..queue_position {
+            if next == NULL_INDEX {
+                break;
+            }
+
+            previous = next;
+            next = self.orders[next as usize].next;
+        }
+
+        self.orders[order_slot as usize].previous = previous;
+        self.orders[order_slot as usize].next = next;
+
+        if previous != NULL_INDEX {
+            self.orders[previous as usize].next = order_slot;
+        } else {
+            self.levels[level as usize].head_order = order_slot;
+        }
+
+        if next != NULL_INDEX {
+            self.orders[next as usize].previous = order_slot;
+        } else {
+            self.levels[level as usize].tail_order = order_slot;
+        }
+    }
+
+    fn unlink_order(&mut self, level: u32, order_slot: u32) {
+        let OrderNode { previous, next, .. } = self.orders[order_slot as usize];
+
+        if previous != NULL_INDEX {
+            self.orders[previous as usize].next = next;
+        } else {
+            self.levels[level as usize].head_order = next;
+        }
+
+        if next != NULL_INDEX {
+            self.orders[next as usize].previous = previous;
+        } else {
+            self.levels[level as usize].tail_order = previous;
+        }
+    }
+}
+
+fn level_key(order_book_id: u32, price: i32) -> u64 {
+    ((order_book_id as u64) << 32) | (price as u32 as u64)
+}
+
+fn order_key(order_book_id: u32, side: char, order_id: u64) -> u128 {
+    ((order_book_id as u128) << 72) | ((side as u8 as u128) << 64) | (order_id as u128)
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookBuilderConfig {
+    pub max_orders: usize,
+    pub max_order_books: usize,
+    pub max_price_levels: usize
+}
+
+impl Default for BookBuilderConfig {
+    fn default() -> Self {
+        BookBuilderConfig {
+            max_orders: 1 << 20,
+            max_order_books: 1 << 14,
+            max_price_levels: 1 << 18
+        }
+    }
+}
\ No newline at end of file
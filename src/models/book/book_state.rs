@@ -0,0 +1,26 @@
+use crate::models::book::{order_node::NULL_INDEX, trade::Trade};
+
+#[derive(Clone)]
+pub(crate) struct BookState {
+    pub order_book_id: u32,
+    pub trading_state: Option<String>,
+    pub last_trade: Option<Trade>,
+    pub best_bid_level: u32,
+    pub best_ask_level: u32,
+    pub bid_level_root: u32,
+    pub ask_level_root: u32
+}
+
+impl BookState {
+    pub fn new(order_book_id: u32) -> Self {
+        BookState {
+            order_book_id,
+            trading_state: None,
+            last_trade: None,
+            best_bid_level: NULL_INDEX,
+            best_ask_level: NULL_INDEX,
+            bid_level_root: NULL_INDEX,
+            ask_level_root: NULL_INDEX
+        }
+    }
+}
\ No newline at end of file
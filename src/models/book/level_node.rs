@@ -0,0 +1,34 @@
+use crate::models::book::order_node::NULL_INDEX;
+
+#[derive(Clone, Copy)]
+pub(crate) struct LevelNode {
+    pub price: i32,
+    pub quantity: u64,
+    pub order_count: u32,
+    pub head_order: u32,
+    pub tail_order: u32,
+    pub previous: u32,
+    pub next: u32,
+    pub left: u32,
+    pub right: u32,
+    pub priority: u32,
+    pub subtree_order_count: u32
+}
+
+impl Default for LevelNode {
+    fn default() -> Self {
+        LevelNode {
+            price: 0,
+            quantity: 0,
+            order_count: 0,
+            head_order: NULL_INDEX,
+            tail_order: NULL_INDEX,
+            previous: NULL_INDEX,
+            next: NULL_INDEX,
+            left: NULL_INDEX,
+            right: NULL_INDEX,
+            priority: 0,
+            subtree_order_count: 0
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use crate::models::book::{level_node::LevelNode, order_node::NULL_INDEX};
+
+pub(crate) fn neighbours(levels: &[LevelNode], root: u32, price: i32) -> (u32, u32) {
+    let mut lower = NULL_INDEX;
+    let mut higher = NULL_INDEX;
+    let mut node = root;
+
+    while node != NULL_INDEX {
+        if levels[node as usize].price < price {
+            lower = node;
+            node = levels[node as usize].right;
+        } else {
+            higher = node;
+            node = levels[node as usize].left;
+        }
+    }
+
+    (lower, higher)
+}
+
+pub(crate) fn insert(levels: &mut [LevelNode], root: u32, level: u32) -> u32 {
+    let price = levels[level as usize].price;
+
+    levels[level as usize].priority = priority(level, price);
+    levels[level as usize].left = NULL_INDEX;
+    levels[level as usize].right = NULL_INDEX;
+    update(levels, level);
+
+    let (lower, higher) = split(levels, root, price, false);
+    let lower = merge(levels, lower, level);
+
+    merge(levels, lower, higher)
+}
+
+pub(crate) fn remove(levels: &mut [LevelNode], root: u32, price: i32) -> u32 {
+    let (lower, rest) = split(levels, root, price, false);
+    let (_, higher) = split(levels, rest, price, true);
+
+    merge(levels, lower, higher)
+}
+
+pub(crate) fn add_order_count(levels: &mut [LevelNode], root: u32, price: i32, delta: i32) {
+    let mut node = root;
+
+    while node != NULL_INDEX {
+        let level = &mut levels[node as usize];
+        level.subtree_order_count = level.subtree_order_count.wrapping_add_signed(delta);
+
+        if level.price == price {
+            level.order_count = level.order_count.wrapping_add_signed(delta);
+            return;
+        }
+
+        node = if price < level.price { level.left } else { level.right };
+    }
+}
+
+pub(crate) fn order_count_below(levels: &[LevelNode], root: u32, price: i32) -> u32 {
+    let mut order_count = 0;
+    let mut node = root;
+
+    while node != NULL_INDEX {
+        let level = &levels[node as usize];
+
+        if level.price < price {
+            order_count += subtree_order_count(levels, level.left) + level.order_count;
+            node = level.right;
+        } else {
+            node = level.left;
+        }
+    }
+
+    order_count
+}
+
+pub(crate) fn order_count_above(levels: &[LevelNode], root: u32, price: i32) -> u32 {
+    let mut order_count = 0;
+    let mut node = root;
+
+    while node != NULL_INDEX {
+        let level = &levels[node as usize];
+
+        if level.price > price {
+            order_count += subtree_order_count(levels, level.right) + level.order_count;
+            node = level.left;
+        } else {
+            node = level.right;
+        }
+    }
+
+    order_count
+}
+
+fn split(levels: &mut [LevelNode], root: u32, price: i32, is_inclusive: bool) -> (u32, u32) {
+    if root == NULL_INDEX {
+        return (NULL_INDEX, NULL_INDEX);
+    }
+
+    let root_price = levels[root as usize].price;
+
+    if root_price < price || (is_inclusive && root_price == price) {
+        let (lower, higher) = split(levels, levels[root as usize].right, price, is_inclusive);
+        levels[root as usize].right = lower;
+        update(levels, root);
+
+        (root, higher)
+    } else {
+        let (lower, higher) = split(levels, levels[root as usize].left, price, is_inclusive);
+        levels[root as usize].left = higher;
+        update(levels, root);
+
+        (lower, root)
+    }
+}
+
+fn merge(levels: &mut [LevelNode], lower: u32, higher: u32) -> u32 {
+    if lower == NULL_INDEX {
+        return higher;
+    }
+
+    if higher == NULL_INDEX {
+        return lower;
+    }
+
+    if levels[lower as usize].priority > levels[higher as usize].priority {
+        levels[lower as usize].right = merge(levels, levels[lower as usize].right, higher);
+        update(levels, lower);
+
+        lower
+    } else {
+        levels[higher as usize].left = merge(levels, lower, levels[higher as usize].left);
+        update(levels, higher);
+
+        higher
+    }
+}
+
+fn update(levels: &mut [LevelNode], node: u32) {
+    let LevelNode { left, right, order_count, .. } = levels[node as usize];
+    levels[node as usize].subtree_order_count = order_count + subtree_order_count(levels, left) + subtree_order_count(levels, right);
+}
+
+fn subtree_order_count(levels: &[LevelNode], node: u32) -> u32 {
+    if node == NULL_INDEX { 0 } else { levels[node as usize].subtree_order_count }
+}
+
+fn priority(level: u32, price: i32) -> u32 {
+    ((((level as u64) << 32) | (price as u32 as u64)).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32) as u32
+}
\ No newline at end of file
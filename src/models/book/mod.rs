@@ -0,0 +1,10 @@
+pub mod book_builder;
+pub mod book_builder_config;
+pub mod order;
+pub mod order_book;
+pub mod price_level;
+pub mod trade;
+pub(crate) mod book_state;
+pub(crate) mod level_node;
+pub(crate) mod level_tree;
+pub(crate) mod order_node;
\ No newline at end of file
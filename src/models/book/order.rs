@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Order {
+    pub order_id: u64,
+    pub order_book_id: u32,
+    pub side: char,
+    pub price: i32,
+    pub quantity: u64
+}
\ No newline at end of file
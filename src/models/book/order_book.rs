@@ -0,0 +1,102 @@
+use crate::models::book::{book_state::BookState, level_node::LevelNode, order::Order, order_node::{NULL_INDEX, OrderNode}, price_level::PriceLevel, trade::Trade};
+
+pub(crate) const AUCTION_TRADING_STATES: [&str; 5] = ["PRE_OPEN", "PRE_CSPA", "PRE_NR", "ADJUST", "ADJUST_ON"];
+
+#[derive(Clone, Copy)]
+pub struct OrderBook<'a> {
+    state: &'a BookState,
+    levels: &'a [LevelNode],
+    orders: &'a [OrderNode]
+}
+
+impl<'a> OrderBook<'a> {
+    pub(crate) fn new(state: &'a BookState, levels: &'a [LevelNode], orders: &'a [OrderNode]) -> Self {
+        OrderBook {
+            state,
+            levels,
+            orders
+        }
+    }
+
+    pub fn order_book_id(&self) -> u32 {
+        self.state.order_book_id
+    }
+
+    pub fn trading_state(&self) -> Option<&'a str> {
+        self.state.trading_state.as_deref()
+    }
+
+    pub fn last_trade(&self) -> Option<Trade> {
+        self.state.last_trade
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids().next()
+    }
+
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks().next()
+    }
+
+    pub fn bids(&self) -> impl Iterator<Item = PriceLevel> + 'a {
+        let book = *self;
+        self.level_indices(self.state.best_bid_level).map(move |index| book.price_level(index))
+    }
+
+    pub fn asks(&self) -> impl Iterator<Item = PriceLevel> + 'a {
+        let book = *self;
+        self.level_indices(self.state.best_ask_level).map(move |index| book.price_level(index))
+    }
+
+    pub fn orders(&self, side: char, price: i32) -> impl Iterator<Item = &'a Order> + 'a {
+        let best_level = if side == 'B' { self.state.best_bid_level } else { self.state.best_ask_level };
+        let levels = self.levels;
+        let orders = self.orders;
+
+        let head_order = self.level_indices(best_level)
+            .find(|index| levels[*index as usize].price == price)
+            .map_or(NULL_INDEX, |index| levels[index as usize].head_order);
+
+        std::iter::successors(
+            (head_order != NULL_INDEX).then_some(head_order),
+            move |index| {
+                let next = orders[*index as usize].next;
+                (next != NULL_INDEX).then_some(next)
+            }
+        ).map(move |index| &orders[index as usize].order)
+    }
+
+    pub fn is_crossed(&self) -> bool {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(best_bid), Some(best_ask)) => best_bid.price >= best_ask.price,
+            _ => false
+        }
+    }
+
+    pub fn is_in_auction(&self) -> bool {
+        self.trading_state()
+            .is_some_and(|trading_state| AUCTION_TRADING_STATES.contains(&trading_state))
+    }
+
+    fn level_indices(&self, best_level: u32) -> impl Iterator<Item = u32> + 'a {
+        let levels = self.levels;
+
+        std::iter::successors(
+            (best_level != NULL_INDEX).then_some(best_level),
+            move |index| {
+                let next = levels[*index as usize].next;
+                (next != NULL_INDEX).then_some(next)
+            }
+        )
+    }
+
+    fn price_level(&self, index: u32) -> PriceLevel {
+        let level = &self.levels[index as usize];
+
+        PriceLevel {
+            price: level.price,
+            quantity: level.quantity,
+            order_count: level.order_count
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::models::book::order::Order;
+
+pub const NULL_INDEX: u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+pub(crate) struct OrderNode {
+    pub order: Order,
+    pub level: u32,
+    pub previous: u32,
+    pub next: u32
+}
+
+impl Default for OrderNode {
+    fn default() -> Self {
+        OrderNode {
+            order: Order {
+                order_id: 0,
+                order_book_id: 0,
+                side: ' ',
+                price: 0,
+                quantity: 0
+            },
+            level: NULL_INDEX,
+            previous: NULL_INDEX,
+            next: NULL_INDEX
+        }
+    }
+}
\ No newline at end of file
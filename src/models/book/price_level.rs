@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceLevel {
+    pub price: i32,
+    pub quantity: u64,
+    pub order_count: u32
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::models::timestamp::Timestamp;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Trade {
+    pub timestamp: Timestamp,
+    pub price: i32,
+    pub quantity: u64
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use std::net::Ipv4Addr;
+
+use crate::models::capture::udp_datagram::UdpDatagram;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CaptureFilter {
+    pub destination_address: Option<Ipv4Addr>,
+    pub destination_port: Option<u16>
+}
+
+impl CaptureFilter {
+    pub fn new(destination_address: Option<Ipv4Addr>, destination_port: Option<u16>) -> Self {
+        CaptureFilter {
+            destination_address,
+            destination_port
+        }
+    }
+
+    pub fn accepts(&self, datagram: &UdpDatagram<'_>) -> bool {
+        self.destination_address.is_none_or(|destination_address| datagram.destination_address == destination_address)
+            && self.destination_port.is_none_or(|destination_port| datagram.destination_port == destination_port)
+    }
+}
\ No newline at end of file
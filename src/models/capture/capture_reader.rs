@@ -0,0 +1,93 @@
+use std::{fs::File, io::{BufReader, Cursor, Read}, path::Path};
+
+use crate::{enums::{capture_file_format::CaptureFileFormat, data_feed_error::DataFeedError}, models::{capture::{capture_filter::CaptureFilter, captured_message::CapturedMessage, pcap_reader::{self, PcapReader}, raw_stream_reader::RawStreamReader}, journal::{journal_reader::JournalReader, journal_writer}}};
+
+const DETECTION_PREFIX_BYTE_COUNT: usize = 8;
+
+type DetectedReader = std::io::Chain<Cursor<Vec<u8>>, Box<dyn Read>>;
+
+enum SourceReader {
+    Journal(JournalReader<DetectedReader>),
+    Pcap(PcapReader<DetectedReader>),
+    Raw(RawStreamReader<DetectedReader>)
+}
+
+pub struct CaptureReader {
+    source: SourceReader
+}
+
+impl CaptureReader {
+    pub fn open<P: AsRef<Path>>(path: P, filter: CaptureFilter) -> Result<Self, DataFeedError> {
+        match path.as_ref().to_str() {
+            Some("-") => CaptureReader::new(Box::new(std::io::stdin().lock()), filter),
+            _ => CaptureReader::new(Box::new(BufReader::new(File::open(path)?)), filter)
+        }
+    }
+
+    pub fn new(reader: Box<dyn Read>, filter: CaptureFilter) -> Result<Self, DataFeedError> {
+        let mut reader = reader;
+        let mut prefix = Vec::with_capacity(DETECTION_PREFIX_BYTE_COUNT);
+        reader.by_ref().take(DETECTION_PREFIX_BYTE_COUNT as u64).read_to_end(&mut prefix)?;
+
+        let is_journal = prefix.as_slice() == journal_writer::JOURNAL_MAGIC;
+        let is_pcap = prefix
+            .first_chunk::<4>()
+            .is_some_and(|magic| CaptureReader::is_pcap_magic(*magic));
+
+        let detected_reader = Cursor::new(prefix).chain(reader);
+
+        let source = if is_journal {
+            SourceReader::Journal(JournalReader::new(detected_reader)?)
+        } else if is_pcap {
+            SourceReader::Pcap(PcapReader::new(detected_reader, filter)?)
+        } else {
+            SourceReader::Raw(RawStreamReader::new(detected_reader))
+        };
+
+        Ok(
+            CaptureReader {
+                source
+            }
+        )
+    }
+
+    pub fn format(&self) -> CaptureFileFormat {
+        match self.source {
+            SourceReader::Journal(_) => CaptureFileFormat::Journal,
+            SourceReader::Pcap(_) => CaptureFileFormat::Pcap,
+            SourceReader::Raw(_) => CaptureFileFormat::Raw
+        }
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<CapturedMessage>, DataFeedError> {
+        match &mut self.source {
+            SourceReader::Journal(journal_reader) => Ok(
+                journal_reader
+                    .next_entry()?
+                    .map(|entry| CapturedMessage {
+                        capture_timestamp: entry.receive_timestamp,
+                        session: String::new(),
+                        sequence_number: entry.sequence_number,
+                        byte_offset: entry.byte_offset + journal_writer::ENTRY_HEADER_BYTE_COUNT as u64,
+                        bytes: entry.bytes
+                    })
+            ),
+            SourceReader::Pcap(pcap_reader) => pcap_reader.next_message(),
+            SourceReader::Raw(raw_stream_reader) => raw_stream_reader.next_message()
+        }
+    }
+
+    fn is_pcap_magic(magic: [u8; 4]) -> bool {
+        [u32::from_le_bytes(magic), u32::from_be_bytes(magic)]
+            .iter()
+            .any(|value| matches!(*value, pcap_reader::PCAP_MICROSECOND_MAGIC | pcap_reader::PCAP_NANOSECOND_MAGIC | pcap_reader::PCAPNG_SECTION_HEADER_BLOCK_TYPE))
+    }
+}
+
+impl Iterator for CaptureReader {
+    type Item = Result<CapturedMessage, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
\ No newline at end of file
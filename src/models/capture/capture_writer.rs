@@ -0,0 +1,85 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use crate::{enums::{capture_file_format::CaptureFileFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}}, models::{capture::{pcap_writer::PcapWriter, pcap_writer_config::PcapWriterConfig, raw_stream_writer::RawStreamWriter}, journal::journal_writer::JournalWriter}};
+
+type OutputStream = BufWriter<Box<dyn Write>>;
+
+enum SinkWriter {
+    Raw(RawStreamWriter<OutputStream>),
+    Journal(JournalWriter<BufWriter<File>, BufWriter<File>>),
+    Pcap(PcapWriter<OutputStream>)
+}
+
+pub struct CaptureWriter {
+    sink: SinkWriter,
+    message_count: u64
+}
+
+impl CaptureWriter {
+    pub fn create<P: AsRef<Path>>(path: P, format: CaptureFileFormat, pcap_writer_config: PcapWriterConfig) -> Result<Self, DataFeedError> {
+        let is_stdout = path.as_ref().to_str() == Some("-");
+
+        let sink = match format {
+            CaptureFileFormat::Journal if is_stdout => return Err(DataFeedErrorKind::Other("A journal cannot be written to standard output because it needs a sidecar index file.".into()).into()),
+            CaptureFileFormat::Journal => SinkWriter::Journal(JournalWriter::open(path)?),
+            CaptureFileFormat::Raw => SinkWriter::Raw(RawStreamWriter::new(CaptureWriter::open_stream(path.as_ref(), is_stdout)?)),
+            CaptureFileFormat::Pcap => SinkWriter::Pcap(PcapWriter::new(CaptureWriter::open_stream(path.as_ref(), is_stdout)?, pcap_writer_config)?)
+        };
+
+        Ok(
+            CaptureWriter {
+                sink,
+                message_count: 0
+            }
+        )
+    }
+
+    pub fn format(&self) -> CaptureFileFormat {
+        match self.sink {
+            SinkWriter::Raw(_) => CaptureFileFormat::Raw,
+            SinkWriter::Journal(_) => CaptureFileFormat::Journal,
+            SinkWriter::Pcap(_) => CaptureFileFormat::Pcap
+        }
+    }
+
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    pub fn write(&mut self, capture_timestamp: u64, sequence_number: u64, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        match &mut self.sink {
+            SinkWriter::Raw(raw_stream_writer) => raw_stream_writer.write_bin(binary_data)?,
+            SinkWriter::Journal(journal_writer) => journal_writer.write(capture_timestamp, sequence_number, binary_data)?,
+            SinkWriter::Pcap(pcap_writer) => pcap_writer.write_bytes(capture_timestamp, binary_data)?
+        }
+
+        self.message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        match &mut self.sink {
+            SinkWriter::Raw(raw_stream_writer) => raw_stream_writer.flush(),
+            SinkWriter::Journal(journal_writer) => journal_writer.flush(),
+            SinkWriter::Pcap(pcap_writer) => pcap_writer.flush_packet()
+        }
+    }
+
+    pub fn finish(self) -> Result<(), DataFeedError> {
+        match self.sink {
+            SinkWriter::Raw(raw_stream_writer) => raw_stream_writer.finish().map(drop),
+            SinkWriter::Journal(journal_writer) => journal_writer.finish().map(drop),
+            SinkWriter::Pcap(pcap_writer) => pcap_writer.finish().map(drop)
+        }
+    }
+
+    fn open_stream(path: &Path, is_stdout: bool) -> Result<OutputStream, DataFeedError> {
+        let stream: Box<dyn Write> = match is_stdout {
+            true => Box::new(std::io::stdout().lock()),
+            false => Box::new(File::create(path)?)
+        };
+
+        Ok(BufWriter::new(stream))
+    }
+}
\ No newline at end of file
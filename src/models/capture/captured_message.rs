@@ -0,0 +1,30 @@
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::{field_validator::FieldValidator, message_decoder_registry::MessageDecoderRegistry}};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedMessage {
+    pub capture_timestamp: u64,
+    pub session: String,
+    pub sequence_number: u64,
+    pub byte_offset: u64,
+    pub bytes: Vec<u8>
+}
+
+impl CapturedMessage {
+    pub fn decode(&self) -> Result<ItchMessage, DataFeedError> {
+        ItchMessage::from_bin(&self.bytes).map_err(|error| self.with_position(error))
+    }
+
+    pub fn decode_with(&self, validator: &mut FieldValidator, message_decoders: &MessageDecoderRegistry) -> Result<ItchMessage, DataFeedError> {
+        message_decoders.decode_with(&self.bytes, validator).map_err(|error| self.with_position(error))
+    }
+
+    pub fn with_position(&self, error: DataFeedError) -> DataFeedError {
+        error.with_byte_offset(self.byte_offset).with_sequence_number(self.sequence_number)
+    }
+}
+
+impl AsRef<[u8]> for CapturedMessage {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedPacket {
+    pub capture_timestamp: u64,
+    pub link_type: u32,
+    pub byte_offset: u64,
+    pub bytes: Vec<u8>
+}
\ No newline at end of file
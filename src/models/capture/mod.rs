@@ -0,0 +1,14 @@
+pub mod capture_filter;
+pub mod capture_reader;
+pub mod capture_writer;
+pub mod captured_message;
+pub mod captured_packet;
+pub mod mold_udp64_packet;
+pub mod mold_udp64_publisher;
+pub mod mold_udp64_publisher_config;
+pub mod pcap_reader;
+pub mod pcap_writer;
+pub mod pcap_writer_config;
+pub mod raw_stream_reader;
+pub mod raw_stream_writer;
+pub mod udp_datagram;
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+pub const HEADER_BYTE_COUNT: usize = 20;
+pub const SESSION_LENGTH: usize = 10;
+pub const END_OF_SESSION_MESSAGE_COUNT: u16 = 0xFFFF;
+
+const SESSION_BYTE_OFFSET: usize = 0;
+const SEQUENCE_NUMBER_BYTE_OFFSET: usize = 10;
+const MESSAGE_COUNT_BYTE_OFFSET: usize = 18;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoldUdp64Packet<'a> {
+    pub session: [u8; SESSION_LENGTH],
+    pub sequence_number: u64,
+    pub message_count: u16,
+    pub payload: &'a [u8]
+}
+
+impl<'a> MoldUdp64Packet<'a> {
+    pub fn parse(binary_data: &'a [u8]) -> Result<Self, DataFeedError> {
+        if binary_data.len() < HEADER_BYTE_COUNT {
+            return Err(DataFeedErrorKind::MalformedCapture(format!("A MoldUDP64 packet needs at least {HEADER_BYTE_COUNT} bytes but only {} were received.", binary_data.len())).into());
+        }
+
+        let packet = MoldUdp64Packet {
+            session: binary_data[SESSION_BYTE_OFFSET..SESSION_BYTE_OFFSET + SESSION_LENGTH].try_into().unwrap(),
+            sequence_number: u64::from_be_bytes(binary_data[SEQUENCE_NUMBER_BYTE_OFFSET..SEQUENCE_NUMBER_BYTE_OFFSET + 8].try_into().unwrap()),
+            message_count: u16::from_be_bytes(binary_data[MESSAGE_COUNT_BYTE_OFFSET..MESSAGE_COUNT_BYTE_OFFSET + 2].try_into().unwrap()),
+            payload: &binary_data[HEADER_BYTE_COUNT..]
+        };
+
+        packet.validate()?;
+
+        Ok(packet)
+    }
+
+    pub fn session_str(&self) -> String {
+        self.session
+            .iter()
+            .map(|byte| *byte as char)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    pub fn is_heartbeat(&self) -> bool {
+        self.message_count == 0
+    }
+
+    pub fn is_end_of_session(&self) -> bool {
+        self.message_count == END_OF_SESSION_MESSAGE_COUNT
+    }
+
+    pub fn messages(&self) -> impl Iterator<Item = &'a [u8]> {
+        let message_count = if self.is_end_of_session() { 0 } else { self.message_count };
+        let mut remaining = self.payload;
+
+        (0..message_count).map_while(move |_| {
+            let length = u16::from_be_bytes([*remaining.first()?, *remaining.get(1)?]) as usize;
+            let message = remaining.get(2..2 + length)?;
+            remaining = &remaining[2 + length..];
+
+            Some(message)
+        })
+    }
+
+    fn validate(&self) -> Result<(), DataFeedError> {
+        if self.is_end_of_session() {
+            return Ok(());
+        }
+
+        let mut remaining = self.payload;
+
+        for message_index in 0..self.message_count {
+            let length = match remaining {
+                [high, low, ..] => u16::from_be_bytes([*high, *low]) as usize,
+                _ => return Err(DataFeedErrorKind::MalformedCapture(format!("The MoldUDP64 packet ends before message block {message_index} of {}.", self.message_count)).into())
+            };
+
+            if remaining.len() < 2 + length {
+                return Err(DataFeedErrorKind::MalformedCapture(format!("MoldUDP64 message block {message_index} declares {length} bytes but only {} remain.", remaining.len() - 2)).into());
+            }
+
+            remaining = &remaining[2 + length..];
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use std::net::{SocketAddrV4, UdpSocket};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, global_constants, models::capture::{mold_udp64_packet, mold_udp64_publisher_config::MoldUdp64PublisherConfig}};
+
+const MAX_UDP_PAYLOAD_BYTE_COUNT: usize = 65507;
+
+pub struct MoldUdp64Publisher {
+    socket: UdpSocket,
+    config: MoldUdp64PublisherConfig,
+    next_sequence_number: u64,
+    batch_message_count: u16,
+    batch_payload: Vec<u8>,
+    packet: Vec<u8>,
+    packet_count: u64
+}
+
+impl MoldUdp64Publisher {
+    pub fn new(config: MoldUdp64PublisherConfig) -> Result<Self, DataFeedError> {
+        if config.max_payload_byte_count < mold_udp64_packet::HEADER_BYTE_COUNT + 2 + global_constants::MAX_MESSAGE_BYTE_COUNT || config.max_payload_byte_count > MAX_UDP_PAYLOAD_BYTE_COUNT {
+            return Err(DataFeedErrorKind::Other(format!("The maximum payload size must fit the largest message and a UDP datagram but was {}.", config.max_payload_byte_count)).into());
+        }
+
+        if config.max_messages_per_packet == 0 || config.max_messages_per_packet == mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT {
+            return Err(DataFeedErrorKind::Other(format!("The maximum messages per packet must be between 1 and {} but was {}.", mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT - 1, config.max_messages_per_packet)).into());
+        }
+
+        let socket = UdpSocket::bind(SocketAddrV4::new(config.interface, 0))?;
+
+        if config.destination.ip().is_multicast() {
+            socket.set_multicast_ttl_v4(config.time_to_live)?;
+            socket.set_multicast_loop_v4(config.loopback)?;
+        } else {
+            socket.set_ttl(config.time_to_live)?;
+        }
+
+        Ok(
+            MoldUdp64Publisher {
+                socket,
+                config,
+                next_sequence_number: config.first_sequence_number,
+                batch_message_count: 0,
+                batch_payload: Vec::with_capacity(config.max_payload_byte_count),
+                packet: Vec::with_capacity(config.max_payload_byte_count),
+                packet_count: 0
+            }
+        )
+    }
+
+    pub fn config(&self) -> MoldUdp64PublisherConfig {
+        self.config
+    }
+
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_sequence_number + self.batch_message_count as u64
+    }
+
+    pub fn packet_count(&self) -> u64 {
+        self.packet_count
+    }
+
+    pub fn publish_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        self.publish_bytes(&message.to_bin()?)
+    }
+
+    pub fn publish_bytes(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        if binary_data.len() > global_constants::MAX_MESSAGE_BYTE_COUNT {
+            return Err(DataFeedErrorKind::InvalidMessageSize(global_constants::MAX_MESSAGE_BYTE_COUNT, binary_data.len()).into());
+        }
+
+        let is_batch_full = mold_udp64_packet::HEADER_BYTE_COUNT + self.batch_payload.len() + 2 + binary_data.len() > self.config.max_payload_byte_count
+            || self.batch_message_count >= self.config.max_messages_per_packet;
+
+        if is_batch_full {
+            self.flush()?;
+        }
+
+        self.batch_payload.extend_from_slice(&(binary_data.len() as u16).to_be_bytes());
+        self.batch_payload.extend_from_slice(binary_data);
+        self.batch_message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn heartbeat(&mut self) -> Result<(), DataFeedError> {
+        self.flush()?;
+        self.send_packet(0)
+    }
+
+    pub fn end_of_session(&mut self) -> Result<(), DataFeedError> {
+        self.flush()?;
+        self.send_packet(mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT)
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        if self.batch_message_count == 0 {
+            return Ok(());
+        }
+
+        self.send_packet(self.batch_message_count)?;
+        self.next_sequence_number += self.batch_message_count as u64;
+        self.batch_message_count = 0;
+        self.batch_payload.clear();
+
+        Ok(())
+    }
+
+    fn send_packet(&mut self, message_count: u16) -> Result<(), DataFeedError> {
+        self.packet.clear();
+        self.packet.extend_from_slice(&self.config.session);
+        self.packet.extend_from_slice(&self.next_sequence_number.to_be_bytes());
+        self.packet.extend_from_slice(&message_count.to_be_bytes());
+        self.packet.extend_from_slice(&self.batch_payload);
+
+        self.socket.send_to(&self.packet, self.config.destination)?;
+        self.packet_count += 1;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use crate::models::capture::mold_udp64_packet;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoldUdp64PublisherConfig {
+    pub destination: SocketAddrV4,
+    pub interface: Ipv4Addr,
+    pub time_to_live: u32,
+    pub loopback: bool,
+    pub session: [u8; mold_udp64_packet::SESSION_LENGTH],
+    pub first_sequence_number: u64,
+    pub max_payload_byte_count: usize,
+    pub max_messages_per_packet: u16
+}
+
+impl Default for MoldUdp64PublisherConfig {
+    fn default() -> Self {
+        MoldUdp64PublisherConfig {
+            destination: SocketAddrV4::new(Ipv4Addr::new(233, 71, 185, 8), 21001),
+            interface: Ipv4Addr::UNSPECIFIED,
+            time_to_live: 1,
+            loopback: true,
+            session: *b"0000000001",
+            first_sequence_number: 1,
+            max_payload_byte_count: 1400,
+            max_messages_per_packet: 64
+        }
+    }
+}
\ No newline at end of file
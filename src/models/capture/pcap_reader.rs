@@ -0,0 +1,367 @@
+use std::{collections::VecDeque, fs::File, io::{BufReader, Read}, path::Path};
+
+use crate::{enums::{capture_format::CaptureFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}}, models::capture::{capture_filter::CaptureFilter, captured_message::CapturedMessage, captured_packet::CapturedPacket, mold_udp64_packet::MoldUdp64Packet}, util::{io_utils::{self, CountingReader}, packet_parser}};
+
+pub(crate) const PCAP_MICROSECOND_MAGIC: u32 = 0xA1B2_C3D4;
+pub(crate) const PCAP_NANOSECOND_MAGIC: u32 = 0xA1B2_3C4D;
+pub(crate) const PCAPNG_SECTION_HEADER_BLOCK_TYPE: u32 = 0x0A0D_0D0A;
+pub(crate) const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+pub(crate) const PCAPNG_INTERFACE_DESCRIPTION_BLOCK_TYPE: u32 = 1;
+pub(crate) const PCAPNG_SIMPLE_PACKET_BLOCK_TYPE: u32 = 3;
+pub(crate) const PCAPNG_ENHANCED_PACKET_BLOCK_TYPE: u32 = 6;
+pub(crate) const PCAP_GLOBAL_HEADER_BYTE_COUNT: usize = 24;
+pub(crate) const PCAP_RECORD_HEADER_BYTE_COUNT: usize = 16;
+
+const PCAPNG_END_OF_OPTIONS_CODE: u16 = 0;
+const PCAPNG_TIMESTAMP_RESOLUTION_OPTION_CODE: u16 = 9;
+const PCAPNG_DEFAULT_TIMESTAMP_RESOLUTION: u8 = 6;
+const PCAPNG_SECTION_HEADER_MINIMUM_BYTE_COUNT: usize = 28;
+const MAX_CAPTURE_BLOCK_BYTE_COUNT: usize = 1 << 24;
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+#[derive(Clone, Copy)]
+struct Interface {
+    link_type: u32,
+    timestamp_resolution: u8
+}
+
+pub struct PcapReader<R> {
+    reader: CountingReader<R>,
+    format: CaptureFormat,
+    is_big_endian: bool,
+    is_nanosecond_resolution: bool,
+    link_type: u32,
+    interfaces: Vec<Interface>,
+    filter: CaptureFilter,
+    pending_messages: VecDeque<CapturedMessage>,
+    packet_count: u64,
+    malformed_packet_count: u64
+}
+
+impl PcapReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P, filter: CaptureFilter) -> Result<Self, DataFeedError> {
+        PcapReader::new(BufReader::new(File::open(path)?), filter)
+    }
+}
+
+impl<R: Read> PcapReader<R> {
+    pub fn new(reader: R, filter: CaptureFilter) -> Result<Self, DataFeedError> {
+        let mut pcap_reader = PcapReader {
+            reader: CountingReader::new(reader),
+            format: CaptureFormat::Pcap,
+            is_big_endian: false,
+            is_nanosecond_resolution: false,
+            link_type: packet_parser::LINK_TYPE_ETHERNET,
+            interfaces: Vec::new(),
+            filter,
+            pending_messages: VecDeque::new(),
+            packet_count: 0,
+            malformed_packet_count: 0
+        };
+
+        let mut magic = [0u8; 4];
+        pcap_reader.reader.read_exact(&mut magic)?;
+
+        if u32::from_le_bytes(magic) == PCAPNG_SECTION_HEADER_BLOCK_TYPE {
+            pcap_reader.format = CaptureFormat::PcapNg;
+            pcap_reader.read_block_after_type(magic)?;
+
+            return Ok(pcap_reader);
+        }
+
+        match (u32::from_le_bytes(magic), u32::from_be_bytes(magic)) {
+            (PCAP_MICROSECOND_MAGIC, _) => {},
+            (PCAP_NANOSECOND_MAGIC, _) => pcap_reader.is_nanosecond_resolution = true,
+            (_, PCAP_MICROSECOND_MAGIC) => pcap_reader.is_big_endian = true,
+            (_, PCAP_NANOSECOND_MAGIC) => {
+                pcap_reader.is_big_endian = true;
+                pcap_reader.is_nanosecond_resolution = true;
+            },
+            _ => return Err(DataFeedErrorKind::MalformedCapture("The file is neither a pcap nor a pcapng capture.".into()).into())
+        }
+
+        let mut global_header = [0u8; PCAP_GLOBAL_HEADER_BYTE_COUNT - 4];
+        pcap_reader.reader.read_exact(&mut global_header)?;
+        pcap_reader.link_type = pcap_reader.read_u32(&global_header, 16) & 0xFFFF;
+
+        Ok(pcap_reader)
+    }
+
+    pub fn format(&self) -> CaptureFormat {
+        self.format
+    }
+
+    pub fn filter(&self) -> CaptureFilter {
+        self.filter
+    }
+
+    pub fn packet_count(&self) -> u64 {
+        self.packet_count
+    }
+
+    pub fn malformed_packet_count(&self) -> u64 {
+        self.malformed_packet_count
+    }
+
+    pub fn next_packet(&mut self) -> Result<Option<CapturedPacket>, DataFeedError> {
+        let packet = match self.format {
+            CaptureFormat::Pcap => self.next_pcap_packet()?,
+            CaptureFormat::PcapNg => self.next_pcapng_packet()?
+        };
+
+        if packet.is_some() {
+            self.packet_count += 1;
+        }
+
+        Ok(packet)
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<CapturedMessage>, DataFeedError> {
+        loop {
+            if let Some(message) = self.pending_messages.pop_front() {
+                return Ok(Some(message));
+            }
+
+            let Some(packet) = self.next_packet()? else {
+                return Ok(None);
+            };
+
+            let Some(datagram) = packet_parser::parse_udp_datagram(&packet.bytes, packet.link_type) else {
+                continue;
+            };
+
+            if !self.filter.accepts(&datagram) {
+                continue;
+            }
+
+            let Ok(mold_packet) = MoldUdp64Packet::parse(datagram.payload) else {
+                self.malformed_packet_count += 1;
+                continue;
+            };
+
+            let session = mold_packet.session_str();
+            let frame_address = packet.bytes.as_ptr() as usize;
+
+            for (message_index, bytes) in mold_packet.messages().enumerate() {
+                self.pending_messages.push_back(
+                    CapturedMessage {
+                        capture_timestamp: packet.capture_timestamp,
+                        session: session.clone(),
+                        sequence_number: mold_packet.sequence_number + message_index as u64,
+                        byte_offset: packet.byte_offset + (bytes.as_ptr() as usize - frame_address) as u64,
+                        bytes: bytes.to_vec()
+                    }
+                );
+            }
+        }
+    }
+
+    fn next_pcap_packet(&mut self) -> Result<Option<CapturedPacket>, DataFeedError> {
+        let mut record_header = [0u8; PCAP_RECORD_HEADER_BYTE_COUNT];
+
+        if !io_utils::read_exact_or_eof(&mut self.reader, &mut record_header, "pcap record header")? {
+            return Ok(None);
+        }
+
+        let seconds = self.read_u32(&record_header, 0) as u64;
+        let fraction = self.read_u32(&record_header, 4) as u64;
+        let captured_length = self.read_u32(&record_header, 8) as usize;
+
+        if captured_length > MAX_CAPTURE_BLOCK_BYTE_COUNT {
+            return Err(DataFeedErrorKind::MalformedCapture(format!("A pcap record declares an implausible length of {captured_length} bytes.")).into());
+        }
+
+        let byte_offset = self.reader.byte_count();
+        let mut bytes = vec![0u8; captured_length];
+
+        if !io_utils::read_exact_or_eof(&mut self.reader, &mut bytes, "pcap record")? {
+            return Err(DataFeedErrorKind::MalformedCapture("The pcap record is truncated.".into()).into());
+        }
+
+        let fraction_nanoseconds = if self.is_nanosecond_resolution { fraction } else { fraction * 1_000 };
+
+        Ok(
+            Some(
+                CapturedPacket {
+                    capture_timestamp: seconds * NANOSECONDS_PER_SECOND + fraction_nanoseconds,
+                    link_type: self.link_type,
+                    byte_offset,
+                    bytes
+                }
+            )
+        )
+    }
+
+    fn next_pcapng_packet(&mut self) -> Result<Option<CapturedPacket>, DataFeedError> {
+        loop {
+            let block_offset = self.reader.byte_count();
+            let mut block_type = [0u8; 4];
+
+            if !io_utils::read_exact_or_eof(&mut self.reader, &mut block_type, "pcapng block header")? {
+                return Ok(None);
+            }
+
+            let (block_type, body) = self.read_block_after_type(block_type)?;
+
+            match block_type {
+                PCAPNG_INTERFACE_DESCRIPTION_BLOCK_TYPE => {
+                    let interface = self.parse_interface(&body)?;
+                    self.interfaces.push(interface);
+                },
+                PCAPNG_ENHANCED_PACKET_BLOCK_TYPE => {
+                    if body.len() < 20 {
+                        return Err(DataFeedErrorKind::MalformedCapture("The enhanced packet block is too short.".into()).into());
+                    }
+
+                    let interface_id = self.read_u32(&body, 0) as usize;
+                    let timestamp = ((self.read_u32(&body, 4) as u64) << 32) | self.read_u32(&body, 8) as u64;
+                    let captured_length = self.read_u32(&body, 12) as usize;
+
+                    let Some(interface) = self.interfaces.get(interface_id).copied() else {
+                        return Err(DataFeedErrorKind::MalformedCapture(format!("The enhanced packet block references the undeclared interface {interface_id}.")).into());
+                    };
+
+                    let Some(bytes) = body.get(20..20 + captured_length) else {
+                        return Err(DataFeedErrorKind::MalformedCapture("The enhanced packet block is shorter than its captured length.".into()).into());
+                    };
+
+                    return Ok(
+                        Some(
+                            CapturedPacket {
+                                capture_timestamp: PcapReader::<R>::to_nanoseconds(timestamp, interface.timestamp_resolution),
+                                link_type: interface.link_type,
+                                byte_offset: block_offset + 28,
+                                bytes: bytes.to_vec()
+                            }
+                        )
+                    );
+                },
+                PCAPNG_SIMPLE_PACKET_BLOCK_TYPE => {
+                    let Some(interface) = self.interfaces.first().copied() else {
+                        return Err(DataFeedErrorKind::MalformedCapture("A simple packet block appeared before any interface was declared.".into()).into());
+                    };
+
+                    if body.len() < 4 {
+                        return Err(DataFeedErrorKind::MalformedCapture("The simple packet block is too short.".into()).into());
+                    }
+
+                    let original_length = self.read_u32(&body, 0) as usize;
+                    let bytes = &body[4..(4 + original_length).min(body.len())];
+
+                    return Ok(
+                        Some(
+                            CapturedPacket {
+                                capture_timestamp: 0,
+                                link_type: interface.link_type,
+                                byte_offset: block_offset + 12,
+                                bytes: bytes.to_vec()
+                            }
+                        )
+                    );
+                },
+                PCAPNG_SECTION_HEADER_BLOCK_TYPE => self.interfaces.clear(),
+                _ => {}
+            }
+        }
+    }
+
+    fn read_block_after_type(&mut self, block_type: [u8; 4]) -> Result<(u32, Vec<u8>), DataFeedError> {
+        let mut block_length = [0u8; 4];
+        self.reader.read_exact(&mut block_length)?;
+
+        let is_section_header = u32::from_le_bytes(block_type) == PCAPNG_SECTION_HEADER_BLOCK_TYPE;
+        let mut byte_order_magic = [0u8; 4];
+
+        if is_section_header {
+            self.reader.read_exact(&mut byte_order_magic)?;
+
+            self.is_big_endian = match (u32::from_le_bytes(byte_order_magic), u32::from_be_bytes(byte_order_magic)) {
+                (PCAPNG_BYTE_ORDER_MAGIC, _) => false,
+                (_, PCAPNG_BYTE_ORDER_MAGIC) => true,
+                _ => return Err(DataFeedErrorKind::MalformedCapture("The pcapng section header has an invalid byte-order magic.".into()).into())
+            };
+        }
+
+        let block_type = self.read_u32(&block_type, 0);
+        let block_length = self.read_u32(&block_length, 0) as usize;
+        let consumed_length = if is_section_header { 12 } else { 8 };
+        let minimum_length = if is_section_header { PCAPNG_SECTION_HEADER_MINIMUM_BYTE_COUNT } else { consumed_length + 4 };
+
+        if block_length < minimum_length || !block_length.is_multiple_of(4) || block_length > MAX_CAPTURE_BLOCK_BYTE_COUNT {
+            return Err(DataFeedErrorKind::MalformedCapture(format!("The pcapng block declares an invalid length of {block_length} bytes.")).into());
+        }
+
+        let mut body = vec![0u8; block_length - consumed_length];
+        self.reader.read_exact(&mut body)?;
+        body.truncate(body.len() - 4);
+
+        if is_section_header {
+            body.splice(0..0, byte_order_magic);
+        }
+
+        Ok((block_type, body))
+    }
+
+    fn parse_interface(&self, body: &[u8]) -> Result<Interface, DataFeedError> {
+        if body.len() < 8 {
+            return Err(DataFeedErrorKind::MalformedCapture("The interface description block is too short.".into()).into());
+        }
+
+        let mut interface = Interface {
+            link_type: self.read_u16(body, 0) as u32,
+            timestamp_resolution: PCAPNG_DEFAULT_TIMESTAMP_RESOLUTION
+        };
+
+        let mut options = &body[8..];
+
+        while options.len() >= 4 {
+            let option_code = self.read_u16(options, 0);
+            let option_length = self.read_u16(options, 2) as usize;
+
+            if option_code == PCAPNG_END_OF_OPTIONS_CODE {
+                break;
+            }
+
+            if option_code == PCAPNG_TIMESTAMP_RESOLUTION_OPTION_CODE && option_length >= 1 && options.len() > 4 {
+                interface.timestamp_resolution = options[4];
+            }
+
+            options = options.get(4 + option_length.div_ceil(4) * 4..).unwrap_or_default();
+        }
+
+        Ok(interface)
+    }
+
+    fn to_nanoseconds(timestamp: u64, timestamp_resolution: u8) -> u64 {
+        let exponent = (timestamp_resolution & 0x7F) as u32;
+
+        if timestamp_resolution & 0x80 != 0 {
+            return ((timestamp as u128 * NANOSECONDS_PER_SECOND as u128) >> exponent) as u64;
+        }
+
+        match exponent {
+            0..=9 => timestamp.saturating_mul(10u64.pow(9 - exponent)),
+            _ => timestamp / 10u64.saturating_pow(exponent - 9)
+        }
+    }
+
+    fn read_u16(&self, buf: &[u8], offset: usize) -> u16 {
+        let bytes = [buf[offset], buf[offset + 1]];
+
+        if self.is_big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) }
+    }
+
+    fn read_u32(&self, buf: &[u8], offset: usize) -> u32 {
+        let bytes = [buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]];
+
+        if self.is_big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) }
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = Result<CapturedMessage, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
\ No newline at end of file
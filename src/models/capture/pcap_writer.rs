@@ -0,0 +1,199 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, global_constants, models::capture::{mold_udp64_packet, pcap_reader, pcap_writer_config::PcapWriterConfig}, util::packet_parser};
+
+const SNAPSHOT_LENGTH: u32 = 65535;
+const ETHER_TYPE_VLAN: [u8; 2] = [0x81, 0x00];
+const ETHER_TYPE_IPV4: [u8; 2] = [0x08, 0x00];
+const IPV4_HEADER_BYTE_COUNT: usize = 20;
+const UDP_HEADER_BYTE_COUNT: usize = 8;
+const IPV4_TIME_TO_LIVE: u8 = 32;
+const IP_PROTOCOL_UDP: u8 = 17;
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+pub struct PcapWriter<W: Write> {
+    writer: W,
+    config: PcapWriterConfig,
+    next_sequence_number: u64,
+    batch_timestamp: u64,
+    batch_message_count: u16,
+    batch_payload: Vec<u8>,
+    frame: Vec<u8>,
+    packet_count: u64,
+    identification: u16
+}
+
+impl PcapWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P, config: PcapWriterConfig) -> Result<Self, DataFeedError> {
+        PcapWriter::new(BufWriter::new(File::create(path)?), config)
+    }
+}
+
+impl<W: Write> PcapWriter<W> {
+    pub fn new(writer: W, config: PcapWriterConfig) -> Result<Self, DataFeedError> {
+        let max_udp_payload_byte_count = u16::MAX as usize - IPV4_HEADER_BYTE_COUNT - UDP_HEADER_BYTE_COUNT;
+
+        if config.max_payload_byte_count < mold_udp64_packet::HEADER_BYTE_COUNT + 2 + global_constants::MAX_MESSAGE_BYTE_COUNT || config.max_payload_byte_count > max_udp_payload_byte_count {
+            return Err(DataFeedErrorKind::Other(format!("The maximum payload size must fit the largest message and a UDP datagram but was {}.", config.max_payload_byte_count)).into());
+        }
+
+        if config.max_messages_per_packet == 0 || config.max_messages_per_packet == mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT {
+            return Err(DataFeedErrorKind::Other(format!("The maximum messages per packet must be between 1 and {} but was {}.", mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT - 1, config.max_messages_per_packet)).into());
+        }
+
+        let mut pcap_writer = PcapWriter {
+            writer,
+            config,
+            next_sequence_number: config.first_sequence_number,
+            batch_timestamp: 0,
+            batch_message_count: 0,
+            batch_payload: Vec::with_capacity(config.max_payload_byte_count),
+            frame: Vec::with_capacity(config.max_payload_byte_count + 64),
+            packet_count: 0,
+            identification: 0
+        };
+
+        pcap_writer.writer.write_all(&pcap_reader::PCAP_NANOSECOND_MAGIC.to_le_bytes())?;
+        pcap_writer.writer.write_all(&2u16.to_le_bytes())?;
+        pcap_writer.writer.write_all(&4u16.to_le_bytes())?;
+        pcap_writer.writer.write_all(&0i32.to_le_bytes())?;
+        pcap_writer.writer.write_all(&0u32.to_le_bytes())?;
+        pcap_writer.writer.write_all(&SNAPSHOT_LENGTH.to_le_bytes())?;
+        pcap_writer.writer.write_all(&packet_parser::LINK_TYPE_ETHERNET.to_le_bytes())?;
+
+        Ok(pcap_writer)
+    }
+
+    pub fn config(&self) -> PcapWriterConfig {
+        self.config
+    }
+
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_sequence_number + self.batch_message_count as u64
+    }
+
+    pub fn packet_count(&self) -> u64 {
+        self.packet_count
+    }
+
+    pub fn write_message(&mut self, capture_timestamp: u64, message: &ItchMessage) -> Result<(), DataFeedError> {
+        self.write_bytes(capture_timestamp, &message.to_bin()?)
+    }
+
+    pub fn write_bytes(&mut self, capture_timestamp: u64, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        if binary_data.len() > global_constants::MAX_MESSAGE_BYTE_COUNT {
+            return Err(DataFeedErrorKind::InvalidMessageSize(global_constants::MAX_MESSAGE_BYTE_COUNT, binary_data.len()).into());
+        }
+
+        let is_batch_full = mold_udp64_packet::HEADER_BYTE_COUNT + self.batch_payload.len() + 2 + binary_data.len() > self.config.max_payload_byte_count
+            || self.batch_message_count >= self.config.max_messages_per_packet;
+
+        if self.batch_message_count > 0 && (capture_timestamp != self.batch_timestamp || is_batch_full) {
+            self.flush_packet()?;
+        }
+
+        if self.batch_message_count == 0 {
+            self.batch_timestamp = capture_timestamp;
+        }
+
+        self.batch_payload.extend_from_slice(&(binary_data.len() as u16).to_be_bytes());
+        self.batch_payload.extend_from_slice(binary_data);
+        self.batch_message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn write_heartbeat(&mut self, capture_timestamp: u64) -> Result<(), DataFeedError> {
+        self.flush_packet()?;
+        self.write_packet(capture_timestamp, 0)
+    }
+
+    pub fn write_end_of_session(&mut self, capture_timestamp: u64) -> Result<(), DataFeedError> {
+        self.flush_packet()?;
+        self.write_packet(capture_timestamp, mold_udp64_packet::END_OF_SESSION_MESSAGE_COUNT)
+    }
+
+    pub fn flush_packet(&mut self) -> Result<(), DataFeedError> {
+        if self.batch_message_count == 0 {
+            return Ok(());
+        }
+
+        self.write_packet(self.batch_timestamp, self.batch_message_count)?;
+        self.next_sequence_number += self.batch_message_count as u64;
+        self.batch_message_count = 0;
+        self.batch_payload.clear();
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, DataFeedError> {
+        self.flush_packet()?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    fn write_packet(&mut self, capture_timestamp: u64, message_count: u16) -> Result<(), DataFeedError> {
+        let udp_length = UDP_HEADER_BYTE_COUNT + mold_udp64_packet::HEADER_BYTE_COUNT + self.batch_payload.len();
+        let ipv4_length = IPV4_HEADER_BYTE_COUNT + udp_length;
+        let config = self.config;
+
+        self.frame.clear();
+        self.frame.extend_from_slice(&config.destination_mac);
+        self.frame.extend_from_slice(&config.source_mac);
+
+        if let Some(vlan_id) = config.vlan_id {
+            self.frame.extend_from_slice(&ETHER_TYPE_VLAN);
+            self.frame.extend_from_slice(&(vlan_id & 0x0FFF).to_be_bytes());
+        }
+
+        self.frame.extend_from_slice(&ETHER_TYPE_IPV4);
+
+        let ipv4_offset = self.frame.len();
+        self.frame.extend_from_slice(&[0x45, 0x00]);
+        self.frame.extend_from_slice(&(ipv4_length as u16).to_be_bytes());
+        self.frame.extend_from_slice(&self.identification.to_be_bytes());
+        self.frame.extend_from_slice(&[0x40, 0x00, IPV4_TIME_TO_LIVE, IP_PROTOCOL_UDP, 0x00, 0x00]);
+        self.frame.extend_from_slice(&config.source_address.octets());
+        self.frame.extend_from_slice(&config.destination_address.octets());
+
+        let checksum = PcapWriter::<W>::ipv4_checksum(&self.frame[ipv4_offset..ipv4_offset + IPV4_HEADER_BYTE_COUNT]);
+        self.frame[ipv4_offset + 10..ipv4_offset + 12].copy_from_slice(&checksum.to_be_bytes());
+
+        self.frame.extend_from_slice(&config.source_port.to_be_bytes());
+        self.frame.extend_from_slice(&config.destination_port.to_be_bytes());
+        self.frame.extend_from_slice(&(udp_length as u16).to_be_bytes());
+        self.frame.extend_from_slice(&[0x00, 0x00]);
+
+        self.frame.extend_from_slice(&config.session);
+        self.frame.extend_from_slice(&self.next_sequence_number.to_be_bytes());
+        self.frame.extend_from_slice(&message_count.to_be_bytes());
+        self.frame.extend_from_slice(&self.batch_payload);
+
+        let frame_length = self.frame.len() as u32;
+
+        self.writer.write_all(&((capture_timestamp / NANOSECONDS_PER_SECOND) as u32).to_le_bytes())?;
+        self.writer.write_all(&((capture_timestamp % NANOSECONDS_PER_SECOND) as u32).to_le_bytes())?;
+        self.writer.write_all(&frame_length.to_le_bytes())?;
+        self.writer.write_all(&frame_length.to_le_bytes())?;
+        self.writer.write_all(&self.frame)?;
+
+        self.identification = self.identification.wrapping_add(1);
+        self.packet_count += 1;
+
+        Ok(())
+    }
+
+    fn ipv4_checksum(header: &[u8]) -> u16 {
+        let mut sum = header
+            .chunks_exact(2)
+            .map(|word| u16::from_be_bytes([word[0], word[1]]) as u32)
+            .sum::<u32>();
+
+        while sum > 0xFFFF {
+            sum = (sum & 0xFFFF) + (sum >> 16);
+        }
+
+        !(sum as u16)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::net::Ipv4Addr;
+
+use crate::models::capture::mold_udp64_packet;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PcapWriterConfig {
+    pub source_mac: [u8; 6],
+    pub destination_mac: [u8; 6],
+    pub vlan_id: Option<u16>,
+    pub source_address: Ipv4Addr,
+    pub destination_address: Ipv4Addr,
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub session: [u8; mold_udp64_packet::SESSION_LENGTH],
+    pub first_sequence_number: u64,
+    pub max_payload_byte_count: usize,
+    pub max_messages_per_packet: u16
+}
+
+impl PcapWriterConfig {
+    pub fn multicast_mac(destination_address: Ipv4Addr) -> [u8; 6] {
+        let octets = destination_address.octets();
+
+        [0x01, 0x00, 0x5E, octets[1] & 0x7F, octets[2], octets[3]]
+    }
+}
+
+impl Default for PcapWriterConfig {
+    fn default() -> Self {
+        let destination_address = Ipv4Addr::new(233, 71, 185, 8);
+
+        PcapWriterConfig {
+            source_mac: [0x02, 0x00, 0x00, 0x00, 0x00, 0x01],
+            destination_mac: PcapWriterConfig::multicast_mac(destination_address),
+            vlan_id: None,
+            source_address: Ipv4Addr::new(10, 0, 0, 1),
+            destination_address,
+            source_port: 21001,
+            destination_port: 21001,
+            session: *b"0000000001",
+            first_sequence_number: 1,
+            max_payload_byte_count: 1400,
+            max_messages_per_packet: 64
+        }
+    }
+}
\ No newline at end of file
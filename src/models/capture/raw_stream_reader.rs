@@ -0,0 +1,66 @@
+use std::{fs::File, io::{BufReader, Read}, path::Path};
+
+use crate::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, models::capture::captured_message::CapturedMessage, util::io_utils::{self, CountingReader}};
+
+pub const LENGTH_PREFIX_BYTE_COUNT: usize = 2;
+
+pub struct RawStreamReader<R> {
+    reader: CountingReader<R>,
+    message_count: u64
+}
+
+impl RawStreamReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(RawStreamReader::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: Read> RawStreamReader<R> {
+    pub fn new(reader: R) -> Self {
+        RawStreamReader {
+            reader: CountingReader::new(reader),
+            message_count: 0
+        }
+    }
+
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<CapturedMessage>, DataFeedError> {
+        let mut length_prefix = [0u8; LENGTH_PREFIX_BYTE_COUNT];
+
+        if !io_utils::read_exact_or_eof(&mut self.reader, &mut length_prefix, "raw message length prefix")? {
+            return Ok(None);
+        }
+
+        let byte_offset = self.reader.byte_count();
+        let mut bytes = vec![0u8; u16::from_be_bytes(length_prefix) as usize];
+
+        if !bytes.is_empty() && !io_utils::read_exact_or_eof(&mut self.reader, &mut bytes, "raw message")? {
+            return Err(DataFeedErrorKind::MalformedCapture("The raw message stream ends inside a message.".into()).into());
+        }
+
+        self.message_count += 1;
+
+        Ok(
+            Some(
+                CapturedMessage {
+                    capture_timestamp: 0,
+                    session: String::new(),
+                    sequence_number: self.message_count,
+                    byte_offset,
+                    bytes
+                }
+            )
+        )
+    }
+}
+
+impl<R: Read> Iterator for RawStreamReader<R> {
+    type Item = Result<CapturedMessage, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
\ No newline at end of file
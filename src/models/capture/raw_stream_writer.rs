@@ -0,0 +1,55 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, global_constants};
+
+pub struct RawStreamWriter<W: Write> {
+    writer: W,
+    message_count: u64
+}
+
+impl RawStreamWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(RawStreamWriter::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> RawStreamWriter<W> {
+    pub fn new(writer: W) -> Self {
+        RawStreamWriter {
+            writer,
+            message_count: 0
+        }
+    }
+
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    pub fn write_bin(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        if binary_data.len() > global_constants::MAX_MESSAGE_BYTE_COUNT {
+            return Err(DataFeedErrorKind::InvalidMessageSize(global_constants::MAX_MESSAGE_BYTE_COUNT, binary_data.len()).into());
+        }
+
+        self.writer.write_all(&(binary_data.len() as u16).to_be_bytes())?;
+        self.writer.write_all(binary_data)?;
+        self.message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        self.write_bin(&message.to_bin()?)
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, DataFeedError> {
+        self.flush()?;
+
+        Ok(self.writer)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::net::Ipv4Addr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UdpDatagram<'a> {
+    pub source_address: Ipv4Addr,
+    pub destination_address: Ipv4Addr,
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub payload: &'a [u8]
+}
\ No newline at end of file
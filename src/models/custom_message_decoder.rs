@@ -0,0 +1,80 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_type::FieldType, itch_message::ItchMessage, json_value::JsonValue}, models::{data_types::price, field_layout::FieldLayout, messages::custom_message::CustomMessage}, util::json_utils};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomMessageDecoder {
+    message_type: u8,
+    name: &'static str,
+    fields: &'static [FieldLayout],
+    byte_count: usize
+}
+
+impl CustomMessageDecoder {
+    pub fn new(message_type: u8, name: &'static str, fields: &'static [FieldLayout]) -> Result<Self, DataFeedError> {
+        if ItchMessage::byte_count(message_type).is_some() {
+            return Err(DataFeedErrorKind::Other(format!("The message type '{}' is already decoded by the feed handler.", message_type as char)).into());
+        }
+
+        match fields.first() {
+            Some(field) if field.offset == 0 && field.length == 1 && field.field_type == FieldType::Alpha => {},
+            _ => return Err(DataFeedErrorKind::Other(format!("The first field of the '{name}' layout must be the one-byte message type.")).into())
+        }
+
+        if let Some(field) = fields.iter().find(|field| field.length == 0 || (field.field_type == FieldType::Price && field.length != price::PRICE_LENGTH) || (field.field_type == FieldType::Numeric && field.length > 16)) {
+            return Err(DataFeedErrorKind::InvalidFieldValue(field.name, format!("A {:?} field cannot be {} bytes long.", field.field_type, field.length)).into());
+        }
+
+        Ok(
+            CustomMessageDecoder {
+                message_type,
+                name,
+                fields,
+                byte_count: fields
+                    .iter()
+                    .map(|field| field.offset + field.length)
+                    .max()
+                    .unwrap_or_default()
+            }
+        )
+    }
+
+    pub fn message_type(&self) -> u8 {
+        self.message_type
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn fields(&self) -> &'static [FieldLayout] {
+        self.fields
+    }
+
+    pub fn byte_count(&self) -> usize {
+        self.byte_count
+    }
+
+    pub fn decode(&self, binary_data: &[u8]) -> Result<CustomMessage, DataFeedError> {
+        if binary_data.len() != self.byte_count {
+            return Err(DataFeedError::from(DataFeedErrorKind::InvalidMessageSize(self.byte_count, binary_data.len())).with_message_type(self.message_type as char));
+        }
+
+        if binary_data[0] != self.message_type {
+            return Err(DataFeedErrorKind::UnknownMessageType(binary_data[0] as char).into());
+        }
+
+        Ok(
+            CustomMessage {
+                name: self.name,
+                fields: self.fields,
+                bytes: binary_data.to_vec()
+            }
+        )
+    }
+
+    pub fn decode_json(&self, json: &JsonValue) -> Result<CustomMessage, DataFeedError> {
+        let mut binary_data = vec![0u8; self.byte_count];
+        json_utils::decode_message_into(self.fields, json, &mut binary_data)?;
+
+        self.decode(&binary_data)
+    }
+}
\ No newline at end of file
@@ -1,29 +1,135 @@
-use crate::{enums::data_feed_error::DataFeedError, traits::data_type::TDataType};
+use std::{borrow::Cow, fmt::Debug, str::FromStr};
 
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, json_value::JsonValue}, traits::{data_type::TDataType, json::TJson}};
+
+pub fn is_printable(byte: u8) -> bool {
+    matches!(byte, 0x20..=0x7E | 0xA0..=0xFF)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Alpha<const SIZE: usize> {
-    pub value: [char; SIZE]
+    pub value: [u8; SIZE]
 }
 
 impl<const SIZE: usize> Alpha<SIZE> {
-    pub fn new(value: [char; SIZE]) -> Result<Self, DataFeedError> {
-        let alpha = Alpha {
+    pub const fn new(value: [u8; SIZE]) -> Self {
+        Alpha {
             value
-        };
+        }
+    }
+
+    pub fn from_str_padded(text: &str) -> Result<Self, DataFeedError> {
+        if text.chars().count() > SIZE {
+            return Err(DataFeedErrorKind::ParseError(format!("The alpha value '{text}' is longer than {SIZE} characters.")).into());
+        }
+
+        let mut value = [b' '; SIZE];
+
+        for (byte, c) in value.iter_mut().zip(text.chars()) {
+            *byte = u8::try_from(c)
+                .ok()
+                .filter(|byte| is_printable(*byte))
+                .ok_or(DataFeedErrorKind::NonISO88591Value(c))?;
+        }
+
+        Ok(Alpha::new(value))
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.value.iter().map(|byte| *byte as char)
+    }
+
+    pub fn trimmed_bytes(&self) -> &[u8] {
+        let length = self.value
+            .iter()
+            .rposition(|byte| *byte != b' ')
+            .map_or(0, |index| index + 1);
+
+        &self.value[..length]
+    }
+
+    pub fn as_str_trimmed(&self) -> Cow<'_, str> {
+        let bytes = self.trimmed_bytes();
+
+        match std::str::from_utf8(bytes) {
+            Ok(text) if bytes.is_ascii() => Cow::Borrowed(text),
+            _ => Cow::Owned(bytes.iter().map(|byte| *byte as char).collect())
+        }
+    }
+
+    pub fn to_trimmed_string(&self) -> String {
+        self.as_str_trimmed().into_owned()
+    }
+}
 
-        alpha.validate()?;
+impl Alpha<1> {
+    pub fn as_char(&self) -> char {
+        self.value[0] as char
+    }
+}
 
-        Ok(alpha)
+impl<const SIZE: usize> Debug for Alpha<SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Alpha")
+            .field(&self.chars().collect::<String>())
+            .finish()
     }
 }
 
 impl<const SIZE: usize> TDataType for Alpha<SIZE> {
     fn validate(&self) -> Result<(), DataFeedError> {
-        for c in self.value {
-            if (c as u16) > 0xFF {
-                return Err(DataFeedError::NonISO88591Value(c));
-            }
+        match self.value.iter().find(|byte| !is_printable(**byte)) {
+            Some(byte) => Err(DataFeedErrorKind::NonISO88591Value(*byte as char).into()),
+            None => Ok(())
         }
-        
-        Ok(())
+    }
+}
+
+impl<const SIZE: usize> FromStr for Alpha<SIZE> {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Alpha::from_str_padded(text)
+    }
+}
+
+impl<const SIZE: usize> PartialEq<str> for Alpha<SIZE> {
+    fn eq(&self, other: &str) -> bool {
+        self.trimmed_bytes()
+            .iter()
+            .map(|byte| *byte as char)
+            .eq(other.trim_end_matches(' ').chars())
+    }
+}
+
+impl<const SIZE: usize> PartialEq<&str> for Alpha<SIZE> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<const SIZE: usize> PartialEq<Alpha<SIZE>> for str {
+    fn eq(&self, other: &Alpha<SIZE>) -> bool {
+        *other == *self
+    }
+}
+
+impl<const SIZE: usize> PartialEq<Alpha<SIZE>> for &str {
+    fn eq(&self, other: &Alpha<SIZE>) -> bool {
+        *other == **self
+    }
+}
+
+impl<const SIZE: usize> TJson for Alpha<SIZE> {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError> {
+        Ok(JsonValue::String(self.to_trimmed_string()))
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized {
+        let text = json
+            .as_str()
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Expected a JSON string for an alpha value but found {json}.")))?;
+
+        Alpha::from_str_padded(text)
     }
 }
\ No newline at end of file
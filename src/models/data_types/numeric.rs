@@ -1,7 +1,8 @@
-use crate::{enums::data_feed_error::DataFeedError, traits::data_type::TDataType};
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, json_value::JsonValue}, traits::{data_type::TDataType, json::TJson}};
 
-pub const VALID_NUMERIC_SIZES: [usize; 5] = [1, 2, 4, 8, 16];
+pub const VALID_NUMERIC_SIZES: [usize; 6] = [1, 2, 4, 8, 12, 16];
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Numeric<const SIZE: usize> {
     pub value: u128
 }
@@ -23,32 +24,52 @@ impl<const SIZE: usize> TDataType for Numeric<SIZE> {
         match SIZE {
             1 => {
                 if self.value < (u8::MIN as u128) || self.value > (u8::MAX as u128) {
-                    return Err(DataFeedError::InvalidNumericSize(SIZE));
+                    return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
                 }
             },
             2 => {
                 if self.value < (u16::MIN as u128) || self.value > (u16::MAX as u128) {
-                    return Err(DataFeedError::InvalidNumericSize(SIZE));
+                    return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
                 }
             },
             4 => {
                 if self.value < (u32::MIN as u128) || self.value > (u32::MAX as u128) {
-                    return Err(DataFeedError::InvalidNumericSize(SIZE));
+                    return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
                 }
             },
             8 => {
                 if self.value < (u64::MIN as u128) || self.value > (u64::MAX as u128) {
-                    return Err(DataFeedError::InvalidNumericSize(SIZE));
+                    return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
+                }
+            },
+            12 => {
+                if self.value >= (1u128 << 96) {
+                    return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
                 }
             },
             16 => {
                 
             }
             _ => {
-                return Err(DataFeedError::Other("An invalid SIZE value was provided for Numeric type.".into()));
+                return Err(DataFeedErrorKind::Other("An invalid SIZE value was provided for Numeric type.".into()).into());
             }
         }
 
         Ok(())
     }
+}
+
+impl<const SIZE: usize> TJson for Numeric<SIZE> {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError> {
+        Ok(JsonValue::Number(self.value.to_string()))
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized {
+        let value = json
+            .as_number_str()
+            .and_then(|text| text.parse::<u128>().ok())
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Expected a JSON integer for a numeric value but found {json}.")))?;
+
+        Numeric::new(value)
+    }
 }
\ No newline at end of file
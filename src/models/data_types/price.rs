@@ -1,5 +1,8 @@
-use crate::{enums::data_feed_error::DataFeedError, traits::data_type::TDataType};
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, json_value::JsonValue}, traits::{data_type::TDataType, json::TJson}};
 
+pub const PRICE_LENGTH: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Price {
     pub value: i32
 }
@@ -20,4 +23,19 @@ impl TDataType for Price {
     fn validate(&self) -> Result<(), DataFeedError> {
         Ok(())
     }
+}
+
+impl TJson for Price {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError> {
+        Ok(JsonValue::Number(self.value.to_string()))
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized {
+        let value = json
+            .as_number_str()
+            .and_then(|text| text.parse::<i32>().ok())
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Expected a JSON integer for a price value but found {json}.")))?;
+
+        Price::new(value)
+    }
 }
\ No newline at end of file
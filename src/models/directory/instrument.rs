@@ -0,0 +1,16 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instrument {
+    pub order_book_id: u32,
+    pub symbol: String,
+    pub long_name: String,
+    pub isin: String,
+    pub financial_product: u8,
+    pub trading_currency: String,
+    pub number_of_decimals_in_price: u16,
+    pub number_of_decimals_in_nominal_value: u16,
+    pub odd_lot_size: u32,
+    pub round_lot_size: u32,
+    pub block_lot_size: u32,
+    pub nominal_value: u64,
+    pub is_combination: bool
+}
\ No newline at end of file
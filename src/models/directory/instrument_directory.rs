@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::{enums::itch_message::ItchMessage, models::directory::instrument::Instrument};
+
+#[derive(Clone, Default)]
+pub struct InstrumentDirectory {
+    instruments: HashMap<u32, Instrument>,
+    order_book_ids_by_symbol: HashMap<String, u32>
+}
+
+impl InstrumentDirectory {
+    pub fn new() -> Self {
+        InstrumentDirectory::default()
+    }
+
+    pub fn process_message(&mut self, message: &ItchMessage) -> Option<&Instrument> {
+        let instrument = match message {
+            ItchMessage::OrderBookDirectory(message) => Instrument {
+                order_book_id: message.order_book_id.value as u32,
+                symbol: message.symbol.to_trimmed_string(),
+                long_name: message.long_name.to_trimmed_string(),
+                isin: message.isin.to_trimmed_string(),
+                financial_product: message.financial_product.value as u8,
+                trading_currency: message.trading_currency.to_trimmed_string(),
+                number_of_decimals_in_price: message.number_of_decimals_in_price.value as u16,
+                number_of_decimals_in_nominal_value: message.number_of_decimals_in_nominal_value.value as u16,
+                odd_lot_size: message.odd_lot_size.value as u32,
+                round_lot_size: message.round_lot_size.value as u32,
+                block_lot_size: message.block_lot_size.value as u32,
+                nominal_value: message.nominal_value.value as u64,
+                is_combination: false
+            },
+            ItchMessage::CombinationOrderBookDirectory(message) => Instrument {
+                order_book_id: message.order_book_id.value as u32,
+                symbol: message.symbol.to_trimmed_string(),
+                long_name: message.long_name.to_trimmed_string(),
+                isin: message.isin.to_trimmed_string(),
+                financial_product: message.financial_product.value as u8,
+                trading_currency: message.trading_currency.to_trimmed_string(),
+                number_of_decimals_in_price: message.number_of_decimals_in_price.value as u16,
+                number_of_decimals_in_nominal_value: message.number_of_decimals_in_nominal_value.value as u16,
+                odd_lot_size: message.odd_lot_size.value as u32,
+                round_lot_size: message.round_lot_size.value as u32,
+                block_lot_size: message.block_lot_size.value as u32,
+                nominal_value: message.nominal_value.value as u64,
+                is_combination: true
+            },
+            _ => return None
+        };
+
+        let order_book_id = instrument.order_book_id;
+        self.order_book_ids_by_symbol.insert(instrument.symbol.clone(), order_book_id);
+        self.instruments.insert(order_book_id, instrument);
+
+        self.instruments.get(&order_book_id)
+    }
+
+    pub fn instrument(&self, order_book_id: u32) -> Option<&Instrument> {
+        self.instruments.get(&order_book_id)
+    }
+
+    pub fn instrument_by_symbol(&self, symbol: &str) -> Option<&Instrument> {
+        self.order_book_ids_by_symbol
+            .get(symbol)
+            .and_then(|order_book_id| self.instruments.get(order_book_id))
+    }
+
+    pub fn instruments(&self) -> impl Iterator<Item = &Instrument> {
+        self.instruments.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.instruments.clear();
+        self.order_book_ids_by_symbol.clear();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod instrument;
+pub mod instrument_directory;
+pub mod tick_size_entry;
+pub mod tick_size_tables;
\ No newline at end of file
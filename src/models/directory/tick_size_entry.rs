@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TickSizeEntry {
+    pub tick_size: u64,
+    pub price_from: i32,
+    pub price_to: i32
+}
\ No newline at end of file
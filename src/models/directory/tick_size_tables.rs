@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use crate::{enums::itch_message::ItchMessage, models::directory::tick_size_entry::TickSizeEntry};
+
+#[derive(Clone, Default)]
+pub struct TickSizeTables {
+    entries: HashMap<u32, Vec<TickSizeEntry>>
+}
+
+impl TickSizeTables {
+    pub fn new() -> Self {
+        TickSizeTables::default()
+    }
+
+    pub fn process_message(&mut self, message: &ItchMessage) {
+        let ItchMessage::TickSizeTableEntry(message) = message else {
+            return;
+        };
+
+        let entry = TickSizeEntry {
+            tick_size: message.tick_size.value as u64,
+            price_from: message.price_from.value,
+            price_to: message.price_to.value
+        };
+
+        let entries = self.entries
+            .entry(message.order_book_id.value as u32)
+            .or_default();
+
+        match entries.iter_mut().find(|existing_entry| existing_entry.price_from == entry.price_from) {
+            Some(existing_entry) => *existing_entry = entry,
+            None => {
+                entries.push(entry);
+                entries.sort_by_key(|entry| entry.price_from);
+            }
+        }
+    }
+
+    pub fn entries(&self, order_book_id: u32) -> &[TickSizeEntry] {
+        self.entries
+            .get(&order_book_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn tick_size(&self, order_book_id: u32, price: i32) -> Option<u64> {
+        self.entries(order_book_id)
+            .iter()
+            .find(|entry| price >= entry.price_from && (entry.price_to == 0 || price <= entry.price_to))
+            .map(|entry| entry.tick_size)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub message_type: Option<char>,
+    pub field_name: Option<&'static str>,
+    pub byte_offset: Option<u64>,
+    pub sequence_number: Option<u64>
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+
+        if let Some(message_type) = self.message_type {
+            write!(f, "message type '{message_type}'")?;
+            separator = ", ";
+        }
+
+        if let Some(field_name) = self.field_name {
+            write!(f, "{separator}field '{field_name}'")?;
+            separator = ", ";
+        }
+
+        if let Some(byte_offset) = self.byte_offset {
+            write!(f, "{separator}byte offset {byte_offset}")?;
+            separator = ", ";
+        }
+
+        if let Some(sequence_number) = self.sequence_number {
+            write!(f, "{separator}sequence number {sequence_number}")?;
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,225 @@
+use std::{collections::BTreeMap, fs::{self, File}, io::{BufWriter, Write}, path::{Path, PathBuf}};
+
+use crate::{enums::{csv_layout::CsvLayout, data_feed_error::DataFeedError, field_type::FieldType, field_value::FieldValue, itch_message::ItchMessage}, models::{directory::instrument_directory::InstrumentDirectory, field_layout::FieldLayout, message_decoder_registry::MessageDecoderRegistry, timestamp::Timestamp}, traits::message_sink::TMessageSink, util::format_utils};
+
+const TIMESTAMP_COLUMN: &str = "timestamp";
+
+pub struct CsvExporter {
+    layout: CsvLayout,
+    directory: PathBuf,
+    unified_writer: Option<Box<dyn Write + Send>>,
+    unified_columns: Vec<&'static str>,
+    writers: BTreeMap<u8, Box<dyn Write + Send>>,
+    instrument_directory: InstrumentDirectory,
+    message_decoders: MessageDecoderRegistry,
+    seconds: u32,
+    row_count: u64,
+    skipped_message_count: u64,
+    row: Vec<String>
+}
+
+impl CsvExporter {
+    pub fn create_per_message_type<P: AsRef<Path>>(directory: P) -> Result<Self, DataFeedError> {
+        fs::create_dir_all(directory.as_ref())?;
+
+        Ok(CsvExporter::with_layout(CsvLayout::PerMessageType, directory.as_ref().to_path_buf(), None))
+    }
+
+    pub fn create_unified<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        CsvExporter::new_unified(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn new_unified<W: Write + Send + 'static>(writer: W) -> Result<Self, DataFeedError> {
+        let mut csv_exporter = CsvExporter::with_layout(CsvLayout::Unified, PathBuf::new(), Some(Box::new(writer)));
+        let header = csv_exporter.unified_columns.join(",");
+
+        if let Some(unified_writer) = csv_exporter.unified_writer.as_mut() {
+            writeln!(unified_writer, "{header}")?;
+        }
+
+        Ok(csv_exporter)
+    }
+
+    pub fn layout(&self) -> CsvLayout {
+        self.layout
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+
+    pub fn skipped_message_count(&self) -> u64 {
+        self.skipped_message_count
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn set_message_decoders(&mut self, message_decoders: MessageDecoderRegistry) {
+        self.message_decoders = message_decoders;
+    }
+
+    pub fn export_bin(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        let message = self.message_decoders.decode(binary_data)?;
+
+        self.export_message(&message)
+    }
+
+    pub fn export_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        if let ItchMessage::Unknown(_) = message {
+            self.skipped_message_count += 1;
+            return Ok(());
+        }
+
+        if let ItchMessage::Seconds(seconds_message) = message {
+            self.seconds = seconds_message.second.value as u32;
+        }
+
+        self.instrument_directory.process_message(message);
+
+        let timestamp = Timestamp::new(self.seconds, message.nanoseconds().unwrap_or(0));
+        let number_of_decimals_in_price = message
+            .order_book_id()
+            .and_then(|order_book_id| self.instrument_directory.instrument(order_book_id))
+            .map_or(0, |instrument| instrument.number_of_decimals_in_price);
+
+        let field_values = message.field_values()?;
+
+        match self.layout {
+            CsvLayout::PerMessageType => {
+                self.row.clear();
+                self.row.push(timestamp.to_string());
+                self.row.extend(field_values.iter().map(|(field, value)| CsvExporter::format_value(field, value, number_of_decimals_in_price)));
+
+                self.open_writer(message)?;
+
+                if let Some(writer) = self.writers.get_mut(&(message.message_type() as u8)) {
+                    CsvExporter::write_row(writer, &self.row)?;
+                }
+            },
+            CsvLayout::Unified => {
+                self.row.clear();
+                self.row.resize(self.unified_columns.len(), String::new());
+                self.row[0] = timestamp.to_string();
+
+                for (field, value) in field_values.iter() {
+                    if let Some(column_index) = self.unified_columns.iter().position(|column| *column == field.name) {
+                        self.row[column_index] = CsvExporter::format_value(field, value, number_of_decimals_in_price);
+                    }
+                }
+
+                if let Some(unified_writer) = self.unified_writer.as_mut() {
+                    CsvExporter::write_row(unified_writer, &self.row)?;
+                }
+            }
+        }
+
+        self.row_count += 1;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        if let Some(unified_writer) = self.unified_writer.as_mut() {
+            unified_writer.flush()?;
+        }
+
+        for writer in self.writers.values_mut() {
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<u64, DataFeedError> {
+        self.flush()?;
+
+        Ok(self.row_count)
+    }
+
+    pub fn file_name_for_type(&self, message_type: u8) -> Option<String> {
+        self.message_decoders
+            .name_for_type(message_type)
+            .map(|name| format!("{}_{name}.csv", message_type as char))
+    }
+
+    fn with_layout(layout: CsvLayout, directory: PathBuf, unified_writer: Option<Box<dyn Write + Send>>) -> Self {
+        let mut unified_columns = vec![TIMESTAMP_COLUMN];
+
+        for message_type in ItchMessage::message_types() {
+            for field in ItchMessage::fields_for_type(message_type).unwrap_or_default() {
+                if !unified_columns.contains(&field.name) {
+                    unified_columns.push(field.name);
+                }
+            }
+        }
+
+        CsvExporter {
+            layout,
+            directory,
+            unified_writer,
+            unified_columns,
+            writers: BTreeMap::new(),
+            instrument_directory: InstrumentDirectory::new(),
+            message_decoders: MessageDecoderRegistry::new(),
+            seconds: 0,
+            row_count: 0,
+            skipped_message_count: 0,
+            row: Vec::new()
+        }
+    }
+
+    fn open_writer(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        let message_type = message.message_type() as u8;
+
+        if !self.writers.contains_key(&message_type) {
+            let file_name = format!("{}_{}.csv", message.message_type(), message.name());
+            let mut writer: Box<dyn Write + Send> = Box::new(BufWriter::new(File::create(self.directory.join(file_name))?));
+            let header = std::iter::once(TIMESTAMP_COLUMN)
+                .chain(message.fields().iter().map(|field| field.name))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            writeln!(writer, "{header}")?;
+            self.writers.insert(message_type, writer);
+        }
+
+        Ok(())
+    }
+
+    fn format_value(field: &FieldLayout, value: &FieldValue, number_of_decimals_in_price: u16) -> String {
+        match (field.field_type, value) {
+            (FieldType::Price, FieldValue::Price(price)) => format_utils::format_price(*price, number_of_decimals_in_price),
+            _ => value.to_string()
+        }
+    }
+
+    fn write_row<W: Write + ?Sized>(writer: &mut W, row: &[String]) -> Result<(), DataFeedError> {
+        for (column_index, value) in row.iter().enumerate() {
+            if column_index > 0 {
+                writer.write_all(b",")?;
+            }
+
+            if value.contains([',', '"', '\n', '\r']) {
+                write!(writer, "\"{}\"", value.replace('"', "\"\""))?;
+            } else {
+                writer.write_all(value.as_bytes())?;
+            }
+        }
+
+        writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+}
+
+impl TMessageSink for CsvExporter {
+    fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        self.export_message(message)
+    }
+
+    fn flush(&mut self) -> Result<(), DataFeedError> {
+        CsvExporter::flush(self)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod csv_exporter;
+pub mod ndjson_reader;
+pub mod ndjson_writer;
+pub mod text_reader;
+pub mod text_writer;
\ No newline at end of file
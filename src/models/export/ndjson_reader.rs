@@ -0,0 +1,67 @@
+use std::{fs::File, io::{BufRead, BufReader}, path::Path};
+
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::message_decoder_registry::MessageDecoderRegistry, util::json_utils};
+
+pub struct NdjsonReader<R: BufRead> {
+    reader: R,
+    line: String,
+    line_number: u64,
+    message_decoders: MessageDecoderRegistry
+}
+
+impl NdjsonReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(NdjsonReader::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> NdjsonReader<R> {
+    pub fn new(reader: R) -> Self {
+        NdjsonReader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            message_decoders: MessageDecoderRegistry::new()
+        }
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn set_message_decoders(&mut self, message_decoders: MessageDecoderRegistry) {
+        self.message_decoders = message_decoders;
+    }
+
+    pub fn line_number(&self) -> u64 {
+        self.line_number
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<ItchMessage>, DataFeedError> {
+        loop {
+            self.line.clear();
+
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(None);
+            }
+
+            self.line_number += 1;
+
+            if self.line.trim().is_empty() {
+                continue;
+            }
+
+            let json = json_utils::parse(self.line.trim())?;
+
+            return self.message_decoders.decode_json(&json).map(Some);
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for NdjsonReader<R> {
+    type Item = Result<ItchMessage, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
\ No newline at end of file
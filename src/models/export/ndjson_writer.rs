@@ -0,0 +1,70 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::message_decoder_registry::MessageDecoderRegistry, traits::{json::TJson, message_sink::TMessageSink}};
+
+pub struct NdjsonWriter<W: Write> {
+    writer: W,
+    message_count: u64,
+    message_decoders: MessageDecoderRegistry
+}
+
+impl NdjsonWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(NdjsonWriter::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    pub fn new(writer: W) -> Self {
+        NdjsonWriter {
+            writer,
+            message_count: 0,
+            message_decoders: MessageDecoderRegistry::new()
+        }
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn set_message_decoders(&mut self, message_decoders: MessageDecoderRegistry) {
+        self.message_decoders = message_decoders;
+    }
+
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    pub fn write_bin(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        self.write_message(&self.message_decoders.decode(binary_data)?)
+    }
+
+    pub fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        writeln!(self.writer, "{}", message.to_json()?)?;
+        self.message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, DataFeedError> {
+        self.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Send> TMessageSink for NdjsonWriter<W> {
+    fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        NdjsonWriter::write_message(self, message)
+    }
+
+    fn flush(&mut self) -> Result<(), DataFeedError> {
+        NdjsonWriter::flush(self)
+    }
+}
\ No newline at end of file
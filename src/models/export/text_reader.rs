@@ -0,0 +1,63 @@
+use std::{fs::File, io::{BufRead, BufReader}, path::Path};
+
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::message_decoder_registry::MessageDecoderRegistry, util::text_format};
+
+pub struct TextReader<R: BufRead> {
+    reader: R,
+    line: String,
+    line_number: u64,
+    message_decoders: MessageDecoderRegistry
+}
+
+impl TextReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(TextReader::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> TextReader<R> {
+    pub fn new(reader: R) -> Self {
+        TextReader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            message_decoders: MessageDecoderRegistry::new()
+        }
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn set_message_decoders(&mut self, message_decoders: MessageDecoderRegistry) {
+        self.message_decoders = message_decoders;
+    }
+
+    pub fn line_number(&self) -> u64 {
+        self.line_number
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<ItchMessage>, DataFeedError> {
+        loop {
+            self.line.clear();
+
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(None);
+            }
+
+            self.line_number += 1;
+
+            if let Some(message) = text_format::parse_message_with(&self.line, &self.message_decoders)? {
+                return Ok(Some(message));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for TextReader<R> {
+    type Item = Result<ItchMessage, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
\ No newline at end of file
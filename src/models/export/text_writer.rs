@@ -0,0 +1,70 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::message_decoder_registry::MessageDecoderRegistry, traits::message_sink::TMessageSink, util::text_format};
+
+pub struct TextWriter<W: Write> {
+    writer: W,
+    message_count: u64,
+    message_decoders: MessageDecoderRegistry
+}
+
+impl TextWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        Ok(TextWriter::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> TextWriter<W> {
+    pub fn new(writer: W) -> Self {
+        TextWriter {
+            writer,
+            message_count: 0,
+            message_decoders: MessageDecoderRegistry::new()
+        }
+    }
+
+    pub fn message_decoders(&self) -> &MessageDecoderRegistry {
+        &self.message_decoders
+    }
+
+    pub fn set_message_decoders(&mut self, message_decoders: MessageDecoderRegistry) {
+        self.message_decoders = message_decoders;
+    }
+
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    pub fn write_bin(&mut self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        self.write_message(&self.message_decoders.decode(binary_data)?)
+    }
+
+    pub fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        writeln!(self.writer, "{}", text_format::format_message(message)?)?;
+        self.message_count += 1;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, DataFeedError> {
+        self.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Send> TMessageSink for TextWriter<W> {
+    fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        TextWriter::write_message(self, message)
+    }
+
+    fn flush(&mut self) -> Result<(), DataFeedError> {
+        TextWriter::flush(self)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use crate::{enums::{data_feed_error::DataFeedError, itch_message::ItchMessage}, models::{order_book_statistics::OrderBookStatistics, timestamp::Timestamp}};
+
+const NANOSECONDS_PER_MILLISECOND: u32 = 1_000_000;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeedStatistics {
+    pub message_count: u64,
+    pub message_counts_by_type: BTreeMap<char, u64>,
+    pub order_book_statistics: BTreeMap<u32, OrderBookStatistics>,
+    pub message_counts_by_second: BTreeMap<u32, u64>,
+    pub decode_error_counts: BTreeMap<&'static str, u64>,
+    pub decode_error_counts_by_field: BTreeMap<&'static str, u64>,
+    pub first_time: Option<Timestamp>,
+    pub last_time: Option<Timestamp>,
+    pub peak_millisecond: Option<Timestamp>,
+    pub peak_millisecond_message_count: u64,
+    seconds: u32,
+    current_millisecond: Option<(u32, u32)>,
+    current_millisecond_message_count: u64
+}
+
+impl FeedStatistics {
+    pub fn new() -> Self {
+        FeedStatistics::default()
+    }
+
+    pub fn record(&mut self, message: &ItchMessage) {
+        if let ItchMessage::Seconds(seconds_message) = message {
+            self.seconds = seconds_message.second.value as u32;
+        }
+
+        let message_time = Timestamp::new(self.seconds, message.nanoseconds().unwrap_or(0));
+
+        self.message_count += 1;
+        *self.message_counts_by_type.entry(message.message_type()).or_default() += 1;
+        *self.message_counts_by_second.entry(message_time.seconds).or_default() += 1;
+
+        self.first_time.get_or_insert(message_time);
+        self.last_time = Some(message_time);
+        self.record_millisecond(message_time);
+
+        let Some(order_book_id) = message.order_book_id() else {
+            return;
+        };
+
+        let order_book_statistics = self.order_book_statistics.entry(order_book_id).or_default();
+        order_book_statistics.message_count += 1;
+
+        match message {
+            ItchMessage::AddOrderNoParticipantId(_) | ItchMessage::AddOrderWithParticipantId(_) => order_book_statistics.add_count += 1,
+            ItchMessage::OrderReplace(_) => order_book_statistics.replace_count += 1,
+            ItchMessage::OrderDelete(_) => order_book_statistics.cancel_count += 1,
+            ItchMessage::OrderExecuted(message) => {
+                order_book_statistics.trade_count += 1;
+                order_book_statistics.traded_quantity += message.executed_quantity.value as u64;
+            },
+            ItchMessage::OrderExecutedWithPrice(message) => {
+                order_book_statistics.trade_count += 1;
+                order_book_statistics.traded_quantity += message.executed_quantity.value as u64;
+            },
+            ItchMessage::Trade(message) => {
+                order_book_statistics.trade_count += 1;
+                order_book_statistics.traded_quantity += message.quantity.value as u64;
+            },
+            _ => {}
+        }
+    }
+
+    pub fn record_decode_error(&mut self, error: &DataFeedError) {
+        *self.decode_error_counts.entry(error.variant_name()).or_default() += 1;
+
+        if let Some(field_name) = error.field_name() {
+            *self.decode_error_counts_by_field.entry(field_name).or_default() += 1;
+        }
+    }
+
+    pub fn decode_error_count(&self) -> u64 {
+        self.decode_error_counts.values().sum()
+    }
+
+    pub fn totals(&self) -> OrderBookStatistics {
+        self.order_book_statistics
+            .values()
+            .fold(OrderBookStatistics::default(), |totals, order_book_statistics| OrderBookStatistics {
+                message_count: totals.message_count + order_book_statistics.message_count,
+                add_count: totals.add_count + order_book_statistics.add_count,
+                replace_count: totals.replace_count + order_book_statistics.replace_count,
+                cancel_count: totals.cancel_count + order_book_statistics.cancel_count,
+                trade_count: totals.trade_count + order_book_statistics.trade_count,
+                traded_quantity: totals.traded_quantity + order_book_statistics.traded_quantity
+            })
+    }
+
+    pub fn average_messages_per_second(&self) -> f64 {
+        match self.message_counts_by_second.len() {
+            0 => 0.0,
+            active_second_count => self.message_count as f64 / active_second_count as f64
+        }
+    }
+
+    pub fn peak_second(&self) -> Option<(u32, u64)> {
+        self.message_counts_by_second
+            .iter()
+            .max_by(|(left_second, left_count), (right_second, right_count)| left_count.cmp(right_count).then(right_second.cmp(left_second)))
+            .map(|(second, message_count)| (*second, *message_count))
+    }
+
+    fn record_millisecond(&mut self, message_time: Timestamp) {
+        let millisecond = (message_time.seconds, message_time.nanoseconds / NANOSECONDS_PER_MILLISECOND);
+
+        if self.current_millisecond == Some(millisecond) {
+            self.current_millisecond_message_count += 1;
+        } else {
+            self.current_millisecond = Some(millisecond);
+            self.current_millisecond_message_count = 1;
+        }
+
+        if self.current_millisecond_message_count > self.peak_millisecond_message_count {
+            self.peak_millisecond_message_count = self.current_millisecond_message_count;
+            self.peak_millisecond = Some(Timestamp::new(millisecond.0, millisecond.1 * NANOSECONDS_PER_MILLISECOND));
+        }
+    }
+}
\ No newline at end of file
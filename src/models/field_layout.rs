@@ -0,0 +1,64 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_type::FieldType, field_value::FieldValue}, models::data_types::alpha};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub field_type: FieldType,
+    pub offset: usize,
+    pub length: usize
+}
+
+impl FieldLayout {
+    pub fn read(&self, binary_data: &[u8]) -> FieldValue {
+        let bytes = &binary_data[self.offset..self.offset + self.length];
+
+        match self.field_type {
+            FieldType::Alpha => FieldValue::Alpha(
+                bytes
+                    .iter()
+                    .map(|byte| *byte as char)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            ),
+            FieldType::Numeric => FieldValue::Numeric(
+                bytes
+                    .iter()
+                    .fold(0u128, |value, byte| (value << 8) | *byte as u128)
+            ),
+            FieldType::Price => FieldValue::Price(i32::from_be_bytes(bytes.try_into().unwrap()))
+        }
+    }
+
+    pub fn write(&self, binary_data: &mut [u8], value: &FieldValue) -> Result<(), DataFeedError> {
+        let bytes = &mut binary_data[self.offset..self.offset + self.length];
+
+        match (self.field_type, value) {
+            (FieldType::Alpha, FieldValue::Alpha(value)) => {
+                if value.chars().count() > self.length {
+                    return Err(DataFeedErrorKind::InvalidFieldValue(self.name, format!("'{value}' is longer than {} characters.", self.length)).into());
+                }
+
+                bytes.fill(b' ');
+
+                for (byte, c) in bytes.iter_mut().zip(value.chars()) {
+                    *byte = u8::try_from(c)
+                        .ok()
+                        .filter(|byte| alpha::is_printable(*byte))
+                        .ok_or(DataFeedErrorKind::NonISO88591Value(c))?;
+                }
+            },
+            (FieldType::Numeric, FieldValue::Numeric(value)) => {
+                if self.length < 16 && *value >> (self.length * 8) != 0 {
+                    return Err(DataFeedErrorKind::InvalidNumericSize(self.length).into());
+                }
+
+                bytes.copy_from_slice(&value.to_be_bytes()[16 - self.length..]);
+            },
+            (FieldType::Price, FieldValue::Price(value)) => bytes.copy_from_slice(&value.to_be_bytes()),
+            _ => return Err(DataFeedErrorKind::InvalidFieldValue(self.name, format!("A {:?} field cannot hold {value:?}.", self.field_type)).into())
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
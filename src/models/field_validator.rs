@@ -0,0 +1,55 @@
+use crate::{enums::{data_feed_error::DataFeedError, validation_category::ValidationCategory, validation_mode::ValidationMode}, models::validation_policy::ValidationPolicy};
+
+pub struct FieldValidator<'a> {
+    policy: &'a ValidationPolicy,
+    warnings: &'a mut Vec<DataFeedError>,
+    message_type: Option<char>
+}
+
+impl<'a> FieldValidator<'a> {
+    pub fn new(policy: &'a ValidationPolicy, warnings: &'a mut Vec<DataFeedError>) -> Self {
+        FieldValidator {
+            policy,
+            warnings,
+            message_type: None
+        }
+    }
+
+    pub fn policy(&self) -> &ValidationPolicy {
+        self.policy
+    }
+
+    pub fn for_message_type(&mut self, message_type: char) -> FieldValidator<'_> {
+        FieldValidator {
+            policy: self.policy,
+            warnings: self.warnings,
+            message_type: Some(message_type)
+        }
+    }
+
+    pub fn check(&mut self, category: ValidationCategory, field_name: &'static str, is_valid: bool, error: impl FnOnce() -> DataFeedError) -> Result<(), DataFeedError> {
+        if is_valid {
+            return Ok(());
+        }
+
+        let mode = self.policy.mode(category);
+
+        if mode == ValidationMode::Off {
+            return Ok(());
+        }
+
+        let mut error = error().with_field(field_name);
+
+        if let Some(message_type) = self.message_type {
+            error = error.with_message_type(message_type);
+        }
+
+        if mode == ValidationMode::Strict {
+            return Err(error);
+        }
+
+        self.warnings.push(error);
+
+        Ok(())
+    }
+}
\ No newline at end of file
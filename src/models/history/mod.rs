@@ -0,0 +1,3 @@
+pub mod order_event;
+pub mod order_history;
+pub mod order_record;
\ No newline at end of file
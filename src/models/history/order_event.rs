@@ -0,0 +1,8 @@
+use crate::{enums::order_event_kind::OrderEventKind, models::timestamp::Timestamp};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderEvent {
+    pub timestamp: Timestamp,
+    pub kind: OrderEventKind,
+    pub remaining_quantity: u64
+}
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{enums::{itch_message::ItchMessage, order_event_kind::OrderEventKind}, models::{history::{order_event::OrderEvent, order_record::OrderRecord}, timestamp::Timestamp}};
+
+type OrderKey = (u32, char, u64);
+
+#[derive(Default)]
+pub struct OrderHistory {
+    records: HashMap<OrderKey, OrderRecord>,
+    completed_order_keys: VecDeque<OrderKey>,
+    completed_order_limit: Option<usize>,
+    duplicate_add_count: u64
+}
+
+impl OrderHistory {
+    pub fn new() -> Self {
+        OrderHistory::default()
+    }
+
+    pub fn with_completed_order_limit(completed_order_limit: usize) -> Self {
+        OrderHistory {
+            completed_order_limit: Some(completed_order_limit),
+            ..OrderHistory::default()
+        }
+    }
+
+    pub fn process_message(&mut self, message: &ItchMessage, timestamp: Timestamp) {
+        match message {
+            ItchMessage::AddOrderNoParticipantId(message) => self.record_add(
+                message.order_id.value as u64,
+                message.order_book_id.value as u32,
+                timestamp,
+                OrderEventKind::Added {
+                    side: message.side.as_char(),
+                    order_book_position: message.order_book_position.value as u32,
+                    quantity: message.quantity.value as u64,
+                    price: message.price.value,
+                    exchange_order_type: message.exchange_order_type.value as u16,
+                    participant_id: None
+                }
+            ),
+            ItchMessage::AddOrderWithParticipantId(message) => self.record_add(
+                message.order_id.value as u64,
+                message.order_book_id.value as u32,
+                timestamp,
+                OrderEventKind::Added {
+                    side: message.side.as_char(),
+                    order_book_position: message.order_book_position.value as u32,
+                    quantity: message.quantity.value as u64,
+                    price: message.price.value,
+                    exchange_order_type: message.exchange_order_type.value as u16,
+                    participant_id: Some(message.participant_id.to_trimmed_string())
+                }
+            ),
+            ItchMessage::OrderReplace(message) => {
+                let key = (message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64);
+                self.record_mut(key).remaining_quantity = message.quantity.value as u64;
+
+                self.push_event(
+                    key,
+                    timestamp,
+                    OrderEventKind::Replaced {
+                        new_order_book_position: message.new_order_book_position.value as u32,
+                        quantity: message.quantity.value as u64,
+                        price: message.price.value,
+                        exchange_order_type: message.exchange_order_type.value as u16
+                    }
+                );
+            },
+            ItchMessage::OrderExecuted(message) => self.record_execution(
+                (message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64),
+                timestamp,
+                OrderEventKind::Executed {
+                    executed_quantity: message.executed_quantity.value as u64,
+                    match_id: message.match_id.value,
+                    trade_price: None
+                }
+            ),
+            ItchMessage::OrderExecutedWithPrice(message) => self.record_execution(
+                (message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64),
+                timestamp,
+                OrderEventKind::Executed {
+                    executed_quantity: message.executed_quantity.value as u64,
+                    match_id: message.match_id.value,
+                    trade_price: Some(message.trade_price.value)
+                }
+            ),
+            ItchMessage::OrderDelete(message) => {
+                let key = (message.order_book_id.value as u32, message.side.as_char(), message.order_id.value as u64);
+                self.record_mut(key).remaining_quantity = 0;
+
+                self.push_event(key, timestamp, OrderEventKind::Deleted);
+                self.complete(key);
+            },
+            _ => {}
+        }
+    }
+
+    pub fn history(&self, order_book_id: u32, side: char, order_id: u64) -> Option<&OrderRecord> {
+        self.records.get(&(order_book_id, side, order_id))
+    }
+
+    pub fn events(&self, order_book_id: u32, side: char, order_id: u64) -> Option<&[OrderEvent]> {
+        self.history(order_book_id, side, order_id).map(|record| record.events.as_slice())
+    }
+
+    pub fn duplicate_add_count(&self) -> u64 {
+        self.duplicate_add_count
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn completed_order_count(&self) -> usize {
+        self.completed_order_keys.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.completed_order_keys.clear();
+        self.duplicate_add_count = 0;
+    }
+
+    fn record_add(&mut self, order_id: u64, order_book_id: u32, timestamp: Timestamp, kind: OrderEventKind) {
+        let OrderEventKind::Added { side, quantity, .. } = kind else {
+            return;
+        };
+
+        let key = (order_book_id, side, order_id);
+
+        if let Some(record) = self.records.get_mut(&key) {
+            self.duplicate_add_count += 1;
+
+            if record.is_completed {
+                record.is_completed = false;
+                self.completed_order_keys.retain(|completed_order_key| *completed_order_key != key);
+            }
+        }
+
+        self.record_mut(key).remaining_quantity = quantity;
+        self.push_event(key, timestamp, kind);
+    }
+
+    fn record_execution(&mut self, key: OrderKey, timestamp: Timestamp, kind: OrderEventKind) {
+        let OrderEventKind::Executed { executed_quantity, .. } = kind else {
+            return;
+        };
+
+        let record = self.record_mut(key);
+        record.remaining_quantity = record.remaining_quantity.saturating_sub(executed_quantity);
+        let is_filled = record.remaining_quantity == 0;
+
+        self.push_event(key, timestamp, kind);
+
+        if is_filled {
+            self.complete(key);
+        }
+    }
+
+    fn record_mut(&mut self, key: OrderKey) -> &mut OrderRecord {
+        let (order_book_id, side, order_id) = key;
+
+        self.records
+            .entry(key)
+            .or_insert_with(|| OrderRecord::new(order_id, order_book_id, side))
+    }
+
+    fn push_event(&mut self, key: OrderKey, timestamp: Timestamp, kind: OrderEventKind) {
+        if let Some(record) = self.records.get_mut(&key) {
+            record.events.push(
+                OrderEvent {
+                    timestamp,
+                    kind,
+                    remaining_quantity: record.remaining_quantity
+                }
+            );
+        }
+    }
+
+    fn complete(&mut self, key: OrderKey) {
+        let Some(record) = self.records.get_mut(&key) else {
+            return;
+        };
+
+        if record.is_completed {
+            return;
+        }
+
+        record.is_completed = true;
+        self.completed_order_keys.push_back(key);
+
+        let Some(completed_order_limit) = self.completed_order_limit else {
+            return;
+        };
+
+        while self.completed_order_keys.len() > completed_order_limit {
+            let Some(evicted_order_key) = self.completed_order_keys.pop_front() else {
+                break;
+            };
+
+            if self.records.get(&evicted_order_key).is_some_and(|record| record.is_completed) {
+                self.records.remove(&evicted_order_key);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::models::history::order_event::OrderEvent;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderRecord {
+    pub order_id: u64,
+    pub order_book_id: u32,
+    pub side: char,
+    pub remaining_quantity: u64,
+    pub is_completed: bool,
+    pub events: Vec<OrderEvent>
+}
+
+impl OrderRecord {
+    pub fn new(order_id: u64, order_book_id: u32, side: char) -> Self {
+        OrderRecord {
+            order_id,
+            order_book_id,
+            side,
+            remaining_quantity: 0,
+            is_completed: false,
+            events: Vec::new()
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use std::fmt::Display;
+
+use crate::{enums::{integrity_anomaly_kind::IntegrityAnomalyKind, itch_message::ItchMessage}, models::timestamp::Timestamp};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityAnomaly {
+    pub timestamp: Timestamp,
+    pub kind: IntegrityAnomalyKind,
+    pub message: ItchMessage
+}
+
+impl Display for IntegrityAnomaly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] '{}' message: {}", self.timestamp, self.message.message_type(), self.kind)
+    }
+}
\ No newline at end of file
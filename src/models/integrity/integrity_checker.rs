@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, integrity_anomaly_kind::IntegrityAnomalyKind, integrity_check_mode::IntegrityCheckMode, itch_message::ItchMessage}, models::{book::book_builder::BookBuilder, integrity::integrity_anomaly::IntegrityAnomaly, timestamp::Timestamp}};
+
+const DEFAULT_RECENT_ANOMALY_LIMIT: usize = 256;
+
+type AnomalyCallback = Box<dyn FnMut(&IntegrityAnomaly) + Send>;
+
+pub struct IntegrityChecker {
+    mode: IntegrityCheckMode,
+    recent_anomalies: VecDeque<IntegrityAnomaly>,
+    recent_anomaly_limit: usize,
+    anomaly_count: u64,
+    anomaly_callback: Option<AnomalyCallback>
+}
+
+impl Default for IntegrityChecker {
+    fn default() -> Self {
+        Self::new(IntegrityCheckMode::default())
+    }
+}
+
+impl IntegrityChecker {
+    pub fn new(mode: IntegrityCheckMode) -> Self {
+        Self::with_recent_anomaly_limit(mode, DEFAULT_RECENT_ANOMALY_LIMIT)
+    }
+
+    pub fn with_recent_anomaly_limit(mode: IntegrityCheckMode, recent_anomaly_limit: usize) -> Self {
+        IntegrityChecker {
+            mode,
+            recent_anomalies: VecDeque::with_capacity(recent_anomaly_limit),
+            recent_anomaly_limit,
+            anomaly_count: 0,
+            anomaly_callback: None
+        }
+    }
+
+    pub fn mode(&self) -> IntegrityCheckMode {
+        self.mode
+    }
+
+    pub fn set_anomaly_callback<F>(&mut self, anomaly_callback: F) where F: FnMut(&IntegrityAnomaly) + Send + 'static {
+        self.anomaly_callback = Some(Box::new(anomaly_callback));
+    }
+
+    pub fn clear_anomaly_callback(&mut self) {
+        self.anomaly_callback = None;
+    }
+
+    pub fn recent_anomalies(&self) -> impl ExactSizeIterator<Item = &IntegrityAnomaly> {
+        self.recent_anomalies.iter()
+    }
+
+    pub fn recent_anomaly_limit(&self) -> usize {
+        self.recent_anomaly_limit
+    }
+
+    pub fn drain_recent_anomalies(&mut self) -> Vec<IntegrityAnomaly> {
+        self.recent_anomalies.drain(..).collect()
+    }
+
+    pub fn anomaly_count(&self) -> u64 {
+        self.anomaly_count
+    }
+
+    pub fn clear(&mut self) {
+        self.recent_anomalies.clear();
+        self.anomaly_count = 0;
+    }
+
+    pub fn check_before_apply(&mut self, book_builder: &BookBuilder, message: &ItchMessage, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        let (Some(order_id), Some(order_book_id), Some(side)) = (message.order_id(), message.order_book_id(), message.side()) else {
+            return Ok(());
+        };
+
+        let resting_order = book_builder.order(order_book_id, side, order_id);
+
+        let kind = match message {
+            ItchMessage::AddOrderNoParticipantId(_) | ItchMessage::AddOrderWithParticipantId(_) => {
+                resting_order.map(|_| IntegrityAnomalyKind::DuplicateOrderId(order_id))
+            },
+            ItchMessage::OrderExecuted(_) | ItchMessage::OrderExecutedWithPrice(_) | ItchMessage::OrderReplace(_) | ItchMessage::OrderDelete(_) => {
+                let opposite_side = if side == 'B' { 'S' } else { 'B' };
+
+                match (resting_order, book_builder.order(order_book_id, opposite_side, order_id)) {
+                    (None, None) => Some(IntegrityAnomalyKind::UnknownOrderId(order_id)),
+                    (None, Some(order)) => Some(IntegrityAnomalyKind::SideMismatch(order_id, order.side, side)),
+                    (Some(order), _) => {
+                        let executed_quantity = match message {
+                            ItchMessage::OrderExecuted(message) => message.executed_quantity.value as u64,
+                            ItchMessage::OrderExecutedWithPrice(message) => message.executed_quantity.value as u64,
+                            _ => 0
+                        };
+
+                        (executed_quantity > order.quantity)
+                            .then_some(IntegrityAnomalyKind::ExecutionExceedsRemainingQuantity(order_id, executed_quantity, order.quantity))
+                    }
+                }
+            },
+            _ => None
+        };
+
+        match kind {
+            Some(kind) => self.report(kind, message, timestamp),
+            None => Ok(())
+        }
+    }
+
+    pub fn check_after_apply(&mut self, book_builder: &BookBuilder, message: &ItchMessage, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        let is_book_changing = matches!(
+            message,
+            ItchMessage::AddOrderNoParticipantId(_) | ItchMessage::AddOrderWithParticipantId(_) | ItchMessage::OrderReplace(_)
+        );
+
+        if !is_book_changing {
+            return Ok(());
+        }
+
+        let Some(book) = message.order_book_id().and_then(|order_book_id| book_builder.book(order_book_id)) else {
+            return Ok(());
+        };
+
+        if book.is_in_auction() || !book.is_crossed() {
+            return Ok(());
+        }
+
+        let (Some(best_bid), Some(best_ask)) = (book.best_bid(), book.best_ask()) else {
+            return Ok(());
+        };
+
+        self.report(IntegrityAnomalyKind::CrossedBook(book.order_book_id(), best_bid.price, best_ask.price), message, timestamp)
+    }
+
+    fn report(&mut self, kind: IntegrityAnomalyKind, message: &ItchMessage, timestamp: Timestamp) -> Result<(), DataFeedError> {
+        let anomaly = IntegrityAnomaly {
+            timestamp,
+            kind,
+            message: message.clone()
+        };
+
+        self.anomaly_count += 1;
+
+        if let Some(anomaly_callback) = self.anomaly_callback.as_mut() {
+            anomaly_callback(&anomaly);
+        }
+
+        let result = match self.mode {
+            IntegrityCheckMode::LogAndContinue => Ok(()),
+            IntegrityCheckMode::FailFast => Err(DataFeedErrorKind::IntegrityViolation(anomaly.to_string()).into())
+        };
+
+        self.remember(anomaly);
+
+        result
+    }
+
+    fn remember(&mut self, anomaly: IntegrityAnomaly) {
+        if self.recent_anomaly_limit == 0 {
+            return;
+        }
+
+        if self.recent_anomalies.len() == self.recent_anomaly_limit {
+            self.recent_anomalies.pop_front();
+        }
+
+        self.recent_anomalies.push_back(anomaly);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod integrity_anomaly;
+pub mod integrity_checker;
\ No newline at end of file
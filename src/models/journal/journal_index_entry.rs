@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JournalIndexEntry {
+    pub receive_timestamp: u64,
+    pub sequence_number: u64,
+    pub byte_offset: u64
+}
\ No newline at end of file
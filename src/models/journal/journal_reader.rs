@@ -0,0 +1,159 @@
+use std::{fs::File, io::{BufReader, ErrorKind, Read, Seek, SeekFrom}, path::Path};
+
+use crate::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, models::journal::{journal_entry::JournalEntry, journal_index_entry::JournalIndexEntry, journal_writer::{self, JournalWriter}}, util::io_utils};
+
+pub struct JournalReader<R> {
+    journal: R,
+    index: Vec<JournalIndexEntry>,
+    pending_entry: Option<JournalEntry>,
+    byte_offset: u64
+}
+
+impl JournalReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        let mut journal_reader = JournalReader::new(BufReader::new(File::open(path.as_ref())?))?;
+
+        journal_reader.index = match std::fs::read(JournalWriter::index_path(path.as_ref())) {
+            Ok(index_bytes) => JournalReader::<BufReader<File>>::parse_index(&index_bytes)?,
+            Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error.into())
+        };
+
+        Ok(journal_reader)
+    }
+}
+
+impl<R: Read> JournalReader<R> {
+    pub fn new(reader: R) -> Result<Self, DataFeedError> {
+        let mut journal = reader;
+
+        let mut magic = [0u8; 8];
+        journal.read_exact(&mut magic)?;
+
+        if magic != journal_writer::JOURNAL_MAGIC {
+            return Err(DataFeedErrorKind::MalformedCapture("The file is not an ITCH journal.".into()).into());
+        }
+
+        Ok(
+            JournalReader {
+                journal,
+                index: Vec::new(),
+                pending_entry: None,
+                byte_offset: journal_writer::JOURNAL_MAGIC.len() as u64
+            }
+        )
+    }
+
+    pub fn index(&self) -> &[JournalIndexEntry] {
+        &self.index
+    }
+
+    pub fn next_entry(&mut self) -> Result<Option<JournalEntry>, DataFeedError> {
+        if let Some(entry) = self.pending_entry.take() {
+            return Ok(Some(entry));
+        }
+
+        let mut header = [0u8; journal_writer::ENTRY_HEADER_BYTE_COUNT];
+
+        if !io_utils::read_exact_or_eof(&mut self.journal, &mut header, "journal entry header")? {
+            return Ok(None);
+        }
+
+        let receive_timestamp = u64::from_be_bytes(header[0..8].try_into().unwrap());
+        let sequence_number = u64::from_be_bytes(header[8..16].try_into().unwrap());
+        let length = u16::from_be_bytes(header[16..18].try_into().unwrap()) as usize;
+
+        let mut bytes = vec![0u8; length];
+        self.journal
+            .read_exact(&mut bytes)
+            .map_err(|_| DataFeedErrorKind::MalformedCapture("The journal ends inside an entry body.".into()))?;
+
+        let byte_offset = self.byte_offset;
+        self.byte_offset += (journal_writer::ENTRY_HEADER_BYTE_COUNT + length) as u64;
+
+        Ok(
+            Some(
+                JournalEntry {
+                    receive_timestamp,
+                    sequence_number,
+                    byte_offset,
+                    bytes
+                }
+            )
+        )
+    }
+
+    fn skip_while<F>(&mut self, mut predicate: F) -> Result<(), DataFeedError>
+    where
+        F: FnMut(&JournalEntry) -> bool
+    {
+        while let Some(entry) = self.next_entry()? {
+            if !predicate(&entry) {
+                self.pending_entry = Some(entry);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_index(index_bytes: &[u8]) -> Result<Vec<JournalIndexEntry>, DataFeedError> {
+        let Some(entry_bytes) = index_bytes.strip_prefix(&journal_writer::INDEX_MAGIC) else {
+            return Err(DataFeedErrorKind::MalformedCapture("The sidecar file is not an ITCH journal index.".into()).into());
+        };
+
+        Ok(
+            entry_bytes
+                .chunks_exact(journal_writer::INDEX_ENTRY_BYTE_COUNT)
+                .map(|chunk| JournalIndexEntry {
+                    receive_timestamp: u64::from_be_bytes(chunk[0..8].try_into().unwrap()),
+                    sequence_number: u64::from_be_bytes(chunk[8..16].try_into().unwrap()),
+                    byte_offset: u64::from_be_bytes(chunk[16..24].try_into().unwrap())
+                })
+                .collect()
+        )
+    }
+}
+
+impl<R: Read + Seek> JournalReader<R> {
+    pub fn seek_to_time(&mut self, receive_timestamp: u64) -> Result<(), DataFeedError> {
+        let index_position = self.index.partition_point(|index_entry| index_entry.receive_timestamp < receive_timestamp);
+        self.seek_to_index_position(index_position)?;
+
+        self.skip_while(|entry| entry.receive_timestamp < receive_timestamp)
+    }
+
+    pub fn seek_to_sequence(&mut self, sequence_number: u64) -> Result<(), DataFeedError> {
+        let index_position = self.index.partition_point(|index_entry| index_entry.sequence_number < sequence_number);
+        self.seek_to_index_position(index_position)?;
+
+        self.skip_while(|entry| entry.sequence_number < sequence_number)
+    }
+
+    pub fn rewind(&mut self) -> Result<(), DataFeedError> {
+        self.seek_to_byte_offset(journal_writer::JOURNAL_MAGIC.len() as u64)
+    }
+
+    fn seek_to_index_position(&mut self, index_position: usize) -> Result<(), DataFeedError> {
+        match index_position.checked_sub(1).map(|position| self.index[position]) {
+            Some(index_entry) => self.seek_to_byte_offset(index_entry.byte_offset),
+            None => self.rewind()
+        }
+    }
+
+    fn seek_to_byte_offset(&mut self, byte_offset: u64) -> Result<(), DataFeedError> {
+        self.pending_entry = None;
+        self.journal.seek(SeekFrom::Start(byte_offset))?;
+        self.byte_offset = byte_offset;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for JournalReader<R> {
+    type Item = Result<JournalEntry, DataFeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_entry().transpose()
+    }
+}
\ No newline at end of file
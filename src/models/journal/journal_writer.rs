@@ -0,0 +1,130 @@
+use std::{fs::{File, OpenOptions}, io::{BufReader, BufWriter, Write}, path::{Path, PathBuf}};
+
+use crate::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, global_constants, models::journal::{journal_index_entry::JournalIndexEntry, journal_reader::JournalReader}};
+
+pub(crate) const JOURNAL_MAGIC: [u8; 8] = *b"ITCHJNL1";
+pub(crate) const INDEX_MAGIC: [u8; 8] = *b"ITCHIDX1";
+pub(crate) const ENTRY_HEADER_BYTE_COUNT: usize = 18;
+pub(crate) const INDEX_ENTRY_BYTE_COUNT: usize = 24;
+
+const DEFAULT_INDEX_INTERVAL: u64 = 1024;
+
+pub struct JournalWriter<W: Write, I: Write> {
+    journal: W,
+    index: I,
+    index_interval: u64,
+    entry_count: u64,
+    byte_offset: u64
+}
+
+impl JournalWriter<BufWriter<File>, BufWriter<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DataFeedError> {
+        JournalWriter::open_with_index_interval(path, DEFAULT_INDEX_INTERVAL)
+    }
+
+    pub fn open_with_index_interval<P: AsRef<Path>>(path: P, index_interval: u64) -> Result<Self, DataFeedError> {
+        let journal = OpenOptions::new().read(true).append(true).create(true).open(path.as_ref())?;
+        let index = BufWriter::new(File::create(JournalWriter::index_path(path.as_ref()))?);
+
+        if journal.metadata()?.len() == 0 {
+            return JournalWriter::new(BufWriter::new(journal), index, index_interval);
+        }
+
+        let mut journal_writer = JournalWriter {
+            journal: BufWriter::new(journal.try_clone()?),
+            index,
+            index_interval: index_interval.max(1),
+            entry_count: 0,
+            byte_offset: JOURNAL_MAGIC.len() as u64
+        };
+
+        journal_writer.index.write_all(&INDEX_MAGIC)?;
+
+        for entry in JournalReader::new(BufReader::new(&journal))? {
+            let entry = entry?;
+
+            journal_writer.write_index_entry(entry.receive_timestamp, entry.sequence_number)?;
+            journal_writer.entry_count += 1;
+            journal_writer.byte_offset += (ENTRY_HEADER_BYTE_COUNT + entry.bytes.len()) as u64;
+        }
+
+        Ok(journal_writer)
+    }
+
+    pub fn index_path(path: &Path) -> PathBuf {
+        let mut index_path = path.as_os_str().to_owned();
+        index_path.push(".idx");
+
+        PathBuf::from(index_path)
+    }
+}
+
+impl<W: Write, I: Write> JournalWriter<W, I> {
+    pub fn new(journal: W, index: I, index_interval: u64) -> Result<Self, DataFeedError> {
+        let mut journal_writer = JournalWriter {
+            journal,
+            index,
+            index_interval: index_interval.max(1),
+            entry_count: 0,
+            byte_offset: JOURNAL_MAGIC.len() as u64
+        };
+
+        journal_writer.journal.write_all(&JOURNAL_MAGIC)?;
+        journal_writer.index.write_all(&INDEX_MAGIC)?;
+
+        Ok(journal_writer)
+    }
+
+    pub fn entry_count(&self) -> u64 {
+        self.entry_count
+    }
+
+    pub fn write(&mut self, receive_timestamp: u64, sequence_number: u64, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        if binary_data.len() > global_constants::MAX_MESSAGE_BYTE_COUNT {
+            return Err(DataFeedErrorKind::InvalidMessageSize(global_constants::MAX_MESSAGE_BYTE_COUNT, binary_data.len()).into());
+        }
+
+        self.write_index_entry(receive_timestamp, sequence_number)?;
+
+        self.journal.write_all(&receive_timestamp.to_be_bytes())?;
+        self.journal.write_all(&sequence_number.to_be_bytes())?;
+        self.journal.write_all(&(binary_data.len() as u16).to_be_bytes())?;
+        self.journal.write_all(binary_data)?;
+
+        self.entry_count += 1;
+        self.byte_offset += (ENTRY_HEADER_BYTE_COUNT + binary_data.len()) as u64;
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), DataFeedError> {
+        self.journal.flush()?;
+        self.index.flush()?;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<(W, I), DataFeedError> {
+        self.flush()?;
+
+        Ok((self.journal, self.index))
+    }
+
+    fn write_index_entry(&mut self, receive_timestamp: u64, sequence_number: u64) -> Result<(), DataFeedError> {
+        if !self.entry_count.is_multiple_of(self.index_interval) {
+            return Ok(());
+        }
+
+        let index_entry = JournalIndexEntry {
+            receive_timestamp,
+            sequence_number,
+            byte_offset: self.byte_offset
+        };
+
+        self.index.write_all(&index_entry.receive_timestamp.to_be_bytes())?;
+        self.index.write_all(&index_entry.sequence_number.to_be_bytes())?;
+        self.index.write_all(&index_entry.byte_offset.to_be_bytes())?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
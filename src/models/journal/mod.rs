@@ -0,0 +1,4 @@
+pub mod journal_entry;
+pub mod journal_index_entry;
+pub mod journal_reader;
+pub mod journal_writer;
\ No newline at end of file
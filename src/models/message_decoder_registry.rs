@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_type::FieldType, itch_message::ItchMessage, json_value::JsonValue}, models::{custom_message_decoder::CustomMessageDecoder, field_layout::FieldLayout, field_validator::FieldValidator, messages::unknown_message::UnknownMessage, validation_policy::ValidationPolicy}, traits::json::TJson};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageDecoderRegistry {
+    custom_decoders: BTreeMap<u8, CustomMessageDecoder>
+}
+
+impl MessageDecoderRegistry {
+    pub fn new() -> Self {
+        MessageDecoderRegistry::default()
+    }
+
+    pub fn register(&mut self, decoder: CustomMessageDecoder) -> Result<(), DataFeedError> {
+        if self.custom_decoders.contains_key(&decoder.message_type()) {
+            return Err(DataFeedErrorKind::Other(format!("A decoder is already registered for the message type '{}'.", decoder.message_type() as char)).into());
+        }
+
+        self.custom_decoders.insert(decoder.message_type(), decoder);
+        Ok(())
+    }
+
+    pub fn unregister(&mut self, message_type: u8) -> Option<CustomMessageDecoder> {
+        self.custom_decoders.remove(&message_type)
+    }
+
+    pub fn decoder(&self, message_type: u8) -> Option<&CustomMessageDecoder> {
+        self.custom_decoders.get(&message_type)
+    }
+
+    pub fn decoders(&self) -> impl Iterator<Item = &CustomMessageDecoder> {
+        self.custom_decoders.values()
+    }
+
+    pub fn is_known(&self, message_type: u8) -> bool {
+        ItchMessage::byte_count(message_type).is_some() || self.custom_decoders.contains_key(&message_type)
+    }
+
+    pub fn name_for_type(&self, message_type: u8) -> Option<&'static str> {
+        ItchMessage::name_for_type(message_type).or_else(|| self.decoder(message_type).map(CustomMessageDecoder::name))
+    }
+
+    pub fn fields_for_type(&self, message_type: u8) -> Option<&'static [FieldLayout]> {
+        ItchMessage::fields_for_type(message_type).or_else(|| self.decoder(message_type).map(CustomMessageDecoder::fields))
+    }
+
+    pub fn peek_order_book_id(&self, binary_data: &[u8]) -> Option<u32> {
+        let Some(decoder) = self.decoder(*binary_data.first()?) else {
+            return ItchMessage::peek_order_book_id(binary_data);
+        };
+
+        let field = decoder
+            .fields()
+            .iter()
+            .find(|field| field.name == "order_book_id" && field.field_type == FieldType::Numeric && field.length == 4)?;
+
+        let order_book_id_bytes = binary_data.get(field.offset..field.offset + 4)?;
+
+        Some(u32::from_be_bytes([order_book_id_bytes[0], order_book_id_bytes[1], order_book_id_bytes[2], order_book_id_bytes[3]]))
+    }
+
+    pub fn decode(&self, binary_data: &[u8]) -> Result<ItchMessage, DataFeedError> {
+        self.decode_with(binary_data, &mut FieldValidator::new(&ValidationPolicy::strict(), &mut Vec::new()))
+    }
+
+    pub fn decode_with(&self, binary_data: &[u8], validator: &mut FieldValidator) -> Result<ItchMessage, DataFeedError> {
+        match binary_data.first().and_then(|&message_type| self.decoder(message_type)) {
+            Some(decoder) => Ok(ItchMessage::Custom(decoder.decode(binary_data)?)),
+            None => ItchMessage::from_bin_with(binary_data, validator)
+        }
+    }
+
+    pub fn decode_json(&self, json: &JsonValue) -> Result<ItchMessage, DataFeedError> {
+        let decoder = json
+            .get("message_type")
+            .and_then(JsonValue::as_str)
+            .and_then(|message_type| message_type.chars().next())
+            .and_then(|message_type| u8::try_from(message_type).ok())
+            .and_then(|message_type| self.decoder(message_type));
+
+        match (decoder, json.get("bytes")) {
+            (Some(decoder), None) => Ok(ItchMessage::Custom(decoder.decode_json(json)?)),
+            (Some(decoder), Some(_)) => Ok(ItchMessage::Custom(decoder.decode(&UnknownMessage::from_json(json)?.bytes)?)),
+            (None, _) => ItchMessage::from_json(json)
+        }
+    }
+}
\ No newline at end of file
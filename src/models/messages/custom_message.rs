@@ -0,0 +1,32 @@
+use crate::{enums::{field_value::FieldValue, json_value::JsonValue}, models::field_layout::FieldLayout, util::json_utils};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomMessage {
+    pub name: &'static str,
+    pub fields: &'static [FieldLayout],
+    pub bytes: Vec<u8>
+}
+
+impl CustomMessage {
+    pub fn message_type(&self) -> char {
+        self.bytes[0] as char
+    }
+
+    pub fn field(&self, name: &str) -> Option<FieldValue> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| field.read(&self.bytes))
+    }
+
+    pub fn numeric_field(&self, name: &str) -> Option<u128> {
+        match self.field(name)? {
+            FieldValue::Numeric(value) => Some(value),
+            _ => None
+        }
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        json_utils::encode_message(self.fields, &self.bytes)
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 pub mod add_order_no_participant_id_message;
 pub mod add_order_with_participant_id_message;
 pub mod combination_order_book_directory_message;
+pub mod custom_message;
 pub mod equilibrium_price_update_message;
 pub mod order_book_directory_message;
 pub mod order_book_state_message;
@@ -11,4 +12,5 @@ pub mod order_replace_message;
 pub mod seconds_message;
 pub mod system_event_message;
 pub mod tick_size_table_entry_message;
-pub mod trade_message;
\ No newline at end of file
+pub mod trade_message;
+pub mod unknown_message;
\ No newline at end of file
@@ -1,122 +1,27 @@
-use crate::{enums::data_feed_error::DataFeedError, global_constants, models::data_types::{alpha::Alpha, numeric::Numeric, price::Price}, traits::message::TMessage, util::buffer_utils};
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, validation_category::ValidationCategory}, global_constants, models::field_validator::FieldValidator, util::message_layout::message_layout};
 
 const VALID_SIDE_VALUES: [char; 2] = ['B', 'S'];
 const VALID_EXCHANGE_ORDER_TYPE_VALUES: [u16; 7] = [4, 8, 32, (4 | 8), (4 | 32), (8 | 32), (4 | 8 | 32)];
 
-const MESSAGE_TYPE_BYTE_OFFSET: usize = 0;
-const NANOSECONDS_BYTE_OFFSET: usize = 1;
-const ORDER_ID_BYTE_OFFSET: usize = 5;
-const ORDER_BOOK_ID_BYTE_OFFSET: usize = 13;
-const SIDE_BYTE_OFFSET: usize = 17;
-const NEW_ORDER_BOOK_POSITION_BYTE_OFFSET: usize = 18;
-const QUANTITY_BYTE_OFFSET: usize = 22;
-const PRICE_BYTE_OFFSET: usize = 30;
-const EXCHANGE_ORDER_TYPE_BYTE_OFFSET: usize = 34;
-
-const MESSAGE_TYPE_LENGTH: usize = 1;
-const NANOSECONDS_LENGTH: usize = 4;
-const ORDER_ID_LENGTH: usize = 8;
-const ORDER_BOOK_ID_LENGTH: usize = 4;
-const SIDE_LENGTH: usize = 1;
-const NEW_ORDER_BOOK_POSITION_LENGTH: usize = 4;
-const QUANTITY_LENGTH: usize = 8;
-const EXCHANGE_ORDER_TYPE_LENGTH: usize = 2;
-
-pub struct OrderReplaceMessage {
-    pub message_type: Alpha<MESSAGE_TYPE_LENGTH>,
-    pub nanoseconds: Numeric<NANOSECONDS_LENGTH>,
-    pub order_id: Numeric<ORDER_ID_LENGTH>,
-    pub order_book_id: Numeric<ORDER_BOOK_ID_LENGTH>,
-    pub side: Alpha<SIDE_LENGTH>,
-    pub new_order_book_position: Numeric<NEW_ORDER_BOOK_POSITION_LENGTH>,
-    pub quantity: Numeric<QUANTITY_LENGTH>,
-    pub price: Price,
-    pub exchange_order_type: Numeric<EXCHANGE_ORDER_TYPE_LENGTH>
-}
-
-impl OrderReplaceMessage {
-    pub fn new(
-        message_type: Alpha<MESSAGE_TYPE_LENGTH>,
-        nanoseconds: Numeric<NANOSECONDS_LENGTH>,
-        order_id: Numeric<ORDER_ID_LENGTH>,
-        order_book_id: Numeric<ORDER_BOOK_ID_LENGTH>,
-        side: Alpha<SIDE_LENGTH>,
-        new_order_book_position: Numeric<NEW_ORDER_BOOK_POSITION_LENGTH>,
-        quantity: Numeric<QUANTITY_LENGTH>,
-        price: Price,
-        exchange_order_type: Numeric<EXCHANGE_ORDER_TYPE_LENGTH>
-    ) -> Result<Self, DataFeedError> {
-        let message = OrderReplaceMessage {
-            message_type,
-            nanoseconds,
-            order_id,
-            order_book_id,
-            side,
-            new_order_book_position,
-            quantity,
-            price,
-            exchange_order_type
-        };
-
-        message.validate_fields()?;
-
-        Ok(message)
+message_layout! {
+    pub struct OrderReplaceMessage('U', global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT) {
+        (message_type, Alpha, 1),
+        (nanoseconds, Numeric, 4),
+        (order_id, Numeric, 8),
+        (order_book_id, Numeric, 4),
+        (side, Alpha, 1),
+        (new_order_book_position, Numeric, 4),
+        (quantity, Numeric, 8),
+        (price, Price, 4),
+        (exchange_order_type, Numeric, 2)
     }
+    validate: validate_values
 }
 
-impl TMessage for OrderReplaceMessage {
-    fn to_bin<const SIZE: usize>(&self) -> Result<[u8; SIZE], DataFeedError> {
-        if SIZE != global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT {
-            return Err(DataFeedError::InvalidMessageSize(global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT, SIZE));
-        }
-
-        let mut binary = [0u8; SIZE];
-
-        buffer_utils::write_alpha(&mut binary, MESSAGE_TYPE_BYTE_OFFSET, &self.message_type.value);
-        buffer_utils::write_num(&mut binary, NANOSECONDS_BYTE_OFFSET, &self.nanoseconds.value.to_be_bytes());
-        buffer_utils::write_num(&mut binary, ORDER_ID_BYTE_OFFSET, &self.order_id.value.to_be_bytes());
-        buffer_utils::write_num(&mut binary, ORDER_BOOK_ID_BYTE_OFFSET, &self.order_book_id.value.to_be_bytes());
-        buffer_utils::write_alpha(&mut binary, SIDE_BYTE_OFFSET, &self.side.value);
-        buffer_utils::write_num(&mut binary, NEW_ORDER_BOOK_POSITION_BYTE_OFFSET, &self.new_order_book_position.value.to_be_bytes());
-        buffer_utils::write_num(&mut binary, QUANTITY_BYTE_OFFSET, &self.quantity.value.to_be_bytes());
-        buffer_utils::write_num(&mut binary, PRICE_BYTE_OFFSET, &self.price.value.to_be_bytes());
-        buffer_utils::write_num(&mut binary, EXCHANGE_ORDER_TYPE_BYTE_OFFSET, &self.exchange_order_type.value.to_be_bytes());
-
-        Ok(binary)
-    }
-
-    fn from_bin<const SIZE: usize>(binary_data: &[u8; SIZE]) -> Result<Self, DataFeedError> where Self: Sized {
-        if SIZE != global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT {
-            return Err(DataFeedError::InvalidMessageSize(global_constants::ORDER_REPLACE_MESSAGE_BYTE_COUNT, SIZE));
-        }
-
-        Ok(
-            OrderReplaceMessage::new(
-                buffer_utils::read_alpha(binary_data, MESSAGE_TYPE_BYTE_OFFSET)?,
-                buffer_utils::read_numeric(binary_data, NANOSECONDS_BYTE_OFFSET)?, 
-                buffer_utils::read_numeric(binary_data, ORDER_ID_BYTE_OFFSET)?, 
-                buffer_utils::read_numeric(binary_data, ORDER_BOOK_ID_BYTE_OFFSET)?, 
-                buffer_utils::read_alpha(binary_data, SIDE_BYTE_OFFSET)?, 
-                buffer_utils::read_numeric(binary_data, NEW_ORDER_BOOK_POSITION_BYTE_OFFSET)?, 
-                buffer_utils::read_numeric(binary_data, QUANTITY_BYTE_OFFSET)?, 
-                buffer_utils::read_price(binary_data, PRICE_BYTE_OFFSET), 
-                buffer_utils::read_numeric(binary_data, EXCHANGE_ORDER_TYPE_BYTE_OFFSET)?
-            )?
-        )
-    }
-
-    fn validate_fields(&self) -> Result<(), DataFeedError> {
-        if self.message_type.value != ['U'] {
-            return Err(DataFeedError::InvalidMessageType('U', self.message_type.value[0]));
-        }
-
-        if !VALID_SIDE_VALUES.contains(&self.side.value[0]) {
-            return Err(DataFeedError::InvalidSideValue(self.side.value[0]));
-        }
-
-        if !VALID_EXCHANGE_ORDER_TYPE_VALUES.contains(&(self.exchange_order_type.value as u16)) {
-            return Err(DataFeedError::InvalidExchangeOrderTypeValue(self.exchange_order_type.value));
-        }
+impl OrderReplaceMessage {
+    fn validate_values(&self, validator: &mut FieldValidator) -> Result<(), DataFeedError> {
+        validator.check(ValidationCategory::Side, "side", VALID_SIDE_VALUES.contains(&self.side.as_char()), || DataFeedErrorKind::InvalidSideValue(self.side.as_char()).into())?;
+        validator.check(ValidationCategory::ExchangeOrderType, "exchange_order_type", VALID_EXCHANGE_ORDER_TYPE_VALUES.contains(&(self.exchange_order_type.value as u16)), || DataFeedErrorKind::InvalidExchangeOrderTypeValue(self.exchange_order_type.value).into())?;
 
         Ok(())
     }
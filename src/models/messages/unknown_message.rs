@@ -0,0 +1,53 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, json_value::JsonValue}, traits::json::TJson};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownMessage {
+    pub message_type: char,
+    pub bytes: Vec<u8>
+}
+
+impl UnknownMessage {
+    pub fn new(bytes: &[u8]) -> Result<Self, DataFeedError> {
+        let Some(&message_type) = bytes.first() else {
+            return Err(DataFeedErrorKind::InvalidMessageSize(1, 0).into());
+        };
+
+        Ok(
+            UnknownMessage {
+                message_type: message_type as char,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+impl TJson for UnknownMessage {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError> {
+        Ok(
+            JsonValue::Object(vec![
+                ("message_type".into(), JsonValue::String(self.message_type.to_string())),
+                ("bytes".into(), JsonValue::Array(self.bytes.iter().map(|byte| JsonValue::Number(byte.to_string())).collect()))
+            ])
+        )
+    }
+
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized {
+        let Some(JsonValue::Array(values)) = json.get("bytes") else {
+            return Err(DataFeedErrorKind::InvalidFieldValue("bytes", "The field is missing.".into()).into());
+        };
+
+        let bytes = values
+            .iter()
+            .map(|value| value.as_number_str().and_then(|text| text.parse().ok()))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| DataFeedErrorKind::InvalidFieldValue("bytes", "Expected an array of byte values.".into()))?;
+
+        let message = UnknownMessage::new(&bytes)?;
+
+        if json.get("message_type").and_then(JsonValue::as_str) != Some(message.message_type.to_string().as_str()) {
+            return Err(DataFeedErrorKind::InvalidFieldValue("message_type", "The message type does not match the first byte.".into()).into());
+        }
+
+        Ok(message)
+    }
+}
\ No newline at end of file
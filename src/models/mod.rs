@@ -1,2 +1,28 @@
+pub mod book;
+pub mod capture;
+pub mod custom_message_decoder;
 pub mod data_types;
-pub mod messages;
\ No newline at end of file
+pub mod directory;
+pub mod error_context;
+pub mod export;
+pub mod feed_statistics;
+pub mod field_layout;
+pub mod field_validator;
+pub mod history;
+pub mod integrity;
+pub mod journal;
+pub mod message_decoder_registry;
+pub mod messages;
+pub mod order_book_statistics;
+pub mod pipeline_config;
+pub mod raw_message;
+pub mod replay_config;
+pub mod ring_buffer_statistics;
+pub mod session_statistics;
+pub mod session_summary;
+pub mod simulator;
+pub mod subscription_filter;
+pub mod subscription_handle;
+pub mod system_clock;
+pub mod timestamp;
+pub mod validation_policy;
\ No newline at end of file
@@ -0,0 +1,15 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderBookStatistics {
+    pub message_count: u64,
+    pub add_count: u64,
+    pub replace_count: u64,
+    pub cancel_count: u64,
+    pub trade_count: u64,
+    pub traded_quantity: u64
+}
+
+impl OrderBookStatistics {
+    pub fn cancel_to_trade_ratio(&self) -> Option<f64> {
+        (self.trade_count > 0).then(|| self.cancel_count as f64 / self.trade_count as f64)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PipelineConfig {
+    pub ring_buffer_capacity: usize,
+    pub batch_size: usize,
+    pub receive_core: Option<usize>,
+    pub processing_core: Option<usize>
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        PipelineConfig {
+            ring_buffer_capacity: 1 << 16,
+            batch_size: 256,
+            receive_core: None,
+            processing_core: None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, global_constants};
+
+#[derive(Clone, Copy)]
+pub struct RawMessage {
+    length: u16,
+    bytes: [u8; global_constants::MAX_MESSAGE_BYTE_COUNT]
+}
+
+impl RawMessage {
+    pub fn new(binary_data: &[u8]) -> Result<Self, DataFeedError> {
+        if binary_data.len() > global_constants::MAX_MESSAGE_BYTE_COUNT {
+            return Err(DataFeedErrorKind::InvalidMessageSize(global_constants::MAX_MESSAGE_BYTE_COUNT, binary_data.len()).into());
+        }
+
+        let mut bytes = [0u8; global_constants::MAX_MESSAGE_BYTE_COUNT];
+        bytes[..binary_data.len()].copy_from_slice(binary_data);
+
+        Ok(
+            RawMessage {
+                length: binary_data.len() as u16,
+                bytes
+            }
+        )
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length as usize]
+    }
+}
\ No newline at end of file
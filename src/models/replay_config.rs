@@ -0,0 +1,8 @@
+use crate::{enums::replay_mode::ReplayMode, models::timestamp::Timestamp};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReplayConfig {
+    pub mode: ReplayMode,
+    pub start_time: Option<Timestamp>,
+    pub stop_time: Option<Timestamp>
+}
\ No newline at end of file
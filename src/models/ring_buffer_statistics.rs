@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RingBufferStatistics {
+    pub capacity: usize,
+    pub pushed_count: u64,
+    pub popped_count: u64,
+    pub full_count: u64,
+    pub high_watermark: usize
+}
\ No newline at end of file
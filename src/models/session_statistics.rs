@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use crate::enums::itch_message::ItchMessage;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SessionStatistics {
+    pub message_count: u64,
+    pub message_counts_by_type: BTreeMap<char, u64>,
+    pub filtered_message_count: u64,
+    pub execution_count: u64,
+    pub executed_quantity: u64
+}
+
+impl SessionStatistics {
+    pub fn new() -> Self {
+        SessionStatistics::default()
+    }
+
+    pub fn record(&mut self, message: &ItchMessage) {
+        self.message_count += 1;
+        *self.message_counts_by_type.entry(message.message_type()).or_default() += 1;
+
+        let executed_quantity = match message {
+            ItchMessage::OrderExecuted(message) => message.executed_quantity.value as u64,
+            ItchMessage::OrderExecutedWithPrice(message) => message.executed_quantity.value as u64,
+            ItchMessage::Trade(message) => message.quantity.value as u64,
+            _ => return
+        };
+
+        self.execution_count += 1;
+        self.executed_quantity += executed_quantity;
+    }
+
+    pub fn record_filtered(&mut self) {
+        self.filtered_message_count += 1;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::fmt::Display;
+
+use crate::models::{session_statistics::SessionStatistics, timestamp::Timestamp};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionSummary {
+    pub session_start: Option<Timestamp>,
+    pub session_end: Timestamp,
+    pub statistics: SessionStatistics,
+    pub instrument_count: usize,
+    pub order_book_count: usize,
+    pub resting_order_count: usize,
+    pub anomaly_count: u64
+}
+
+impl Display for SessionSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.session_start {
+            Some(session_start) => writeln!(f, "Session: {session_start} - {}", self.session_end)?,
+            None => writeln!(f, "Session: ? - {}", self.session_end)?
+        }
+
+        writeln!(f, "Messages: {} ({} filtered)", self.statistics.message_count, self.statistics.filtered_message_count)?;
+
+        for (message_type, count) in self.statistics.message_counts_by_type.iter() {
+            writeln!(f, "  {message_type}: {count}")?;
+        }
+
+        writeln!(f, "Executions: {} ({} shares)", self.statistics.execution_count, self.statistics.executed_quantity)?;
+        writeln!(f, "Instruments: {}", self.instrument_count)?;
+        writeln!(f, "Order books: {}", self.order_book_count)?;
+        writeln!(f, "Resting orders: {}", self.resting_order_count)?;
+        write!(f, "Integrity anomalies: {}", self.anomaly_count)
+    }
+}
\ No newline at end of file
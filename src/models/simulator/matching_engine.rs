@@ -0,0 +1,483 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, models::{book::order_book::AUCTION_TRADING_STATES, data_types::{alpha::Alpha, numeric::Numeric, price::Price}, messages::{add_order_no_participant_id_message::AddOrderNoParticipantIdMessage, add_order_with_participant_id_message::AddOrderWithParticipantIdMessage, order_book_state_message::OrderBookStateMessage, order_delete_message::OrderDeleteMessage, order_executed_message::OrderExecutedMessage, order_executed_with_price_message::OrderExecutedWithPriceMessage, order_replace_message::OrderReplaceMessage, trade_message::TradeMessage}, simulator::{resting_order::RestingOrder, simulated_instrument::SimulatedInstrument}}};
+
+const EXCHANGE_ORDER_TYPE_LIMIT: u128 = 4;
+const LOT_TYPE_ROUND_LOT: u128 = 2;
+
+pub struct MatchingEngine {
+    instrument: SimulatedInstrument,
+    trading_state: Option<String>,
+    bids: BTreeMap<i32, VecDeque<RestingOrder>>,
+    asks: BTreeMap<i32, VecDeque<RestingOrder>>,
+    order_prices: HashMap<u64, (char, i32)>,
+    last_trade_price: Option<i32>
+}
+
+impl MatchingEngine {
+    pub fn new(instrument: SimulatedInstrument) -> Self {
+        MatchingEngine {
+            instrument,
+            trading_state: None,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            order_prices: HashMap::new(),
+            last_trade_price: None
+        }
+    }
+
+    pub fn instrument(&self) -> &SimulatedInstrument {
+        &self.instrument
+    }
+
+    pub fn order_book_id(&self) -> u32 {
+        self.instrument.order_book_id
+    }
+
+    pub fn trading_state(&self) -> Option<&str> {
+        self.trading_state.as_deref()
+    }
+
+    pub fn is_in_auction(&self) -> bool {
+        self.trading_state()
+            .is_some_and(|trading_state| AUCTION_TRADING_STATES.contains(&trading_state))
+    }
+
+    pub fn best_bid(&self) -> Option<i32> {
+        self.bids.keys().next_back().copied()
+    }
+
+    pub fn best_ask(&self) -> Option<i32> {
+        self.asks.keys().next().copied()
+    }
+
+    pub fn last_trade_price(&self) -> Option<i32> {
+        self.last_trade_price
+    }
+
+    pub fn order_count(&self) -> usize {
+        self.order_prices.len()
+    }
+
+    pub fn order(&self, order_id: u64) -> Option<&RestingOrder> {
+        let (side, price) = self.order_prices.get(&order_id)?;
+
+        self.levels(*side)
+            .get(price)?
+            .iter()
+            .find(|order| order.order_id == order_id)
+    }
+
+    pub fn orders(&self) -> impl Iterator<Item = &RestingOrder> {
+        self.bids
+            .values()
+            .rev()
+            .chain(self.asks.values())
+            .flatten()
+    }
+
+    pub fn order_book_position(&self, order_id: u64) -> Option<u32> {
+        let (side, price) = *self.order_prices.get(&order_id)?;
+        let levels = self.levels(side);
+
+        let orders_at_better_prices = match side {
+            'B' => levels.range(price + 1..).map(|(_, level)| level.len()).sum::<usize>(),
+            _ => levels.range(..price).map(|(_, level)| level.len()).sum::<usize>()
+        };
+
+        let queue_position = levels
+            .get(&price)?
+            .iter()
+            .position(|order| order.order_id == order_id)?;
+
+        Some((orders_at_better_prices + queue_position + 1) as u32)
+    }
+
+    pub fn add_order(&mut self, nanoseconds: u32, order: RestingOrder, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        if order.side != 'B' && order.side != 'S' {
+            return Err(DataFeedErrorKind::InvalidSideValue(order.side).into());
+        }
+
+        if order.quantity == 0 {
+            return Err(DataFeedErrorKind::InvalidFieldValue("quantity", "An order must have a positive quantity.".into()).into());
+        }
+
+        if self.order_prices.contains_key(&order.order_id) {
+            return Err(DataFeedErrorKind::InvalidFieldValue("order_id", format!("The order {} is already resting in the book.", order.order_id)).into());
+        }
+
+        let mut order = order;
+
+        if !self.is_in_auction() {
+            self.match_incoming(nanoseconds, &mut order, next_match_id, messages)?;
+        }
+
+        if order.quantity == 0 {
+            return Ok(());
+        }
+
+        let order_id = order.order_id;
+        self.rest(order);
+        messages.push(self.add_message(nanoseconds, order_id)?);
+
+        Ok(())
+    }
+
+    pub fn replace_order(&mut self, nanoseconds: u32, order_id: u64, price: i32, quantity: u64, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        if quantity == 0 {
+            return Err(DataFeedErrorKind::InvalidFieldValue("quantity", "A replaced order must keep a positive quantity.".into()).into());
+        }
+
+        let Some(order) = self.order(order_id).cloned() else {
+            return Err(DataFeedErrorKind::InvalidFieldValue("order_id", format!("The order {order_id} is not resting in the book.")).into());
+        };
+
+        if !self.is_in_auction() && self.crosses(order.side, price) {
+            self.cancel_order(nanoseconds, order_id, messages)?;
+
+            return self.add_order(
+                nanoseconds,
+                RestingOrder {
+                    price,
+                    quantity,
+                    ..order
+                },
+                next_match_id,
+                messages
+            );
+        }
+
+        if price == order.price && quantity <= order.quantity {
+            if let Some(resting_order) = self.order_mut(order_id) {
+                resting_order.quantity = quantity;
+            }
+        } else {
+            self.remove_order(order_id);
+            self.rest(
+                RestingOrder {
+                    price,
+                    quantity,
+                    ..order
+                }
+            );
+        }
+
+        messages.push(self.replace_message(nanoseconds, order_id)?);
+
+        Ok(())
+    }
+
+    pub fn cancel_order(&mut self, nanoseconds: u32, order_id: u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        let Some(order) = self.remove_order(order_id) else {
+            return Err(DataFeedErrorKind::InvalidFieldValue("order_id", format!("The order {order_id} is not resting in the book.")).into());
+        };
+
+        messages.push(
+            ItchMessage::OrderDelete(
+                OrderDeleteMessage::new(
+                    Alpha { value: [b'D'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: order.order_id as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha { value: [order.side as u8] }
+                )?
+            )
+        );
+
+        Ok(())
+    }
+
+    pub fn set_trading_state(&mut self, nanoseconds: u32, state_name: &str, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        let was_in_auction = self.is_in_auction();
+        self.trading_state = Some(state_name.to_string());
+
+        messages.push(
+            ItchMessage::OrderBookState(
+                OrderBookStateMessage::new(
+                    Alpha { value: [b'O'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha::from_str_padded(state_name)?
+                )?
+            )
+        );
+
+        if was_in_auction && !self.is_in_auction() {
+            self.uncross(nanoseconds, next_match_id, messages)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn report_trade(&mut self, nanoseconds: u32, price: i32, quantity: u64, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        let match_id = take_match_id(next_match_id);
+        self.last_trade_price = Some(price);
+
+        messages.push(
+            ItchMessage::Trade(
+                TradeMessage::new(
+                    Alpha { value: [b'P'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: match_id as u128 },
+                    Alpha { value: [b' '] },
+                    Numeric { value: quantity as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Price { value: price },
+                    Alpha::from_str_padded("")?,
+                    Alpha::from_str_padded("")?,
+                    Alpha { value: [b'Y'] },
+                    Alpha { value: [b'N'] }
+                )?
+            )
+        );
+
+        Ok(())
+    }
+
+    pub fn equilibrium_price(&self) -> Option<i32> {
+        let mut best = None;
+
+        for price in self.bids.keys().chain(self.asks.keys()).copied() {
+            let bid_quantity = self.bids.range(price..).flat_map(|(_, level)| level.iter()).map(|order| order.quantity).sum::<u64>();
+            let ask_quantity = self.asks.range(..=price).flat_map(|(_, level)| level.iter()).map(|order| order.quantity).sum::<u64>();
+            let executable_quantity = bid_quantity.min(ask_quantity);
+            let imbalance = bid_quantity.abs_diff(ask_quantity);
+
+            if executable_quantity == 0 {
+                continue;
+            }
+
+            let is_better = best.is_none_or(|(best_executable_quantity, best_imbalance, best_price)| {
+                (executable_quantity, std::cmp::Reverse(imbalance), std::cmp::Reverse(price)) > (best_executable_quantity, std::cmp::Reverse(best_imbalance), std::cmp::Reverse(best_price))
+            });
+
+            if is_better {
+                best = Some((executable_quantity, imbalance, price));
+            }
+        }
+
+        best.map(|(_, _, price)| price)
+    }
+
+    fn uncross(&mut self, nanoseconds: u32, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        let Some(equilibrium_price) = self.equilibrium_price() else {
+            return Ok(());
+        };
+
+        while self.best_bid().is_some_and(|bid| bid >= equilibrium_price) && self.best_ask().is_some_and(|ask| ask <= equilibrium_price) {
+            let bid = self.front_order('B').cloned().unwrap();
+            let ask = self.front_order('S').cloned().unwrap();
+            let executed_quantity = bid.quantity.min(ask.quantity);
+            let match_id = take_match_id(next_match_id);
+
+            messages.push(self.executed_with_price_message(nanoseconds, &bid, executed_quantity, match_id, &ask, equilibrium_price)?);
+            messages.push(self.executed_with_price_message(nanoseconds, &ask, executed_quantity, match_id, &bid, equilibrium_price)?);
+
+            self.fill_front_order('B', executed_quantity);
+            self.fill_front_order('S', executed_quantity);
+            self.last_trade_price = Some(equilibrium_price);
+        }
+
+        Ok(())
+    }
+
+    fn match_incoming(&mut self, nanoseconds: u32, incoming: &mut RestingOrder, next_match_id: &mut u64, messages: &mut Vec<ItchMessage>) -> Result<(), DataFeedError> {
+        let contra_side = if incoming.side == 'B' { 'S' } else { 'B' };
+
+        while incoming.quantity > 0 && self.crosses(incoming.side, incoming.price) {
+            let resting = self.front_order(contra_side).cloned().unwrap();
+            let executed_quantity = incoming.quantity.min(resting.quantity);
+            let match_id = take_match_id(next_match_id);
+
+            messages.push(
+                ItchMessage::OrderExecuted(
+                    OrderExecutedMessage::new(
+                        Alpha { value: [b'E'] },
+                        Numeric { value: nanoseconds as u128 },
+                        Numeric { value: resting.order_id as u128 },
+                        Numeric { value: self.order_book_id() as u128 },
+                        Alpha { value: [resting.side as u8] },
+                        Numeric { value: executed_quantity as u128 },
+                        Numeric { value: match_id as u128 },
+                        Alpha::from_str_padded(resting.participant_id.as_deref().unwrap_or_default())?,
+                        Alpha::from_str_padded(incoming.participant_id.as_deref().unwrap_or_default())?
+                    )?
+                )
+            );
+
+            incoming.quantity -= executed_quantity;
+            self.fill_front_order(contra_side, executed_quantity);
+            self.last_trade_price = Some(resting.price);
+        }
+
+        Ok(())
+    }
+
+    fn crosses(&self, side: char, price: i32) -> bool {
+        match side {
+            'B' => self.best_ask().is_some_and(|ask| ask <= price),
+            _ => self.best_bid().is_some_and(|bid| bid >= price)
+        }
+    }
+
+    fn front_order(&self, side: char) -> Option<&RestingOrder> {
+        match side {
+            'B' => self.bids.values().next_back()?.front(),
+            _ => self.asks.values().next()?.front()
+        }
+    }
+
+    fn fill_front_order(&mut self, side: char, executed_quantity: u64) {
+        let price = if side == 'B' { self.best_bid() } else { self.best_ask() };
+        let Some(price) = price else {
+            return;
+        };
+
+        let levels = self.levels_mut(side);
+        let Some(level) = levels.get_mut(&price) else {
+            return;
+        };
+
+        let Some(order) = level.front_mut() else {
+            return;
+        };
+
+        order.quantity = order.quantity.saturating_sub(executed_quantity);
+
+        if order.quantity == 0 {
+            let order_id = order.order_id;
+            level.pop_front();
+
+            if level.is_empty() {
+                levels.remove(&price);
+            }
+
+            self.order_prices.remove(&order_id);
+        }
+    }
+
+    fn rest(&mut self, order: RestingOrder) {
+        self.order_prices.insert(order.order_id, (order.side, order.price));
+        self.levels_mut(order.side)
+            .entry(order.price)
+            .or_default()
+            .push_back(order);
+    }
+
+    fn remove_order(&mut self, order_id: u64) -> Option<RestingOrder> {
+        let (side, price) = self.order_prices.remove(&order_id)?;
+        let levels = self.levels_mut(side);
+        let level = levels.get_mut(&price)?;
+        let order = level.remove(level.iter().position(|order| order.order_id == order_id)?);
+
+        if level.is_empty() {
+            levels.remove(&price);
+        }
+
+        order
+    }
+
+    fn order_mut(&mut self, order_id: u64) -> Option<&mut RestingOrder> {
+        let (side, price) = *self.order_prices.get(&order_id)?;
+
+        self.levels_mut(side)
+            .get_mut(&price)?
+            .iter_mut()
+            .find(|order| order.order_id == order_id)
+    }
+
+    fn levels(&self, side: char) -> &BTreeMap<i32, VecDeque<RestingOrder>> {
+        if side == 'B' { &self.bids } else { &self.asks }
+    }
+
+    fn levels_mut(&mut self, side: char) -> &mut BTreeMap<i32, VecDeque<RestingOrder>> {
+        if side == 'B' { &mut self.bids } else { &mut self.asks }
+    }
+
+    fn add_message(&self, nanoseconds: u32, order_id: u64) -> Result<ItchMessage, DataFeedError> {
+        let order = self.order(order_id).unwrap();
+        let order_book_position = self.order_book_position(order_id).unwrap_or(1);
+
+        let message = match &order.participant_id {
+            Some(participant_id) => ItchMessage::AddOrderWithParticipantId(
+                AddOrderWithParticipantIdMessage::new(
+                    Alpha { value: [b'F'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: order.order_id as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha { value: [order.side as u8] },
+                    Numeric { value: order_book_position as u128 },
+                    Numeric { value: order.quantity as u128 },
+                    Price { value: order.price },
+                    Numeric { value: EXCHANGE_ORDER_TYPE_LIMIT },
+                    Numeric { value: LOT_TYPE_ROUND_LOT },
+                    Alpha::from_str_padded(participant_id)?
+                )?
+            ),
+            None => ItchMessage::AddOrderNoParticipantId(
+                AddOrderNoParticipantIdMessage::new(
+                    Alpha { value: [b'A'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: order.order_id as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha { value: [order.side as u8] },
+                    Numeric { value: order_book_position as u128 },
+                    Numeric { value: order.quantity as u128 },
+                    Price { value: order.price },
+                    Numeric { value: EXCHANGE_ORDER_TYPE_LIMIT },
+                    Numeric { value: LOT_TYPE_ROUND_LOT }
+                )?
+            )
+        };
+
+        Ok(message)
+    }
+
+    fn replace_message(&self, nanoseconds: u32, order_id: u64) -> Result<ItchMessage, DataFeedError> {
+        let order = self.order(order_id).unwrap();
+
+        Ok(
+            ItchMessage::OrderReplace(
+                OrderReplaceMessage::new(
+                    Alpha { value: [b'U'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: order.order_id as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha { value: [order.side as u8] },
+                    Numeric { value: self.order_book_position(order_id).unwrap_or(1) as u128 },
+                    Numeric { value: order.quantity as u128 },
+                    Price { value: order.price },
+                    Numeric { value: EXCHANGE_ORDER_TYPE_LIMIT }
+                )?
+            )
+        )
+    }
+
+    fn executed_with_price_message(&self, nanoseconds: u32, order: &RestingOrder, executed_quantity: u64, match_id: u64, counterparty: &RestingOrder, trade_price: i32) -> Result<ItchMessage, DataFeedError> {
+        Ok(
+            ItchMessage::OrderExecutedWithPrice(
+                OrderExecutedWithPriceMessage::new(
+                    Alpha { value: [b'C'] },
+                    Numeric { value: nanoseconds as u128 },
+                    Numeric { value: order.order_id as u128 },
+                    Numeric { value: self.order_book_id() as u128 },
+                    Alpha { value: [order.side as u8] },
+                    Numeric { value: executed_quantity as u128 },
+                    Numeric { value: match_id as u128 },
+                    Alpha::from_str_padded(order.participant_id.as_deref().unwrap_or_default())?,
+                    Alpha::from_str_padded(counterparty.participant_id.as_deref().unwrap_or_default())?,
+                    Price { value: trade_price },
+                    Alpha { value: [b'Y'] },
+                    Alpha { value: [b'Y'] }
+                )?
+            )
+        )
+    }
+}
+
+fn take_match_id(next_match_id: &mut u64) -> u64 {
+    let match_id = *next_match_id;
+    *next_match_id += 1;
+
+    match_id
+}
\ No newline at end of file
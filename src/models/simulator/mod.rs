@@ -0,0 +1,5 @@
+pub mod matching_engine;
+pub mod random_order_flow;
+pub mod random_order_flow_config;
+pub mod resting_order;
+pub mod simulated_instrument;
\ No newline at end of file
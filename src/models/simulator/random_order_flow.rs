@@ -0,0 +1,121 @@
+use crate::{enums::order_instruction::OrderInstruction, exchange_simulator::ExchangeSimulator, models::{simulator::random_order_flow_config::RandomOrderFlowConfig, timestamp::Timestamp}};
+
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+pub struct RandomOrderFlow {
+    config: RandomOrderFlowConfig,
+    order_book_ids: Vec<u32>,
+    state: u64,
+    time: u64
+}
+
+impl RandomOrderFlow {
+    pub fn new(config: RandomOrderFlowConfig, order_book_ids: Vec<u32>, start_time: Timestamp) -> Self {
+        RandomOrderFlow {
+            config,
+            order_book_ids,
+            state: config.seed.max(1),
+            time: start_time.as_nanoseconds()
+        }
+    }
+
+    pub fn config(&self) -> RandomOrderFlowConfig {
+        self.config
+    }
+
+    pub fn next_instruction(&mut self, simulator: &ExchangeSimulator) -> (Timestamp, OrderInstruction) {
+        let interval = (self.next_f64() * 2.0 * self.config.mean_interval_nanoseconds as f64) as u64;
+        self.time += interval.max(1);
+
+        let time = Timestamp::new((self.time / NANOSECONDS_PER_SECOND) as u32, (self.time % NANOSECONDS_PER_SECOND) as u32);
+
+        let book_index = self.next_below(self.order_book_ids.len() as u64) as usize;
+        let order_book_id = self.order_book_ids[book_index];
+        let engine = simulator.engine(order_book_id);
+        let order_ids = engine
+            .map(|engine| engine.orders().map(|order| order.order_id).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let reference_price = engine
+            .and_then(|engine| match (engine.best_bid(), engine.best_ask()) {
+                (Some(best_bid), Some(best_ask)) if best_bid < best_ask => Some(best_bid + (best_ask - best_bid) / 2),
+                _ => engine.last_trade_price()
+            })
+            .unwrap_or(self.config.reference_price);
+
+        let roll = self.next_f64();
+
+        if !order_ids.is_empty() && roll < self.config.cancel_probability {
+            let order_id = order_ids[self.next_below(order_ids.len() as u64) as usize];
+
+            return (time, OrderInstruction::CancelOrder { order_id });
+        }
+
+        if !order_ids.is_empty() && roll < self.config.cancel_probability + self.config.replace_probability {
+            let order_id = order_ids[self.next_below(order_ids.len() as u64) as usize];
+            let side = engine
+                .and_then(|engine| engine.order(order_id))
+                .map(|order| order.side)
+                .unwrap_or('B');
+
+            return (
+                time,
+                OrderInstruction::ReplaceOrder {
+                    order_id,
+                    price: self.passive_price(side, reference_price),
+                    quantity: self.next_quantity()
+                }
+            );
+        }
+
+        let side = if self.next_below(2) == 0 { 'B' } else { 'S' };
+        let aggressive_price = engine.and_then(|engine| if side == 'B' { engine.best_ask() } else { engine.best_bid() });
+
+        let price = match aggressive_price {
+            Some(price) if self.next_f64() < self.config.aggressive_probability => price,
+            _ => self.passive_price(side, reference_price)
+        };
+
+        (
+            time,
+            OrderInstruction::AddOrder {
+                order_id: None,
+                order_book_id,
+                side,
+                price,
+                quantity: self.next_quantity(),
+                participant_id: None
+            }
+        )
+    }
+
+    fn passive_price(&mut self, side: char, reference_price: i32) -> i32 {
+        let tick_size = self.config.tick_size.max(1);
+        let ticks = 1 + self.next_below(self.config.max_spread_ticks.max(1) as u64) as i32;
+        let price = if side == 'B' { reference_price - ticks * tick_size } else { reference_price + ticks * tick_size };
+
+        price.max(tick_size)
+    }
+
+    fn next_quantity(&mut self) -> u64 {
+        let min_quantity = self.config.min_quantity.max(1);
+        let max_quantity = self.config.max_quantity.max(min_quantity);
+
+        min_quantity + self.next_below(max_quantity - min_quantity + 1)
+    }
+
+    fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound.max(1)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RandomOrderFlowConfig {
+    pub seed: u64,
+    pub reference_price: i32,
+    pub tick_size: i32,
+    pub max_spread_ticks: u32,
+    pub min_quantity: u64,
+    pub max_quantity: u64,
+    pub mean_interval_nanoseconds: u64,
+    pub cancel_probability: f64,
+    pub replace_probability: f64,
+    pub aggressive_probability: f64
+}
+
+impl Default for RandomOrderFlowConfig {
+    fn default() -> Self {
+        RandomOrderFlowConfig {
+            seed: 1,
+            reference_price: 10_000,
+            tick_size: 1,
+            max_spread_ticks: 10,
+            min_quantity: 1,
+            max_quantity: 1_000,
+            mean_interval_nanoseconds: 1_000_000,
+            cancel_probability: 0.2,
+            replace_probability: 0.1,
+            aggressive_probability: 0.1
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestingOrder {
+    pub order_id: u64,
+    pub side: char,
+    pub price: i32,
+    pub quantity: u64,
+    pub participant_id: Option<String>
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedInstrument {
+    pub order_book_id: u32,
+    pub symbol: String,
+    pub long_name: String,
+    pub isin: String,
+    pub financial_product: u8,
+    pub trading_currency: String,
+    pub number_of_decimals_in_price: u16,
+    pub round_lot_size: u32
+}
+
+impl SimulatedInstrument {
+    pub fn new(order_book_id: u32, symbol: &str) -> Self {
+        SimulatedInstrument {
+            order_book_id,
+            symbol: symbol.to_string(),
+            long_name: symbol.to_string(),
+            ..SimulatedInstrument::default()
+        }
+    }
+}
+
+impl Default for SimulatedInstrument {
+    fn default() -> Self {
+        SimulatedInstrument {
+            order_book_id: 0,
+            symbol: String::new(),
+            long_name: String::new(),
+            isin: String::new(),
+            financial_product: 5,
+            trading_currency: "AUD".into(),
+            number_of_decimals_in_price: 2,
+            round_lot_size: 1
+        }
+    }
+}
\ No newline at end of file
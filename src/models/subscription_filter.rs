@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use crate::{enums::itch_message::ItchMessage, models::{directory::{instrument::Instrument, instrument_directory::InstrumentDirectory}, message_decoder_registry::MessageDecoderRegistry}};
+
+#[derive(Clone, Default)]
+pub struct SubscriptionFilter {
+    order_book_ids: HashSet<u32>,
+    symbols: HashSet<String>,
+    isins: HashSet<String>,
+    financial_products: HashSet<u8>,
+    resolved_order_book_ids: HashSet<u32>,
+    directory: InstrumentDirectory
+}
+
+impl SubscriptionFilter {
+    pub fn new() -> Self {
+        SubscriptionFilter::default()
+    }
+
+    pub fn subscribe_order_book_id(&mut self, order_book_id: u32) {
+        self.order_book_ids.insert(order_book_id);
+        self.resolved_order_book_ids.insert(order_book_id);
+    }
+
+    pub fn unsubscribe_order_book_id(&mut self, order_book_id: u32) {
+        self.order_book_ids.remove(&order_book_id);
+        self.resolve();
+    }
+
+    pub fn subscribe_symbol(&mut self, symbol: &str) {
+        self.symbols.insert(symbol.trim_end().to_string());
+        self.resolve();
+    }
+
+    pub fn unsubscribe_symbol(&mut self, symbol: &str) {
+        self.symbols.remove(symbol.trim_end());
+        self.resolve();
+    }
+
+    pub fn subscribe_isin(&mut self, isin: &str) {
+        self.isins.insert(isin.trim_end().to_string());
+        self.resolve();
+    }
+
+    pub fn unsubscribe_isin(&mut self, isin: &str) {
+        self.isins.remove(isin.trim_end());
+        self.resolve();
+    }
+
+    pub fn subscribe_financial_product(&mut self, financial_product: u8) {
+        self.financial_products.insert(financial_product);
+        self.resolve();
+    }
+
+    pub fn unsubscribe_financial_product(&mut self, financial_product: u8) {
+        self.financial_products.remove(&financial_product);
+        self.resolve();
+    }
+
+    pub fn clear_subscriptions(&mut self) {
+        self.order_book_ids.clear();
+        self.symbols.clear();
+        self.isins.clear();
+        self.financial_products.clear();
+        self.resolved_order_book_ids.clear();
+    }
+
+    pub fn copy_subscriptions_from(&mut self, other: &SubscriptionFilter) {
+        self.order_book_ids.clone_from(&other.order_book_ids);
+        self.symbols.clone_from(&other.symbols);
+        self.isins.clone_from(&other.isins);
+        self.financial_products.clone_from(&other.financial_products);
+        self.resolve();
+    }
+
+    pub fn clear_directory(&mut self) {
+        self.directory.clear();
+        self.resolve();
+    }
+
+    pub fn process_directory_message(&mut self, message: &ItchMessage) {
+        let Some(order_book_id) = self.directory.process_message(message).map(|instrument| instrument.order_book_id) else {
+            return;
+        };
+
+        if self.directory.instrument(order_book_id).is_some_and(|instrument| self.matches(instrument)) {
+            self.resolved_order_book_ids.insert(order_book_id);
+        }
+    }
+
+    pub fn is_subscribed(&self, order_book_id: u32) -> bool {
+        self.resolved_order_book_ids.contains(&order_book_id)
+    }
+
+    pub fn accepts(&self, message: &ItchMessage) -> bool {
+        message
+            .order_book_id()
+            .is_none_or(|order_book_id| self.is_subscribed(order_book_id))
+    }
+
+    pub fn accepts_bin(&self, binary_data: &[u8], message_decoders: &MessageDecoderRegistry) -> bool {
+        message_decoders
+            .peek_order_book_id(binary_data)
+            .is_none_or(|order_book_id| self.is_subscribed(order_book_id))
+    }
+
+    pub fn subscribed_order_book_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.resolved_order_book_ids.iter().copied()
+    }
+
+    fn matches(&self, instrument: &Instrument) -> bool {
+        self.order_book_ids.contains(&instrument.order_book_id)
+            || self.symbols.contains(&instrument.symbol)
+            || self.isins.contains(&instrument.isin)
+            || self.financial_products.contains(&instrument.financial_product)
+    }
+
+    fn resolve(&mut self) {
+        let resolved_order_book_ids = self.directory
+            .instruments()
+            .filter(|instrument| self.matches(instrument))
+            .map(|instrument| instrument.order_book_id)
+            .chain(self.order_book_ids.iter().copied())
+            .collect();
+
+        self.resolved_order_book_ids = resolved_order_book_ids;
+    }
+}
\ No newline at end of file
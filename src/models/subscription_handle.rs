@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex, PoisonError, atomic::{AtomicU64, Ordering}};
+
+use crate::models::subscription_filter::SubscriptionFilter;
+
+struct SharedSubscriptions {
+    generation: AtomicU64,
+    subscriptions: Mutex<SubscriptionFilter>
+}
+
+#[derive(Clone)]
+pub struct SubscriptionHandle {
+    shared: Arc<SharedSubscriptions>
+}
+
+impl SubscriptionHandle {
+    pub fn new(subscription_filter: &SubscriptionFilter) -> Self {
+        let mut subscriptions = subscription_filter.clone();
+        subscriptions.clear_directory();
+
+        SubscriptionHandle {
+            shared: Arc::new(
+                SharedSubscriptions {
+                    generation: AtomicU64::new(0),
+                    subscriptions: Mutex::new(subscriptions)
+                }
+            )
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::Acquire)
+    }
+
+    pub fn update<F>(&self, update: F) where F: FnOnce(&mut SubscriptionFilter) {
+        let mut subscriptions = self.shared.subscriptions.lock().unwrap_or_else(PoisonError::into_inner);
+        update(&mut subscriptions);
+        self.shared.generation.fetch_add(1, Ordering::Release);
+    }
+
+    pub fn subscribe_order_book_id(&self, order_book_id: u32) {
+        self.update(|subscriptions| subscriptions.subscribe_order_book_id(order_book_id));
+    }
+
+    pub fn unsubscribe_order_book_id(&self, order_book_id: u32) {
+        self.update(|subscriptions| subscriptions.unsubscribe_order_book_id(order_book_id));
+    }
+
+    pub fn subscribe_symbol(&self, symbol: &str) {
+        self.update(|subscriptions| subscriptions.subscribe_symbol(symbol));
+    }
+
+    pub fn unsubscribe_symbol(&self, symbol: &str) {
+        self.update(|subscriptions| subscriptions.unsubscribe_symbol(symbol));
+    }
+
+    pub fn subscribe_isin(&self, isin: &str) {
+        self.update(|subscriptions| subscriptions.subscribe_isin(isin));
+    }
+
+    pub fn unsubscribe_isin(&self, isin: &str) {
+        self.update(|subscriptions| subscriptions.unsubscribe_isin(isin));
+    }
+
+    pub fn subscribe_financial_product(&self, financial_product: u8) {
+        self.update(|subscriptions| subscriptions.subscribe_financial_product(financial_product));
+    }
+
+    pub fn unsubscribe_financial_product(&self, financial_product: u8) {
+        self.update(|subscriptions| subscriptions.unsubscribe_financial_product(financial_product));
+    }
+
+    pub fn clear_subscriptions(&self) {
+        self.update(SubscriptionFilter::clear_subscriptions);
+    }
+
+    pub(crate) fn apply_if_newer(&self, applied_generation: &mut u64, subscription_filter: &mut SubscriptionFilter) {
+        let generation = self.generation();
+
+        if generation == *applied_generation {
+            return;
+        }
+
+        let subscriptions = self.shared.subscriptions.lock().unwrap_or_else(PoisonError::into_inner);
+        subscription_filter.copy_subscriptions_from(&subscriptions);
+        *applied_generation = generation;
+    }
+}
\ No newline at end of file
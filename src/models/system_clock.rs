@@ -0,0 +1,32 @@
+use std::{thread, time::{Duration, Instant}};
+
+use crate::traits::replay_clock::ReplayClock;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    origin: Instant
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock {
+            origin: Instant::now()
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl ReplayClock for SystemClock {
+    fn elapsed(&mut self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    pub seconds: u32,
+    pub nanoseconds: u32
+}
+
+impl Timestamp {
+    pub fn new(seconds: u32, nanoseconds: u32) -> Self {
+        Timestamp {
+            seconds,
+            nanoseconds
+        }
+    }
+
+    pub fn as_nanoseconds(&self) -> u64 {
+        (self.seconds as u64) * NANOSECONDS_PER_SECOND + (self.nanoseconds as u64)
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hours = self.seconds / 3600;
+        let minutes = (self.seconds % 3600) / 60;
+        let seconds = self.seconds % 60;
+
+        write!(f, "{hours:02}:{minutes:02}:{seconds:02}.{:09}", self.nanoseconds)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid_timestamp = || DataFeedErrorKind::ParseError(format!("'{text}' is not a valid timestamp. Expected HH:MM:SS[.fraction] or seconds[.fraction].")).into();
+
+        let (whole_text, fraction_text) = text.trim().split_once('.').unwrap_or((text.trim(), ""));
+
+        if fraction_text.len() > 9 || !fraction_text.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid_timestamp());
+        }
+
+        let mut seconds = 0u32;
+
+        for (component_index, component) in whole_text.rsplit(':').enumerate() {
+            let value = component.parse::<u32>().map_err(|_| invalid_timestamp())?;
+            let multiplier = match component_index {
+                0 => 1,
+                1 => 60,
+                2 => 3600,
+                _ => return Err(invalid_timestamp())
+            };
+
+            if component_index < 2 && whole_text.contains(':') && value >= 60 {
+                return Err(invalid_timestamp());
+            }
+
+            seconds = value
+                .checked_mul(multiplier)
+                .and_then(|component_seconds| seconds.checked_add(component_seconds))
+                .ok_or_else(invalid_timestamp)?;
+        }
+
+        let nanoseconds = if fraction_text.is_empty() {
+            0
+        } else {
+            fraction_text.parse::<u32>().map_err(|_| invalid_timestamp())? * 10u32.pow(9 - fraction_text.len() as u32)
+        };
+
+        Ok(Timestamp::new(seconds, nanoseconds))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+use crate::enums::{data_feed_error::DataFeedError, validation_category::ValidationCategory, validation_mode::ValidationMode};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidationPolicy {
+    modes: [ValidationMode; ValidationCategory::ALL.len()]
+}
+
+impl ValidationPolicy {
+    pub fn uniform(mode: ValidationMode) -> Self {
+        ValidationPolicy {
+            modes: [mode; ValidationCategory::ALL.len()]
+        }
+    }
+
+    pub fn strict() -> Self {
+        ValidationPolicy::uniform(ValidationMode::Strict)
+    }
+
+    pub fn lenient() -> Self {
+        ValidationPolicy::uniform(ValidationMode::Lenient)
+    }
+
+    pub fn off() -> Self {
+        ValidationPolicy::uniform(ValidationMode::Off)
+    }
+
+    pub fn with(mut self, category: ValidationCategory, mode: ValidationMode) -> Self {
+        self.set(category, mode);
+        self
+    }
+
+    pub fn set(&mut self, category: ValidationCategory, mode: ValidationMode) {
+        self.modes[category as usize] = mode;
+    }
+
+    pub fn mode(&self, category: ValidationCategory) -> ValidationMode {
+        self.modes[category as usize]
+    }
+}
+
+impl FromStr for ValidationPolicy {
+    type Err = DataFeedError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut policy = ValidationPolicy::strict();
+
+        for setting in text.split(',').map(str::trim).filter(|setting| !setting.is_empty()) {
+            match setting.split_once('=') {
+                Some((category, mode)) => policy.set(category.trim().parse()?, mode.trim().parse()?),
+                None => policy = ValidationPolicy::uniform(setting.parse()?)
+            }
+        }
+
+        Ok(policy)
+    }
+}
\ No newline at end of file
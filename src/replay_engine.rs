@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use crate::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, replay_mode::ReplayMode}, models::{replay_config::ReplayConfig, system_clock::SystemClock, timestamp::Timestamp}, traits::replay_clock::ReplayClock};
+
+type DecodeErrorCallback = Box<dyn FnMut(&DataFeedError) + Send>;
+
+pub struct ReplayEngine<I> {
+    source: I,
+    handler: DataFeedHandler,
+    config: ReplayConfig,
+    seconds: u32,
+    current_time: Option<Timestamp>,
+    clock: Box<dyn ReplayClock>,
+    pacing_anchor: Option<(Timestamp, Duration)>,
+    replayed_message_count: u64,
+    decode_error_count: u64,
+    decode_error_callback: Option<DecodeErrorCallback>,
+    is_finished: bool
+}
+
+impl<I, B> ReplayEngine<I>
+where
+    I: Iterator<Item = Result<B, DataFeedError>>,
+    B: AsRef<[u8]>
+{
+    pub fn new(source: I, handler: DataFeedHandler, config: ReplayConfig) -> Self {
+        ReplayEngine {
+            source,
+            handler,
+            config,
+            seconds: 0,
+            current_time: None,
+            clock: Box::new(SystemClock::new()),
+            pacing_anchor: None,
+            replayed_message_count: 0,
+            decode_error_count: 0,
+            decode_error_callback: None,
+            is_finished: false
+        }
+    }
+
+    pub fn handler(&self) -> &DataFeedHandler {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut DataFeedHandler {
+        &mut self.handler
+    }
+
+    pub fn into_handler(self) -> DataFeedHandler {
+        self.handler
+    }
+
+    pub fn config(&self) -> ReplayConfig {
+        self.config
+    }
+
+    pub fn set_clock<C>(&mut self, clock: C) where C: ReplayClock + 'static {
+        self.clock = Box::new(clock);
+        self.pacing_anchor = None;
+    }
+
+    pub fn current_time(&self) -> Option<Timestamp> {
+        self.current_time
+    }
+
+    pub fn replayed_message_count(&self) -> u64 {
+        self.replayed_message_count
+    }
+
+    pub fn decode_error_count(&self) -> u64 {
+        self.decode_error_count
+    }
+
+    pub fn set_decode_error_callback<F>(&mut self, decode_error_callback: F) where F: FnMut(&DataFeedError) + Send + 'static {
+        self.decode_error_callback = Some(Box::new(decode_error_callback));
+    }
+
+    pub fn clear_decode_error_callback(&mut self) {
+        self.decode_error_callback = None;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    pub fn step(&mut self) -> Result<Option<ItchMessage>, DataFeedError> {
+        let Some(message) = self.next_message()? else {
+            return Ok(None);
+        };
+
+        self.deliver(&message)?;
+
+        Ok(Some(message))
+    }
+
+    pub fn run(&mut self) -> Result<(), DataFeedError> {
+        let speed = match self.config.mode {
+            ReplayMode::RealTime => Some(1.0),
+            ReplayMode::Accelerated(multiplier) if multiplier > 0.0 => Some(multiplier),
+            ReplayMode::Accelerated(multiplier) => return Err(DataFeedErrorKind::Other(format!("The replay multiplier must be positive but was {multiplier}.")).into()),
+            ReplayMode::AsFastAsPossible => None,
+            ReplayMode::Step => return Err(DataFeedErrorKind::Other("Step mode replays are driven one message at a time through step().".into()).into())
+        };
+
+        while let Some(message) = self.next_message()? {
+            if let (Some(speed), Some(message_time)) = (speed, self.current_time) {
+                self.pace(message_time, speed);
+            }
+
+            self.deliver(&message)?;
+        }
+
+        Ok(())
+    }
+
+    fn next_message(&mut self) -> Result<Option<ItchMessage>, DataFeedError> {
+        while !self.is_finished {
+            let Some(binary_data) = self.source.next().transpose()? else {
+                self.is_finished = true;
+                break;
+            };
+
+            let message = match self.handler.decode_bin(binary_data.as_ref()) {
+                Ok(message) => message,
+                Err(error) => {
+                    self.report_decode_error(error);
+                    continue;
+                }
+            };
+
+            if let ItchMessage::Seconds(seconds_message) = &message {
+                self.seconds = seconds_message.second.value as u32;
+            }
+
+            let message_time = Timestamp::new(self.seconds, message.nanoseconds().unwrap_or(0));
+
+            if self.config.stop_time.is_some_and(|stop_time| message_time > stop_time) {
+                self.is_finished = true;
+                break;
+            }
+
+            self.current_time = Some(message_time);
+
+            if self.config.start_time.is_some_and(|start_time| message_time < start_time) {
+                self.handler.process_message(&message)?;
+                continue;
+            }
+
+            return Ok(Some(message));
+        }
+
+        Ok(None)
+    }
+
+    fn report_decode_error(&mut self, error: DataFeedError) {
+        self.decode_error_count += 1;
+
+        if let Some(decode_error_callback) = self.decode_error_callback.as_mut() {
+            decode_error_callback(&error);
+        }
+    }
+
+    fn deliver(&mut self, message: &ItchMessage) -> Result<(), DataFeedError> {
+        self.handler.process_message(message)?;
+        self.replayed_message_count += 1;
+
+        Ok(())
+    }
+
+    fn pace(&mut self, message_time: Timestamp, speed: f64) {
+        let now = self.clock.elapsed();
+        let (anchor_time, anchor_elapsed) = *self.pacing_anchor.get_or_insert((message_time, now));
+
+        let elapsed_nanoseconds = message_time.as_nanoseconds().saturating_sub(anchor_time.as_nanoseconds());
+        let target_elapsed = anchor_elapsed + Duration::from_nanos((elapsed_nanoseconds as f64 / speed) as u64);
+
+        if target_elapsed > now {
+            self.clock.sleep(target_elapsed - now);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use std::{sync::{Arc, Mutex, PoisonError, mpsc::{self, SyncSender}}, thread::{self, JoinHandle}};
+
+use crate::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage, shard_input::ShardInput}, models::{message_decoder_registry::MessageDecoderRegistry, raw_message::RawMessage}};
+
+type WorkerError = Arc<Mutex<Option<DataFeedError>>>;
+
+pub struct ShardedDataFeedHandler {
+    senders: Vec<SyncSender<ShardInput>>,
+    workers: Vec<JoinHandle<Option<DataFeedHandler>>>,
+    worker_errors: Vec<WorkerError>,
+    message_decoders: MessageDecoderRegistry
+}
+
+impl ShardedDataFeedHandler {
+    pub fn new<F>(shard_count: usize, queue_capacity: usize, handler_factory: F) -> Result<Self, DataFeedError>
+    where
+        F: Fn(usize) -> DataFeedHandler
+    {
+        if shard_count == 0 {
+            return Err(DataFeedErrorKind::Other("At least one shard is required for sharded processing.".into()).into());
+        }
+
+        let mut senders = Vec::with_capacity(shard_count);
+        let mut workers = Vec::with_capacity(shard_count);
+        let mut worker_errors = Vec::with_capacity(shard_count);
+        let mut message_decoders = MessageDecoderRegistry::new();
+
+        for shard_index in 0..shard_count {
+            let (sender, receiver) = mpsc::sync_channel::<ShardInput>(queue_capacity);
+            let mut handler = handler_factory(shard_index);
+
+            if shard_index == 0 {
+                message_decoders = handler.message_decoders().clone();
+            }
+
+            let worker_error = WorkerError::default();
+            let thread_worker_error = Arc::clone(&worker_error);
+
+            let worker = thread::Builder::new()
+                .name(format!("itch-shard-{shard_index}"))
+                .spawn(move || {
+                    let result = receiver.iter().try_for_each(|input| match input {
+                        ShardInput::Message(message) => handler.process_message(&message),
+                        ShardInput::Bin(raw_message) => handler.process_bin(raw_message.as_bytes())
+                    });
+
+                    match result {
+                        Ok(()) => Some(handler),
+                        Err(error) => {
+                            *thread_worker_error.lock().unwrap_or_else(PoisonError::into_inner) = Some(error);
+                            None
+                        }
+                    }
+                })
+                .map_err(|error| DataFeedErrorKind::Other(format!("Failed to spawn the worker for shard {shard_index}: {error}")))?;
+
+            senders.push(sender);
+            workers.push(worker);
+            worker_errors.push(worker_error);
+        }
+
+        Ok(
+            ShardedDataFeedHandler {
+                senders,
+                workers,
+                worker_errors,
+                message_decoders
+            }
+        )
+    }
+
+    pub fn shard_count(&self) -> usize {
+        self.senders.len()
+    }
+
+    pub fn shard_for(&self, order_book_id: u32) -> usize {
+        order_book_id as usize % self.senders.len()
+    }
+
+    pub fn route_bin(&self, binary_data: &[u8]) -> Result<(), DataFeedError> {
+        let raw_message = RawMessage::new(binary_data)?;
+
+        match self.message_decoders.peek_order_book_id(binary_data) {
+            Some(order_book_id) => self.send(self.shard_for(order_book_id), ShardInput::Bin(raw_message)),
+            None => {
+                for shard_index in 0..self.senders.len() {
+                    self.send(shard_index, ShardInput::Bin(raw_message))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn route(&self, message: ItchMessage) -> Result<(), DataFeedError> {
+        match message.order_book_id() {
+            Some(order_book_id) => self.send(self.shard_for(order_book_id), ShardInput::Message(message)),
+            None => {
+                let last_shard_index = self.senders.len() - 1;
+
+                for shard_index in 0..last_shard_index {
+                    self.send(shard_index, ShardInput::Message(message.clone()))?;
+                }
+
+                self.send(last_shard_index, ShardInput::Message(message))
+            }
+        }
+    }
+
+    pub fn join(self) -> Result<Vec<DataFeedHandler>, DataFeedError> {
+        drop(self.senders);
+
+        let mut handlers = Vec::with_capacity(self.workers.len());
+        let mut first_error = None;
+
+        for (shard_index, (worker, worker_error)) in self.workers.into_iter().zip(self.worker_errors).enumerate() {
+            match worker.join() {
+                Ok(Some(handler)) => handlers.push(handler),
+                Ok(None) => {
+                    first_error.get_or_insert_with(|| ShardedDataFeedHandler::take_worker_error(&worker_error, shard_index));
+                },
+                Err(_) => {
+                    first_error.get_or_insert(DataFeedErrorKind::Other(format!("The worker for shard {shard_index} panicked.")).into());
+                }
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(handlers)
+        }
+    }
+
+    fn send(&self, shard_index: usize, input: ShardInput) -> Result<(), DataFeedError> {
+        self.senders[shard_index]
+            .send(input)
+            .map_err(|_| ShardedDataFeedHandler::take_worker_error(&self.worker_errors[shard_index], shard_index))
+    }
+
+    fn take_worker_error(worker_error: &WorkerError, shard_index: usize) -> DataFeedError {
+        worker_error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .unwrap_or_else(|| DataFeedErrorKind::ShardDisconnected(shard_index).into())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+pub trait IndexKey: Copy + Default + Eq {
+    fn hash_key(self) -> u64;
+}
+
+impl IndexKey for u64 {
+    fn hash_key(self) -> u64 {
+        self
+    }
+}
+
+impl IndexKey for u128 {
+    fn hash_key(self) -> u64 {
+        (self as u64) ^ ((self >> 64) as u64).wrapping_mul(0xFF51_AFD7_ED55_8CCD)
+    }
+}
\ No newline at end of file
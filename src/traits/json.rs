@@ -0,0 +1,6 @@
+use crate::enums::{data_feed_error::DataFeedError, json_value::JsonValue};
+
+pub trait TJson {
+    fn to_json(&self) -> Result<JsonValue, DataFeedError>;
+    fn from_json(json: &JsonValue) -> Result<Self, DataFeedError> where Self: Sized;
+}
\ No newline at end of file
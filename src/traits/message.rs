@@ -1,7 +1,15 @@
-use crate::enums::data_feed_error::DataFeedError;
+use crate::{enums::data_feed_error::DataFeedError, models::{field_validator::FieldValidator, validation_policy::ValidationPolicy}};
 
 pub trait TMessage {
     fn to_bin<const SIZE: usize>(&self) -> Result<[u8; SIZE], DataFeedError>;
-    fn from_bin<const SIZE: usize>(binary_data: &[u8; SIZE]) -> Result<Self, DataFeedError> where Self: Sized;
-    fn validate_fields(&self) -> Result<(), DataFeedError>;
+    fn from_bin_with<const SIZE: usize>(binary_data: &[u8; SIZE], validator: &mut FieldValidator) -> Result<Self, DataFeedError> where Self: Sized;
+    fn validate_fields_with(&self, validator: &mut FieldValidator) -> Result<(), DataFeedError>;
+
+    fn from_bin<const SIZE: usize>(binary_data: &[u8; SIZE]) -> Result<Self, DataFeedError> where Self: Sized {
+        Self::from_bin_with(binary_data, &mut FieldValidator::new(&ValidationPolicy::strict(), &mut Vec::new()))
+    }
+
+    fn validate_fields(&self) -> Result<(), DataFeedError> {
+        self.validate_fields_with(&mut FieldValidator::new(&ValidationPolicy::strict(), &mut Vec::new()))
+    }
 }
\ No newline at end of file
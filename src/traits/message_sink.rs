@@ -0,0 +1,6 @@
+use crate::enums::{data_feed_error::DataFeedError, itch_message::ItchMessage};
+
+pub trait TMessageSink: Send {
+    fn write_message(&mut self, message: &ItchMessage) -> Result<(), DataFeedError>;
+    fn flush(&mut self) -> Result<(), DataFeedError>;
+}
\ No newline at end of file
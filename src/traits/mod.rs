@@ -1,2 +1,6 @@
 pub mod data_type;
-pub mod message;
\ No newline at end of file
+pub mod index_key;
+pub mod json;
+pub mod message;
+pub mod message_sink;
+pub mod replay_clock;
\ No newline at end of file
@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+pub trait ReplayClock: Send {
+    fn elapsed(&mut self) -> Duration;
+
+    fn sleep(&mut self, duration: Duration);
+}
\ No newline at end of file
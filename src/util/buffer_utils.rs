@@ -1,4 +1,4 @@
-use crate::{enums::data_feed_error::DataFeedError, models::data_types::{alpha::Alpha, numeric::{self, Numeric}, price::Price}};
+use crate::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, models::data_types::{alpha::Alpha, numeric::{self, Numeric}, price::Price}, traits::data_type::TDataType};
 
 #[inline(always)]
 pub fn write_num<const SIZE: usize>(
@@ -9,16 +9,39 @@ pub fn write_num<const SIZE: usize>(
     buf[offset..offset + SIZE].copy_from_slice(bytes);
 }
 
+#[inline(always)]
+pub fn write_numeric<const SIZE: usize>(
+    buf: &mut [u8],
+    offset: usize,
+    numeric: &Numeric<SIZE>
+) {
+    let bytes = numeric.value.to_be_bytes();
+    buf[offset..offset + SIZE].copy_from_slice(&bytes[bytes.len() - SIZE..]);
+}
+
+#[inline(always)]
+pub fn write_price(
+    buf: &mut [u8],
+    offset: usize,
+    price: &Price
+) {
+    write_num(buf, offset, &price.value.to_be_bytes());
+}
+
 #[inline(always)]
 pub fn write_alpha<const SIZE: usize>(
     buf: &mut [u8],
     offset: usize,
-    chars: &[char; SIZE]
+    alpha: &Alpha<SIZE>
 ) {
-    let dst = &mut buf[offset..offset + SIZE];
-    for i in 0..SIZE {
-        dst[i] = chars[i] as u8;
-    }
+    buf[offset..offset + SIZE].copy_from_slice(&alpha.value);
+}
+
+#[inline(always)]
+pub fn as_fixed_size<const SIZE: usize>(
+    buf: &[u8]
+) -> Result<&[u8; SIZE], DataFeedError> {
+    buf.try_into().map_err(|_| DataFeedErrorKind::InvalidMessageSize(SIZE, buf.len()).into())
 }
 
 #[inline(always)]
@@ -27,7 +50,7 @@ pub fn read_numeric<const SIZE: usize>(
     offset: usize,
 ) -> Result<Numeric<SIZE>, DataFeedError> {
     if !numeric::VALID_NUMERIC_SIZES.contains(&SIZE) {
-        return Err(DataFeedError::InvalidNumericSize(SIZE));
+        return Err(DataFeedErrorKind::InvalidNumericSize(SIZE).into());
     }
 
     let src = &buf[offset..offset + SIZE];
@@ -62,22 +85,14 @@ pub fn read_alpha<const SIZE: usize>(
     buf: &[u8],
     offset: usize,
 ) -> Result<Alpha<SIZE>, DataFeedError> {
-    let mut char_arr = [' '; SIZE];
-    let src = &buf[offset..offset + SIZE];
+    let mut value = [b' '; SIZE];
+    value.copy_from_slice(&buf[offset..offset + SIZE]);
 
-    for i in 0..SIZE {
-        let b = src[i];
+    let alpha = Alpha {
+        value
+    };
 
-        if b < 0x20 || b > 0x7F {
-            return Err(DataFeedError::NonISO88591Value(b as char));
-        }
-
-        char_arr[i] = b as char;
-    }
+    alpha.validate()?;
 
-    Ok(
-        Alpha {
-            value: char_arr
-        }
-    )
+    Ok(alpha)
 }
\ No newline at end of file
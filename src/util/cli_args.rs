@@ -0,0 +1,98 @@
+use std::{collections::{HashMap, HashSet}, str::FromStr};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+const LIST_SEPARATOR: char = ',';
+
+#[derive(Clone, Debug, Default)]
+pub struct CliArgs {
+    values: HashMap<String, Vec<String>>,
+    flags: HashSet<String>,
+    positionals: Vec<String>
+}
+
+impl CliArgs {
+    pub fn parse<I>(args: I, value_options: &[&str], flag_options: &[&str]) -> Result<Self, DataFeedError>
+    where
+        I: IntoIterator<Item = String>
+    {
+        let mut cli_args = CliArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                cli_args.positionals.extend(args.by_ref());
+                break;
+            }
+
+            let Some(option) = arg.strip_prefix("--").or_else(|| (arg == "-h").then_some("help")) else {
+                cli_args.positionals.push(arg);
+                continue;
+            };
+
+            let (name, inline_value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (option, None)
+            };
+
+            if value_options.contains(&name) {
+                let Some(value) = inline_value.or_else(|| args.next()) else {
+                    return Err(DataFeedErrorKind::ParseError(format!("The option --{name} requires a value.")).into());
+                };
+
+                cli_args.values.entry(name.to_string()).or_default().push(value);
+            } else if flag_options.contains(&name) && inline_value.is_none() {
+                cli_args.flags.insert(name.to_string());
+            } else {
+                return Err(DataFeedErrorKind::ParseError(format!("Unknown option: {arg}.")).into());
+            }
+        }
+
+        Ok(cli_args)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .and_then(|values| values.last())
+            .map(String::as_str)
+    }
+
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.values
+            .get(name)
+            .into_iter()
+            .flatten()
+            .flat_map(|value| value.split(LIST_SEPARATOR))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, DataFeedError> {
+        self.value(name)
+            .map(|value| CliArgs::parse_one(name, value))
+            .transpose()
+    }
+
+    pub fn parse_values<T: FromStr>(&self, name: &str) -> Result<Vec<T>, DataFeedError> {
+        self.values(name)
+            .into_iter()
+            .map(|value| CliArgs::parse_one(name, value))
+            .collect()
+    }
+
+    pub fn positionals(&self) -> &[String] {
+        &self.positionals
+    }
+
+    fn parse_one<T: FromStr>(name: &str, value: &str) -> Result<T, DataFeedError> {
+        value
+            .parse::<T>()
+            .map_err(|_| DataFeedErrorKind::ParseError(format!("'{value}' is not a valid value for --{name}.")).into())
+    }
+}
\ No newline at end of file
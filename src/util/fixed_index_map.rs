@@ -0,0 +1,136 @@
+use crate::traits::index_key::IndexKey;
+
+const EMPTY_SLOT: u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+struct Slot<K> {
+    key: K,
+    value: u32
+}
+
+pub struct FixedIndexMap<K: IndexKey = u64> {
+    slots: Vec<Slot<K>>,
+    mask: usize,
+    len: usize,
+    capacity: usize
+}
+
+impl<K: IndexKey> FixedIndexMap<K> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        let slot_count = (capacity.max(1) * 2).next_power_of_two();
+
+        FixedIndexMap {
+            slots: vec![Slot { key: K::default(), value: EMPTY_SLOT }; slot_count],
+            mask: slot_count - 1,
+            len: 0,
+            capacity
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn get(&self, key: K) -> Option<u32> {
+        let mut index = self.home_slot(key);
+
+        loop {
+            let slot = self.slots[index];
+
+            if slot.value == EMPTY_SLOT {
+                return None;
+            }
+
+            if slot.key == key {
+                return Some(slot.value);
+            }
+
+            index = (index + 1) & self.mask;
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: u32) -> bool {
+        let mut index = self.home_slot(key);
+
+        loop {
+            let slot = &mut self.slots[index];
+
+            if slot.value == EMPTY_SLOT {
+                if self.len == self.capacity {
+                    return false;
+                }
+
+                *slot = Slot { key, value };
+                self.len += 1;
+                return true;
+            }
+
+            if slot.key == key {
+                slot.value = value;
+                return true;
+            }
+
+            index = (index + 1) & self.mask;
+        }
+    }
+
+    pub fn remove(&mut self, key: K) -> Option<u32> {
+        let mut index = self.home_slot(key);
+
+        loop {
+            let slot = self.slots[index];
+
+            if slot.value == EMPTY_SLOT {
+                return None;
+            }
+
+            if slot.key == key {
+                break;
+            }
+
+            index = (index + 1) & self.mask;
+        }
+
+        let removed_value = self.slots[index].value;
+        let mut hole = index;
+        let mut next = (hole + 1) & self.mask;
+
+        while self.slots[next].value != EMPTY_SLOT {
+            let home = self.home_slot(self.slots[next].key);
+            let distance_from_home = next.wrapping_sub(home) & self.mask;
+            let distance_from_hole = next.wrapping_sub(hole) & self.mask;
+
+            if distance_from_home >= distance_from_hole {
+                self.slots[hole] = self.slots[next];
+                hole = next;
+            }
+
+            next = (next + 1) & self.mask;
+        }
+
+        self.slots[hole].value = EMPTY_SLOT;
+        self.len -= 1;
+
+        Some(removed_value)
+    }
+
+    pub fn clear(&mut self) {
+        for slot in self.slots.iter_mut() {
+            slot.value = EMPTY_SLOT;
+        }
+
+        self.len = 0;
+    }
+
+    fn home_slot(&self, key: K) -> usize {
+        (key.hash_key().wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32) as usize & self.mask
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+pub fn format_price(price: i32, number_of_decimals: u16) -> String {
+    if number_of_decimals == 0 {
+        return price.to_string();
+    }
+
+    let Some(divisor) = 10u64.checked_pow(number_of_decimals as u32) else {
+        return price.to_string();
+    };
+
+    let magnitude = price.unsigned_abs() as u64;
+    let sign = if price < 0 { "-" } else { "" };
+
+    format!("{sign}{}.{:0width$}", magnitude / divisor, magnitude % divisor, width = number_of_decimals as usize)
+}
+
+pub fn parse_price(text: &str, number_of_decimals: u16) -> Option<i32> {
+    let (is_negative, unsigned_text) = match text.strip_prefix('-') {
+        Some(unsigned_text) => (true, unsigned_text),
+        None => (false, text)
+    };
+
+    let (whole_text, fraction_text) = unsigned_text.split_once('.').unwrap_or((unsigned_text, ""));
+
+    if fraction_text.len() > number_of_decimals as usize || (whole_text.is_empty() && fraction_text.is_empty()) {
+        return None;
+    }
+
+    let whole = if whole_text.is_empty() { 0 } else { whole_text.parse::<i64>().ok()? };
+    let fraction = if fraction_text.is_empty() { 0 } else { fraction_text.parse::<i64>().ok()? };
+    let fraction_scale = 10i64.checked_pow((number_of_decimals as usize - fraction_text.len()) as u32)?;
+    let magnitude = whole
+        .checked_mul(10i64.checked_pow(number_of_decimals as u32)?)?
+        .checked_add(fraction.checked_mul(fraction_scale)?)?;
+
+    i32::try_from(if is_negative { -magnitude } else { magnitude }).ok()
+}
\ No newline at end of file
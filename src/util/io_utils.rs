@@ -0,0 +1,50 @@
+use std::io::{ErrorKind, Read};
+
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+pub fn read_exact_or_eof<R: Read>(reader: &mut R, buf: &mut [u8], context: &str) -> Result<bool, DataFeedError> {
+    let mut read_length = 0;
+
+    while read_length < buf.len() {
+        match reader.read(&mut buf[read_length..]) {
+            Ok(0) if read_length == 0 => return Ok(false),
+            Ok(0) => return Err(DataFeedErrorKind::MalformedCapture(format!("The {context} is truncated.")).into()),
+            Ok(length) => read_length += length,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {},
+            Err(error) => return Err(error.into())
+        }
+    }
+
+    Ok(true)
+}
+
+pub struct CountingReader<R> {
+    reader: R,
+    byte_count: u64
+}
+
+impl<R> CountingReader<R> {
+    pub fn new(reader: R) -> Self {
+        CountingReader {
+            reader,
+            byte_count: 0
+        }
+    }
+
+    pub fn byte_count(&self) -> u64 {
+        self.byte_count
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let length = self.reader.read(buf)?;
+        self.byte_count += length as u64;
+
+        Ok(length)
+    }
+}
\ No newline at end of file
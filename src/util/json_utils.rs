@@ -0,0 +1,256 @@
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_type::FieldType, field_value::FieldValue, json_value::JsonValue}, models::field_layout::FieldLayout};
+
+pub fn parse(text: &str) -> Result<JsonValue, DataFeedError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        position: 0
+    };
+
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+
+    if parser.position != parser.chars.len() {
+        return Err(parser.error("Unexpected trailing characters"));
+    }
+
+    Ok(value)
+}
+
+pub fn encode_message(fields: &[FieldLayout], binary_data: &[u8]) -> JsonValue {
+    JsonValue::Object(
+        fields
+            .iter()
+            .map(|field| (field.name.to_string(), field_value_to_json(&field.read(binary_data))))
+            .collect()
+    )
+}
+
+pub fn decode_message<const SIZE: usize>(fields: &[FieldLayout], json: &JsonValue) -> Result<[u8; SIZE], DataFeedError> {
+    let mut binary = [0u8; SIZE];
+    decode_message_into(fields, json, &mut binary)?;
+
+    Ok(binary)
+}
+
+pub fn decode_message_into(fields: &[FieldLayout], json: &JsonValue, binary_data: &mut [u8]) -> Result<(), DataFeedError> {
+    for field in fields {
+        let value = json
+            .get(field.name)
+            .ok_or_else(|| DataFeedErrorKind::InvalidFieldValue(field.name, "The field is missing.".into()))?;
+
+        field.write(binary_data, &json_to_field_value(field, value)?)?;
+    }
+
+    Ok(())
+}
+
+pub fn field_value_to_json(value: &FieldValue) -> JsonValue {
+    match value {
+        FieldValue::Alpha(value) => JsonValue::String(value.clone()),
+        FieldValue::Numeric(value) => JsonValue::Number(value.to_string()),
+        FieldValue::Price(value) => JsonValue::Number(value.to_string())
+    }
+}
+
+pub fn json_to_field_value(field: &FieldLayout, json: &JsonValue) -> Result<FieldValue, DataFeedError> {
+    let value = match (field.field_type, json) {
+        (FieldType::Alpha, JsonValue::String(value)) => Some(FieldValue::Alpha(value.clone())),
+        (FieldType::Numeric, JsonValue::Number(value)) => value.parse().ok().map(FieldValue::Numeric),
+        (FieldType::Price, JsonValue::Number(value)) => value.parse().ok().map(FieldValue::Price),
+        _ => None
+    };
+
+    value.ok_or_else(|| DataFeedErrorKind::InvalidFieldValue(field.name, format!("The JSON value {json} does not match the {:?} field type.", field.field_type)).into())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize
+}
+
+impl Parser {
+    fn parse_value(&mut self) -> Result<JsonValue, DataFeedError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some('t') => self.parse_literal("true", JsonValue::Bool(true)),
+            Some('f') => self.parse_literal("false", JsonValue::Bool(false)),
+            Some('n') => self.parse_literal("null", JsonValue::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(_) => Err(self.error("Unexpected character")),
+            None => Err(self.error("Unexpected end of input"))
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, DataFeedError> {
+        let mut members = Vec::new();
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(JsonValue::Object(members)),
+                _ => return Err(self.error("Expected ',' or '}'"))
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, DataFeedError> {
+        let mut values = Vec::new();
+        self.expect('[')?;
+        self.skip_whitespace();
+
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(JsonValue::Array(values)),
+                _ => return Err(self.error("Expected ',' or ']'"))
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, DataFeedError> {
+        let mut value = String::new();
+        self.expect('"')?;
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('/') => value.push('/'),
+                    Some('b') => value.push('\u{8}'),
+                    Some('f') => value.push('\u{C}'),
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some('u') => value.push(self.parse_unicode_escape()?),
+                    _ => return Err(self.error("Invalid escape sequence"))
+                },
+                Some(c) if (c as u32) < 0x20 => return Err(self.error("Unescaped control character in string")),
+                Some(c) => value.push(c),
+                None => return Err(self.error("Unterminated string"))
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, DataFeedError> {
+        let high = self.parse_hex_code_unit()?;
+
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+            if self.next() != Some('\\') || self.next() != Some('u') {
+                return Err(self.error("Unpaired surrogate in unicode escape"));
+            }
+
+            let low = self.parse_hex_code_unit()?;
+
+            if !(0xDC00..=0xDFFF).contains(&low) {
+                return Err(self.error("Invalid low surrogate in unicode escape"));
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code_point).ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+
+    fn parse_hex_code_unit(&mut self) -> Result<u32, DataFeedError> {
+        let mut code_unit = 0;
+
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("Invalid hexadecimal digit in unicode escape"))?;
+
+            code_unit = code_unit * 16 + digit;
+        }
+
+        Ok(code_unit)
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, DataFeedError> {
+        let start = self.position;
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')) {
+            self.position += 1;
+        }
+
+        let number = self.chars[start..self.position].iter().collect::<String>();
+
+        if number.parse::<f64>().is_err() {
+            return Err(self.error("Invalid number"));
+        }
+
+        Ok(JsonValue::Number(number))
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, DataFeedError> {
+        for expected in literal.chars() {
+            if self.next() != Some(expected) {
+                return Err(self.error("Invalid literal"));
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), DataFeedError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(self.error(&format!("Expected '{expected}'")))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.position += 1;
+
+        c
+    }
+
+    fn error(&self, reason: &str) -> DataFeedError {
+        DataFeedErrorKind::ParseError(format!("{reason} at position {} of the JSON input.", self.position)).into()
+    }
+}
\ No newline at end of file
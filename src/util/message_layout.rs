@@ -0,0 +1,169 @@
+use crate::{enums::field_type::FieldType, global_constants, models::{data_types::{numeric, price}, field_layout::FieldLayout}};
+
+pub const fn field_offset(spans: &[usize], index: usize) -> usize {
+    let mut offset = 0;
+    let mut position = 0;
+
+    while position < index {
+        offset += spans[position];
+        position += 1;
+    }
+
+    offset
+}
+
+pub const fn verify_layout(fields: &[FieldLayout], spans: &[usize], byte_count: usize) {
+    assert!(!fields.is_empty() && fields.len() == spans.len(), "A message layout needs one span per field.");
+    assert!(byte_count <= global_constants::MAX_MESSAGE_BYTE_COUNT, "The message byte count exceeds MAX_MESSAGE_BYTE_COUNT.");
+
+    let mut index = 0;
+
+    while index < fields.len() {
+        let field = &fields[index];
+
+        assert!(field.length > 0 && field.length <= spans[index], "A field must have a length between 1 and its span.");
+
+        match field.field_type {
+            FieldType::Numeric => assert!(is_valid_numeric_size(field.length), "A numeric field length must be one of VALID_NUMERIC_SIZES."),
+            FieldType::Price => assert!(field.length == price::PRICE_LENGTH, "A price field length must be PRICE_LENGTH."),
+            FieldType::Alpha => {}
+        }
+
+        index += 1;
+    }
+
+    let last_index = fields.len() - 1;
+
+    assert!(fields[last_index].offset + spans[last_index] == byte_count, "The message fields do not add up to the byte count in global_constants.");
+}
+
+const fn is_valid_numeric_size(length: usize) -> bool {
+    let mut index = 0;
+
+    while index < numeric::VALID_NUMERIC_SIZES.len() {
+        if numeric::VALID_NUMERIC_SIZES[index] == length {
+            return true;
+        }
+
+        index += 1;
+    }
+
+    false
+}
+
+macro_rules! message_layout {
+    (
+        pub struct $name:ident($message_type:literal, $byte_count:path) {
+            $(($field:ident, $kind:ident, $length:literal $(, padding = $padding:literal)?)),+ $(,)?
+        }
+        $(validate: $validate:ident)?
+    ) => {
+        #[allow(non_camel_case_types, dead_code)]
+        enum FieldIndex {
+            $($field),+
+        }
+
+        const FIELD_SPANS: &[usize] = &[$($length $(+ $padding)?),+];
+
+        pub const FIELDS: [$crate::models::field_layout::FieldLayout; FIELD_SPANS.len()] = [
+            $(
+                $crate::models::field_layout::FieldLayout {
+                    name: stringify!($field),
+                    field_type: $crate::enums::field_type::FieldType::$kind,
+                    offset: $crate::util::message_layout::field_offset(FIELD_SPANS, FieldIndex::$field as usize),
+                    length: $length
+                }
+            ),+
+        ];
+
+        const _: () = $crate::util::message_layout::verify_layout(&FIELDS, FIELD_SPANS, $byte_count);
+
+        #[allow(non_upper_case_globals)]
+        pub mod offsets {
+            $(pub const $field: usize = super::FIELDS[super::FieldIndex::$field as usize].offset;)+
+        }
+
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $field: $crate::util::message_layout::message_layout!(@type $kind, $length)),+
+        }
+
+        impl $name {
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(
+                $($field: $crate::util::message_layout::message_layout!(@type $kind, $length)),+
+            ) -> Result<Self, $crate::enums::data_feed_error::DataFeedError> {
+                let message = $name {
+                    $($field),+
+                };
+
+                $crate::traits::message::TMessage::validate_fields(&message)?;
+
+                Ok(message)
+            }
+        }
+
+        impl $crate::traits::message::TMessage for $name {
+            fn to_bin<const SIZE: usize>(&self) -> Result<[u8; SIZE], $crate::enums::data_feed_error::DataFeedError> {
+                if SIZE != $byte_count {
+                    return Err($crate::enums::data_feed_error::DataFeedErrorKind::InvalidMessageSize($byte_count, SIZE).into());
+                }
+
+                let mut binary = [0u8; SIZE];
+
+                $($crate::util::message_layout::message_layout!(@write $kind, &mut binary, offsets::$field, &self.$field);)+
+
+                Ok(binary)
+            }
+
+            fn from_bin_with<const SIZE: usize>(binary_data: &[u8; SIZE], validator: &mut $crate::models::field_validator::FieldValidator) -> Result<Self, $crate::enums::data_feed_error::DataFeedError> where Self: Sized {
+                if SIZE != $byte_count {
+                    return Err($crate::enums::data_feed_error::DataFeedErrorKind::InvalidMessageSize($byte_count, SIZE).into());
+                }
+
+                let message = $name {
+                    $(
+                        $field: $crate::util::message_layout::message_layout!(@read $kind, binary_data, offsets::$field)
+                            .map_err(|error| error.with_field(stringify!($field)).with_message_type($message_type))?
+                    ),+
+                };
+
+                $crate::traits::message::TMessage::validate_fields_with(&message, validator)?;
+
+                Ok(message)
+            }
+
+            #[allow(unused_variables)]
+            fn validate_fields_with(&self, validator: &mut $crate::models::field_validator::FieldValidator) -> Result<(), $crate::enums::data_feed_error::DataFeedError> {
+                if self.message_type.as_char() != $message_type {
+                    return Err($crate::enums::data_feed_error::DataFeedErrorKind::InvalidMessageType($message_type, self.message_type.as_char()).into());
+                }
+
+                $(self.$validate(&mut validator.for_message_type($message_type))?;)?
+
+                Ok(())
+            }
+        }
+
+        impl $crate::traits::json::TJson for $name {
+            fn to_json(&self) -> Result<$crate::enums::json_value::JsonValue, $crate::enums::data_feed_error::DataFeedError> {
+                Ok($crate::util::json_utils::encode_message(&FIELDS, &$crate::traits::message::TMessage::to_bin::<{ $byte_count }>(self)?))
+            }
+
+            fn from_json(json: &$crate::enums::json_value::JsonValue) -> Result<Self, $crate::enums::data_feed_error::DataFeedError> where Self: Sized {
+                $crate::traits::message::TMessage::from_bin(&$crate::util::json_utils::decode_message::<{ $byte_count }>(&FIELDS, json)?)
+            }
+        }
+    };
+    (@type Alpha, $length:literal) => { $crate::models::data_types::alpha::Alpha<$length> };
+    (@type Numeric, $length:literal) => { $crate::models::data_types::numeric::Numeric<$length> };
+    (@type Price, $length:literal) => { $crate::models::data_types::price::Price };
+    (@write Alpha, $binary:expr, $offset:expr, $value:expr) => { $crate::util::buffer_utils::write_alpha($binary, $offset, $value) };
+    (@write Numeric, $binary:expr, $offset:expr, $value:expr) => { $crate::util::buffer_utils::write_numeric($binary, $offset, $value) };
+    (@write Price, $binary:expr, $offset:expr, $value:expr) => { $crate::util::buffer_utils::write_price($binary, $offset, $value) };
+    (@read Alpha, $binary:expr, $offset:expr) => { $crate::util::buffer_utils::read_alpha($binary, $offset) };
+    (@read Numeric, $binary:expr, $offset:expr) => { $crate::util::buffer_utils::read_numeric($binary, $offset) };
+    (@read Price, $binary:expr, $offset:expr) => { Ok::<_, $crate::enums::data_feed_error::DataFeedError>($crate::util::buffer_utils::read_price($binary, $offset)) };
+}
+
+pub(crate) use message_layout;
\ No newline at end of file
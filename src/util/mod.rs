@@ -1 +1,12 @@
-pub mod buffer_utils;
\ No newline at end of file
+pub mod buffer_utils;
+pub mod cli_args;
+pub mod fixed_index_map;
+pub mod format_utils;
+pub mod io_utils;
+pub mod json_utils;
+pub mod message_layout;
+pub mod packet_parser;
+pub mod simulator_script;
+pub mod spsc_ring_buffer;
+pub mod text_format;
+pub mod thread_affinity;
\ No newline at end of file
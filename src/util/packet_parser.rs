@@ -0,0 +1,93 @@
+use std::net::Ipv4Addr;
+
+use crate::models::capture::udp_datagram::UdpDatagram;
+
+pub const LINK_TYPE_ETHERNET: u32 = 1;
+pub const LINK_TYPE_RAW: u32 = 101;
+pub const LINK_TYPE_LINUX_SLL: u32 = 113;
+pub const LINK_TYPE_IPV4: u32 = 228;
+pub const LINK_TYPE_LINUX_SLL2: u32 = 276;
+
+const ETHER_TYPE_IPV4: u16 = 0x0800;
+const ETHER_TYPE_VLAN: u16 = 0x8100;
+const ETHER_TYPE_QINQ: u16 = 0x88A8;
+const ETHERNET_HEADER_BYTE_COUNT: usize = 14;
+const VLAN_TAG_BYTE_COUNT: usize = 4;
+const LINUX_SLL_HEADER_BYTE_COUNT: usize = 16;
+const LINUX_SLL2_HEADER_BYTE_COUNT: usize = 20;
+const IPV4_MINIMUM_HEADER_BYTE_COUNT: usize = 20;
+const IP_PROTOCOL_UDP: u8 = 17;
+const UDP_HEADER_BYTE_COUNT: usize = 8;
+
+pub fn parse_udp_datagram(frame: &[u8], link_type: u32) -> Option<UdpDatagram<'_>> {
+    let ipv4_packet = match link_type {
+        LINK_TYPE_ETHERNET => parse_ethernet(frame)?,
+        LINK_TYPE_RAW | LINK_TYPE_IPV4 => frame,
+        LINK_TYPE_LINUX_SLL => parse_linux_sll(frame, LINUX_SLL_HEADER_BYTE_COUNT, LINUX_SLL_HEADER_BYTE_COUNT - 2)?,
+        LINK_TYPE_LINUX_SLL2 => parse_linux_sll(frame, LINUX_SLL2_HEADER_BYTE_COUNT, 0)?,
+        _ => return None
+    };
+
+    parse_ipv4_udp(ipv4_packet)
+}
+
+fn parse_ethernet(frame: &[u8]) -> Option<&[u8]> {
+    let mut ether_type_offset = ETHERNET_HEADER_BYTE_COUNT - 2;
+    let mut ether_type = read_u16(frame, ether_type_offset)?;
+
+    while ether_type == ETHER_TYPE_VLAN || ether_type == ETHER_TYPE_QINQ {
+        ether_type_offset += VLAN_TAG_BYTE_COUNT;
+        ether_type = read_u16(frame, ether_type_offset)?;
+    }
+
+    if ether_type != ETHER_TYPE_IPV4 {
+        return None;
+    }
+
+    frame.get(ether_type_offset + 2..)
+}
+
+fn parse_linux_sll(frame: &[u8], header_byte_count: usize, protocol_offset: usize) -> Option<&[u8]> {
+    if read_u16(frame, protocol_offset)? != ETHER_TYPE_IPV4 {
+        return None;
+    }
+
+    frame.get(header_byte_count..)
+}
+
+fn parse_ipv4_udp(packet: &[u8]) -> Option<UdpDatagram<'_>> {
+    if packet.len() < IPV4_MINIMUM_HEADER_BYTE_COUNT || packet[0] >> 4 != 4 {
+        return None;
+    }
+
+    let header_byte_count = ((packet[0] & 0x0F) as usize) * 4;
+    let total_length = read_u16(packet, 2)? as usize;
+    let fragment_field = read_u16(packet, 6)?;
+    let is_fragment = fragment_field & 0x2000 != 0 || fragment_field & 0x1FFF != 0;
+
+    if header_byte_count < IPV4_MINIMUM_HEADER_BYTE_COUNT || packet[9] != IP_PROTOCOL_UDP || is_fragment {
+        return None;
+    }
+
+    let packet = packet.get(..total_length.min(packet.len()))?;
+    let segment = packet.get(header_byte_count..)?;
+    let udp_length = read_u16(segment, 4)? as usize;
+
+    if udp_length < UDP_HEADER_BYTE_COUNT {
+        return None;
+    }
+
+    Some(
+        UdpDatagram {
+            source_address: Ipv4Addr::new(packet[12], packet[13], packet[14], packet[15]),
+            destination_address: Ipv4Addr::new(packet[16], packet[17], packet[18], packet[19]),
+            source_port: read_u16(segment, 0)?,
+            destination_port: read_u16(segment, 2)?,
+            payload: segment.get(UDP_HEADER_BYTE_COUNT..udp_length)?
+        }
+    )
+}
+
+fn read_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([*buf.get(offset)?, *buf.get(offset + 1)?]))
+}
\ No newline at end of file
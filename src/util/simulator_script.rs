@@ -0,0 +1,102 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, order_instruction::OrderInstruction}, models::{simulator::simulated_instrument::SimulatedInstrument, timestamp::Timestamp}, util::text_format};
+
+const COMMENT_PREFIX: char = '#';
+
+struct ScriptArguments<'a> {
+    command: &'a str,
+    values: HashMap<&'a str, &'a str>
+}
+
+impl ScriptArguments<'_> {
+    fn optional<T: FromStr>(&self, key: &str) -> Result<Option<T>, DataFeedError> {
+        self.values
+            .get(key)
+            .map(|text| text.parse::<T>().map_err(|_| DataFeedErrorKind::ParseError(format!("'{text}' is not a valid value for '{key}' in the '{}' command.", self.command)).into()))
+            .transpose()
+    }
+
+    fn required<T: FromStr>(&self, key: &str) -> Result<T, DataFeedError> {
+        self.optional(key)?
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("The '{}' command requires '{key}'.", self.command)).into())
+    }
+}
+
+pub fn parse_instruction(line: &str) -> Result<Option<(Timestamp, OrderInstruction)>, DataFeedError> {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+        return Ok(None);
+    }
+
+    let tokens = text_format::tokenize(line)?;
+
+    let [time, command, pairs @ ..] = tokens.as_slice() else {
+        return Err(DataFeedErrorKind::ParseError(format!("Expected '<time> <command> key=value...' but found '{line}'.")).into());
+    };
+
+    let mut values = HashMap::new();
+
+    for pair in pairs {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Expected key=value but found '{pair}'.")))?;
+
+        values.insert(key, value);
+    }
+
+    let arguments = ScriptArguments {
+        command,
+        values
+    };
+
+    let instruction = match command.as_str() {
+        "instrument" => {
+            let default_instrument = SimulatedInstrument::default();
+            let symbol = arguments.required::<String>("symbol")?;
+
+            OrderInstruction::AddInstrument(
+                SimulatedInstrument {
+                    order_book_id: arguments.required("book")?,
+                    long_name: arguments.optional("long_name")?.unwrap_or_else(|| symbol.clone()),
+                    symbol,
+                    isin: arguments.optional("isin")?.unwrap_or_default(),
+                    financial_product: arguments.optional("product")?.unwrap_or(default_instrument.financial_product),
+                    trading_currency: arguments.optional("currency")?.unwrap_or(default_instrument.trading_currency),
+                    number_of_decimals_in_price: arguments.optional("decimals")?.unwrap_or(default_instrument.number_of_decimals_in_price),
+                    round_lot_size: arguments.optional("lot")?.unwrap_or(default_instrument.round_lot_size)
+                }
+            )
+        },
+        "system" => OrderInstruction::SystemEvent(arguments.required("code")?),
+        "state" => OrderInstruction::SetTradingState {
+            order_book_id: arguments.required("book")?,
+            state_name: arguments.required("name")?
+        },
+        "add" => OrderInstruction::AddOrder {
+            order_id: arguments.optional("oid")?,
+            order_book_id: arguments.required("book")?,
+            side: arguments.required("side")?,
+            price: arguments.required("px")?,
+            quantity: arguments.required("qty")?,
+            participant_id: arguments.optional("participant")?
+        },
+        "replace" => OrderInstruction::ReplaceOrder {
+            order_id: arguments.required("oid")?,
+            price: arguments.required("px")?,
+            quantity: arguments.required("qty")?
+        },
+        "cancel" => OrderInstruction::CancelOrder {
+            order_id: arguments.required("oid")?
+        },
+        "trade" => OrderInstruction::ReportTrade {
+            order_book_id: arguments.required("book")?,
+            price: arguments.required("px")?,
+            quantity: arguments.required("qty")?
+        },
+        _ => return Err(DataFeedErrorKind::ParseError(format!("Unknown simulator command '{command}'.")).into())
+    };
+
+    Ok(Some((time.parse()?, instruction)))
+}
\ No newline at end of file
@@ -0,0 +1,324 @@
+use std::{cell::UnsafeCell, mem::MaybeUninit, sync::{Arc, atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering}}};
+
+use crate::models::ring_buffer_statistics::RingBufferStatistics;
+
+const SPINS_BEFORE_YIELD: u32 = 64;
+
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+#[derive(Default)]
+struct ProducerCounters {
+    pushed_count: AtomicU64,
+    full_count: AtomicU64,
+    high_watermark: AtomicUsize
+}
+
+#[derive(Default)]
+struct ConsumerCounters {
+    popped_count: AtomicU64
+}
+
+struct Shared<T> {
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+    producer_counters: CachePadded<ProducerCounters>,
+    consumer_counters: CachePadded<ConsumerCounters>,
+    is_producer_closed: AtomicBool,
+    is_consumer_closed: AtomicBool,
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize
+}
+
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn statistics(&self) -> RingBufferStatistics {
+        RingBufferStatistics {
+            capacity: self.slots.len(),
+            pushed_count: self.producer_counters.0.pushed_count.load(Ordering::Relaxed),
+            popped_count: self.consumer_counters.0.popped_count.load(Ordering::Relaxed),
+            full_count: self.producer_counters.0.full_count.load(Ordering::Relaxed),
+            high_watermark: self.producer_counters.0.high_watermark.load(Ordering::Relaxed)
+        }
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let head = self.head.0.load(Ordering::Relaxed);
+        let tail = self.tail.0.load(Ordering::Relaxed);
+
+        for position in head..tail {
+            unsafe { (*self.slots[position & self.mask].get()).assume_init_drop() };
+        }
+    }
+}
+
+pub struct RingProducer<T> {
+    shared: Arc<Shared<T>>,
+    tail: usize,
+    cached_head: usize,
+    pushed_count: u64,
+    full_count: u64,
+    high_watermark: usize
+}
+
+pub struct RingConsumer<T> {
+    shared: Arc<Shared<T>>,
+    head: usize,
+    cached_tail: usize,
+    popped_count: u64
+}
+
+pub fn spsc_ring_buffer<T>(capacity: usize) -> (RingProducer<T>, RingConsumer<T>) {
+    let slot_count = capacity.max(2).next_power_of_two();
+    let slots = (0..slot_count)
+        .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+
+    let shared = Arc::new(
+        Shared {
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+            producer_counters: CachePadded(ProducerCounters::default()),
+            consumer_counters: CachePadded(ConsumerCounters::default()),
+            is_producer_closed: AtomicBool::new(false),
+            is_consumer_closed: AtomicBool::new(false),
+            slots,
+            mask: slot_count - 1
+        }
+    );
+
+    (
+        RingProducer {
+            shared: shared.clone(),
+            tail: 0,
+            cached_head: 0,
+            pushed_count: 0,
+            full_count: 0,
+            high_watermark: 0
+        },
+        RingConsumer {
+            shared,
+            head: 0,
+            cached_tail: 0,
+            popped_count: 0
+        }
+    )
+}
+
+impl<T> RingProducer<T> {
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+
+    pub fn statistics(&self) -> RingBufferStatistics {
+        self.shared.statistics()
+    }
+
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.free_slots() == 0 {
+            self.record_full();
+            return Err(value);
+        }
+
+        self.write(value);
+        self.publish(1);
+
+        Ok(())
+    }
+
+    pub fn is_consumer_closed(&self) -> bool {
+        self.shared.is_consumer_closed.load(Ordering::Acquire)
+    }
+
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let mut value = value;
+        let mut spins = 0;
+
+        loop {
+            match self.try_push(value) {
+                Ok(()) => return Ok(()),
+                Err(rejected_value) if self.is_consumer_closed() => return Err(rejected_value),
+                Err(rejected_value) => {
+                    value = rejected_value;
+                    backoff(&mut spins);
+                }
+            }
+        }
+    }
+
+    pub fn push_batch<I>(&mut self, values: &mut I) -> usize
+    where
+        I: Iterator<Item = T>
+    {
+        self.cached_head = self.shared.head.0.load(Ordering::Acquire);
+
+        let free_slots = self.free_slots();
+        let mut pushed = 0;
+
+        while pushed < free_slots {
+            let Some(value) = values.next() else {
+                break;
+            };
+
+            self.write(value);
+            pushed += 1;
+        }
+
+        if pushed == 0 && free_slots == 0 {
+            self.record_full();
+        }
+
+        if pushed > 0 {
+            self.publish(pushed);
+        }
+
+        pushed
+    }
+
+    fn free_slots(&mut self) -> usize {
+        let capacity = self.shared.slots.len();
+
+        if self.tail - self.cached_head == capacity {
+            self.cached_head = self.shared.head.0.load(Ordering::Acquire);
+        }
+
+        capacity - (self.tail - self.cached_head)
+    }
+
+    fn write(&mut self, value: T) {
+        let slot = &self.shared.slots[self.tail & self.shared.mask];
+        unsafe { (*slot.get()).write(value) };
+        self.tail += 1;
+    }
+
+    fn publish(&mut self, pushed: usize) {
+        self.shared.tail.0.store(self.tail, Ordering::Release);
+        self.pushed_count += pushed as u64;
+        self.shared.producer_counters.0.pushed_count.store(self.pushed_count, Ordering::Relaxed);
+
+        if self.tail - self.cached_head > self.high_watermark {
+            self.cached_head = self.shared.head.0.load(Ordering::Acquire);
+
+            if self.tail - self.cached_head > self.high_watermark {
+                self.high_watermark = self.tail - self.cached_head;
+                self.shared.producer_counters.0.high_watermark.store(self.high_watermark, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn record_full(&mut self) {
+        self.full_count += 1;
+        self.shared.producer_counters.0.full_count.store(self.full_count, Ordering::Relaxed);
+    }
+}
+
+impl<T> Drop for RingProducer<T> {
+    fn drop(&mut self) {
+        self.shared.is_producer_closed.store(true, Ordering::Release);
+    }
+}
+
+impl<T> RingConsumer<T> {
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+
+    pub fn statistics(&self) -> RingBufferStatistics {
+        self.shared.statistics()
+    }
+
+    pub fn is_producer_closed(&self) -> bool {
+        self.shared.is_producer_closed.load(Ordering::Acquire)
+    }
+
+    pub fn is_finished(&mut self) -> bool {
+        self.is_producer_closed() && self.available() == 0
+    }
+
+    pub fn try_pop(&mut self) -> Option<T> {
+        if self.available() == 0 {
+            return None;
+        }
+
+        let value = self.read();
+        self.release(1);
+
+        Some(value)
+    }
+
+    pub fn pop_batch<F>(&mut self, max_count: usize, mut consume: F) -> usize
+    where
+        F: FnMut(T)
+    {
+        self.cached_tail = self.shared.tail.0.load(Ordering::Acquire);
+
+        let count = self.available().min(max_count);
+        let mut release_guard = ReleaseGuard {
+            consumer: self,
+            popped: 0
+        };
+
+        for _ in 0..count {
+            let value = release_guard.consumer.read();
+            release_guard.popped += 1;
+            consume(value);
+        }
+
+        count
+    }
+
+    fn available(&mut self) -> usize {
+        if self.cached_tail == self.head {
+            self.cached_tail = self.shared.tail.0.load(Ordering::Acquire);
+        }
+
+        self.cached_tail - self.head
+    }
+
+    fn read(&mut self) -> T {
+        let slot = &self.shared.slots[self.head & self.shared.mask];
+        let value = unsafe { (*slot.get()).assume_init_read() };
+        self.head += 1;
+
+        value
+    }
+
+    fn release(&mut self, popped: usize) {
+        self.shared.head.0.store(self.head, Ordering::Release);
+        self.popped_count += popped as u64;
+        self.shared.consumer_counters.0.popped_count.store(self.popped_count, Ordering::Relaxed);
+    }
+}
+
+impl<T> Drop for RingConsumer<T> {
+    fn drop(&mut self) {
+        self.shared.is_consumer_closed.store(true, Ordering::Release);
+    }
+}
+
+struct ReleaseGuard<'a, T> {
+    consumer: &'a mut RingConsumer<T>,
+    popped: usize
+}
+
+impl<T> Drop for ReleaseGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.popped > 0 {
+            self.consumer.release(self.popped);
+        }
+    }
+}
+
+pub fn backoff(spins: &mut u32) {
+    if *spins < SPINS_BEFORE_YIELD {
+        *spins += 1;
+        std::hint::spin_loop();
+    } else {
+        std::thread::yield_now();
+    }
+}
\ No newline at end of file
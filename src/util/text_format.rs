@@ -0,0 +1,191 @@
+use std::fmt::Write;
+
+use crate::{enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, field_type::FieldType, field_value::FieldValue, itch_message::ItchMessage}, models::{message_decoder_registry::MessageDecoderRegistry, messages::unknown_message::UnknownMessage}};
+
+const MESSAGE_TYPE_FIELD_NAME: &str = "message_type";
+const BYTES_FIELD_NAME: &str = "bytes";
+const COMMENT_PREFIX: char = '#';
+
+const FIELD_ALIASES: [(&str, &str); 11] = [
+    ("nanoseconds", "ts"),
+    ("order_id", "oid"),
+    ("order_book_id", "book"),
+    ("order_book_position", "pos"),
+    ("new_order_book_position", "new_pos"),
+    ("quantity", "qty"),
+    ("executed_quantity", "exec_qty"),
+    ("price", "px"),
+    ("trade_price", "trade_px"),
+    ("exchange_order_type", "type"),
+    ("lot_type", "lot")
+];
+
+pub fn alias_for(field_name: &str) -> &str {
+    FIELD_ALIASES
+        .iter()
+        .find(|(name, _)| *name == field_name)
+        .map_or(field_name, |(_, alias)| alias)
+}
+
+pub fn format_message(message: &ItchMessage) -> Result<String, DataFeedError> {
+    let mut line = String::new();
+    line.push(message.message_type());
+
+    if let ItchMessage::Unknown(unknown_message) = message {
+        let _ = write!(line, " {BYTES_FIELD_NAME}=");
+
+        for byte in unknown_message.bytes.iter() {
+            let _ = write!(line, "{byte:02x}");
+        }
+
+        return Ok(line);
+    }
+
+    for (field, value) in message.field_values()? {
+        if field.name == MESSAGE_TYPE_FIELD_NAME {
+            continue;
+        }
+
+        let _ = write!(line, " {}=", alias_for(field.name));
+
+        match value {
+            FieldValue::Alpha(value) if value.is_empty() || value.contains([' ', '"', '=', '\\', COMMENT_PREFIX]) => {
+                line.push('"');
+
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        line.push('\\');
+                    }
+
+                    line.push(c);
+                }
+
+                line.push('"');
+            },
+            value => {
+                let _ = write!(line, "{value}");
+            }
+        }
+    }
+
+    Ok(line)
+}
+
+pub fn parse_message(line: &str) -> Result<Option<ItchMessage>, DataFeedError> {
+    parse_message_with(line, &MessageDecoderRegistry::new())
+}
+
+pub fn parse_message_with(line: &str, message_decoders: &MessageDecoderRegistry) -> Result<Option<ItchMessage>, DataFeedError> {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+        return Ok(None);
+    }
+
+    let tokens = tokenize(line)?;
+    let (message_type_token, pairs) = tokens.split_first().ok_or_else(|| DataFeedErrorKind::ParseError("The line has no message type.".into()))?;
+
+    let mut message_type_chars = message_type_token.chars();
+
+    let message_type = match (message_type_chars.next(), message_type_chars.next()) {
+        (Some(message_type), None) if (message_type as u32) <= 0xFF => message_type as u8,
+        _ => return Err(DataFeedErrorKind::ParseError(format!("Expected a single-character message type but found '{message_type_token}'.")).into())
+    };
+
+    let (fields, byte_count) = match (ItchMessage::fields_for_type(message_type), ItchMessage::byte_count(message_type)) {
+        (Some(fields), Some(byte_count)) => (fields, byte_count),
+        _ => match message_decoders.decoder(message_type) {
+            Some(decoder) => (decoder.fields(), decoder.byte_count()),
+            None => return parse_unknown_message(message_type, pairs).map(Some)
+        }
+    };
+
+    let mut binary = vec![0u8; byte_count];
+
+    for field in fields.iter().filter(|field| field.field_type == FieldType::Alpha) {
+        binary[field.offset..field.offset + field.length].fill(b' ');
+    }
+
+    binary[0] = message_type;
+
+    for pair in pairs {
+        let (key, text) = pair
+            .split_once('=')
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("Expected key=value but found '{pair}'.")))?;
+
+        let field = fields
+            .iter()
+            .find(|field| field.name != MESSAGE_TYPE_FIELD_NAME && (field.name == key || alias_for(field.name) == key))
+            .ok_or_else(|| DataFeedErrorKind::ParseError(format!("The '{}' message has no field named '{key}'.", message_type as char)))?;
+
+        let value = match field.field_type {
+            FieldType::Alpha => Some(FieldValue::Alpha(text.to_string())),
+            FieldType::Numeric => text.parse().ok().map(FieldValue::Numeric),
+            FieldType::Price => text.parse().ok().map(FieldValue::Price)
+        };
+
+        let value = value.ok_or_else(|| DataFeedErrorKind::InvalidFieldValue(field.name, format!("'{text}' is not a valid {:?} value.", field.field_type)))?;
+        field.write(&mut binary, &value)?;
+    }
+
+    message_decoders.decode(&binary).map(Some)
+}
+
+fn parse_unknown_message(message_type: u8, pairs: &[String]) -> Result<ItchMessage, DataFeedError> {
+    let text = match pairs {
+        [pair] => pair.strip_prefix(BYTES_FIELD_NAME).and_then(|text| text.strip_prefix('=')),
+        _ => None
+    };
+
+    let text = text.ok_or(DataFeedErrorKind::UnknownMessageType(message_type as char))?;
+
+    let bytes = (0..text.len())
+        .step_by(2)
+        .map(|index| text.get(index..index + 2).and_then(|digits| u8::from_str_radix(digits, 16).ok()))
+        .collect::<Option<Vec<u8>>>()
+        .filter(|bytes| bytes.first() == Some(&message_type))
+        .ok_or_else(|| DataFeedErrorKind::InvalidFieldValue(BYTES_FIELD_NAME, format!("'{text}' is not the hex encoding of a '{}' message.", message_type as char)))?;
+
+    Ok(ItchMessage::Unknown(UnknownMessage::new(&bytes)?))
+}
+
+pub(crate) fn tokenize(line: &str) -> Result<Vec<String>, DataFeedError> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut chars = line.chars();
+    let mut is_in_token = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                is_in_token = true;
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => token.push(chars.next().ok_or_else(|| DataFeedErrorKind::ParseError("The line ends inside an escape sequence.".into()))?),
+                        Some(c) => token.push(c),
+                        None => return Err(DataFeedErrorKind::ParseError("The line ends inside a quoted value.".into()).into())
+                    }
+                }
+            },
+            COMMENT_PREFIX if !is_in_token => break,
+            c if c.is_whitespace() => {
+                if is_in_token {
+                    tokens.push(std::mem::take(&mut token));
+                    is_in_token = false;
+                }
+            },
+            c => {
+                is_in_token = true;
+                token.push(c);
+            }
+        }
+    }
+
+    if is_in_token {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
\ No newline at end of file
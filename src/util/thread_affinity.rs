@@ -0,0 +1,32 @@
+use crate::enums::data_feed_error::{DataFeedError, DataFeedErrorKind};
+
+#[cfg(target_os = "linux")]
+const CPU_SET_WORD_COUNT: usize = 16;
+
+#[cfg(target_os = "linux")]
+unsafe extern "C" {
+    fn sched_setaffinity(pid: i32, cpu_set_size: usize, cpu_set: *const u64) -> i32;
+}
+
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(core: usize) -> Result<(), DataFeedError> {
+    if core >= CPU_SET_WORD_COUNT * 64 {
+        return Err(DataFeedErrorKind::Other(format!("Core {core} is outside the supported CPU set.")).into());
+    }
+
+    let mut cpu_set = [0u64; CPU_SET_WORD_COUNT];
+    cpu_set[core / 64] |= 1 << (core % 64);
+
+    let result = unsafe { sched_setaffinity(0, std::mem::size_of_val(&cpu_set), cpu_set.as_ptr()) };
+
+    if result != 0 {
+        return Err(DataFeedErrorKind::Other(format!("Failed to pin the current thread to core {core}: {}", std::io::Error::last_os_error())).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(core: usize) -> Result<(), DataFeedError> {
+    Err(DataFeedErrorKind::Other(format!("Pinning threads to core {core} is not supported on this platform.")).into())
+}
\ No newline at end of file
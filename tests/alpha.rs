@@ -0,0 +1,83 @@
+use asx_itch_data_feed_handler::{enums::{data_feed_error::DataFeedErrorKind, itch_message::ItchMessage}, models::{data_types::alpha::{self, Alpha}, messages::order_book_directory_message::offsets}, traits::data_type::TDataType, util::text_format};
+
+fn directory_bytes() -> Vec<u8> {
+    text_format::parse_message("R nanoseconds=5 order_book_id=7 symbol=BHP long_name=\"BHP Group\" isin=AU000000BHP4 financial_product=1 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0")
+        .unwrap()
+        .unwrap()
+        .to_bin()
+        .unwrap()
+}
+
+#[test]
+fn latin_1_bytes_above_ascii_decode_and_round_trip() {
+    let mut binary = directory_bytes();
+    binary[offsets::long_name..offsets::long_name + 9].copy_from_slice(b"Soci\xe9t\xe9 \xa9");
+
+    let ItchMessage::OrderBookDirectory(message) = ItchMessage::from_bin(&binary).unwrap() else {
+        panic!("Expected an order book directory message.");
+    };
+
+    assert_eq!(message.long_name.as_str_trimmed(), "Société ©");
+    assert_eq!(message.long_name, "Société ©");
+    assert_eq!(ItchMessage::OrderBookDirectory(message).to_bin().unwrap(), binary);
+}
+
+#[test]
+fn control_characters_are_rejected_when_decoding() {
+    for byte in [0x00, 0x09, 0x1F, 0x7F, 0x80, 0x9F] {
+        let mut binary = directory_bytes();
+        binary[offsets::long_name + 3] = byte;
+
+        let error = ItchMessage::from_bin(&binary).unwrap_err();
+
+        assert!(matches!(error.kind(), DataFeedErrorKind::NonISO88591Value(c) if *c as u32 == byte as u32), "{byte:#04x}");
+        assert_eq!(error.field_name(), Some("long_name"));
+        assert_eq!(error.context().message_type, Some('R'));
+    }
+
+    assert!((0x20..=0x7E).chain(0xA0..=0xFF).all(alpha::is_printable));
+    assert!((0x00..=0x1F).chain(0x7F..=0x9F).all(|byte| !alpha::is_printable(byte)));
+    assert!(Alpha::new(*b"AB\x07").validate().is_err());
+    assert!(Alpha::new(*b"AB\xff").validate().is_ok());
+}
+
+#[test]
+fn trimmed_strings_drop_only_trailing_spaces() {
+    assert_eq!(Alpha::new(*b"BHP   ").as_str_trimmed(), "BHP");
+    assert_eq!(Alpha::new(*b" B P  ").as_str_trimmed(), " B P");
+    assert_eq!(Alpha::new(*b"      ").as_str_trimmed(), "");
+    assert_eq!(Alpha::new(*b"caf\xe9  ").as_str_trimmed(), "café");
+    assert_eq!(Alpha::new(*b"caf\xe9  ").to_trimmed_string(), "café");
+    assert_eq!(Alpha::new(*b"BHP   ").trimmed_bytes(), b"BHP");
+}
+
+#[test]
+fn alphas_compare_with_strings_ignoring_trailing_padding() {
+    let symbol = Alpha::new(*b"BHP   ");
+
+    assert_eq!(symbol, "BHP");
+    assert_eq!(symbol, "BHP ");
+    assert_eq!("BHP", symbol);
+    assert_eq!(*"BHP", symbol);
+    assert_ne!(symbol, "BH");
+    assert_ne!(symbol, "BHPX");
+    assert_ne!(symbol, " BHP");
+    assert_eq!(Alpha::new(*b"\xe9t\xe9"), "été");
+    assert_ne!(Alpha::new(*b"\xc3\xa9 "), "é");
+}
+
+#[test]
+fn padded_strings_fill_with_spaces_and_reject_overlong_or_unprintable_input() {
+    assert_eq!(Alpha::<6>::from_str_padded("BHP").unwrap().value, *b"BHP   ");
+    assert_eq!(Alpha::<3>::from_str_padded("BHP").unwrap().value, *b"BHP");
+    assert_eq!(Alpha::<4>::from_str_padded("").unwrap().value, *b"    ");
+    assert_eq!(Alpha::<4>::from_str_padded("été").unwrap().value, *b"\xe9t\xe9 ");
+    assert_eq!("AUD".parse::<Alpha<3>>().unwrap(), "AUD");
+
+    assert!(Alpha::<2>::from_str_padded("BHP").is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::ParseError(_))));
+    assert!(Alpha::<3>::from_str_padded("ééé").is_ok());
+    assert!(Alpha::<3>::from_str_padded("éééé").is_err());
+    assert!(Alpha::<4>::from_str_padded("€").is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::NonISO88591Value('€'))));
+    assert!(Alpha::<4>::from_str_padded("a\tb").is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::NonISO88591Value('\t'))));
+    assert!(Alpha::<4>::from_str_padded("a\u{85}").is_err());
+}
\ No newline at end of file
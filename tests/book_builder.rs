@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use asx_itch_data_feed_handler::{enums::itch_message::ItchMessage, models::{book::{book_builder::BookBuilder, book_builder_config::BookBuilderConfig, price_level::PriceLevel}, timestamp::Timestamp}, util::text_format};
+
+fn add_order(order_id: u64, order_book_id: u32, side: char, order_book_position: u32, quantity: u64, price: i32) -> ItchMessage {
+    text_format::parse_message(&format!("A nanoseconds=1 order_id={order_id} order_book_id={order_book_id} side={side} order_book_position={order_book_position} quantity={quantity} price={price} exchange_order_type=4 lot_type=1"))
+        .unwrap()
+        .unwrap()
+}
+
+fn delete_order(order_id: u64, order_book_id: u32, side: char) -> ItchMessage {
+    text_format::parse_message(&format!("D nanoseconds=1 order_id={order_id} order_book_id={order_book_id} side={side}"))
+        .unwrap()
+        .unwrap()
+}
+
+fn execute_order(order_id: u64, order_book_id: u32, side: char, executed_quantity: u64) -> ItchMessage {
+    text_format::parse_message(&format!("E nanoseconds=1 order_id={order_id} order_book_id={order_book_id} side={side} executed_quantity={executed_quantity} match_id=1 owner_participant_id=OWNER counterparty_participant_id=COUNTER"))
+        .unwrap()
+        .unwrap()
+}
+
+fn build(messages: &[ItchMessage]) -> BookBuilder {
+    let mut book_builder = BookBuilder::new(BookBuilderConfig::default());
+
+    for message in messages {
+        book_builder.process_message(message, Timestamp::new(1, 1)).unwrap();
+    }
+
+    book_builder
+}
+
+fn order_ids(book_builder: &BookBuilder, order_book_id: u32, side: char, price: i32) -> Vec<u64> {
+    book_builder.book(order_book_id).unwrap().orders(side, price).map(|order| order.order_id).collect()
+}
+
+#[test]
+fn order_book_ids_differing_only_in_the_high_bit_keep_separate_levels() {
+    let book_builder = build(&[
+        add_order(1, 1, 'B', 1, 100, 1000),
+        add_order(2, 0x8000_0001, 'B', 1, 30, 1000),
+        add_order(3, 1, 'S', 1, 50, 1000)
+    ]);
+
+    let level = |quantity| PriceLevel {
+        price: 1000,
+        quantity,
+        order_count: 1
+    };
+
+    assert_eq!(book_builder.book(1).unwrap().bids().collect::<Vec<_>>(), [level(100)]);
+    assert_eq!(book_builder.book(1).unwrap().asks().collect::<Vec<_>>(), [level(50)]);
+    assert_eq!(book_builder.book(0x8000_0001).unwrap().bids().collect::<Vec<_>>(), [level(30)]);
+    assert_eq!(book_builder.book(0x8000_0001).unwrap().asks().count(), 0);
+}
+
+#[test]
+fn order_book_position_is_the_rank_within_the_book_side() {
+    let book_builder = build(&[
+        add_order(1, 7, 'B', 1, 100, 1010),
+        add_order(2, 7, 'B', 2, 100, 1010),
+        add_order(3, 7, 'B', 3, 100, 1000),
+        add_order(4, 7, 'B', 4, 100, 1000),
+        add_order(5, 7, 'B', 3, 100, 1000),
+        add_order(6, 7, 'S', 1, 100, 1020),
+        add_order(7, 7, 'S', 2, 100, 1030),
+        add_order(8, 7, 'S', 1, 100, 1020)
+    ]);
+
+    assert_eq!(order_ids(&book_builder, 7, 'B', 1010), [1, 2]);
+    assert_eq!(order_ids(&book_builder, 7, 'B', 1000), [5, 3, 4]);
+    assert_eq!(order_ids(&book_builder, 7, 'S', 1020), [8, 6]);
+    assert_eq!(order_ids(&book_builder, 7, 'S', 1030), [7]);
+}
+
+#[test]
+fn order_ids_are_scoped_to_the_order_book_and_side() {
+    let mut book_builder = build(&[
+        add_order(5, 1, 'B', 1, 100, 1000),
+        add_order(5, 1, 'S', 1, 200, 1010),
+        add_order(5, 2, 'B', 1, 300, 1000)
+    ]);
+
+    assert_eq!(book_builder.order_count(), 3);
+    assert_eq!(book_builder.order(1, 'B', 5).unwrap().quantity, 100);
+    assert_eq!(book_builder.order(1, 'S', 5).unwrap().quantity, 200);
+    assert_eq!(book_builder.order(2, 'B', 5).unwrap().quantity, 300);
+    assert!(book_builder.order(2, 'S', 5).is_none());
+
+    book_builder.process_message(&execute_order(5, 1, 'S', 50), Timestamp::new(1, 1)).unwrap();
+    book_builder.process_message(&delete_order(5, 1, 'B'), Timestamp::new(1, 1)).unwrap();
+
+    assert!(book_builder.order(1, 'B', 5).is_none());
+    assert_eq!(book_builder.order(1, 'S', 5).unwrap().quantity, 150);
+    assert_eq!(book_builder.order(2, 'B', 5).unwrap().quantity, 300);
+    assert_eq!(book_builder.book(1).unwrap().bids().count(), 0);
+    assert_eq!(book_builder.book(1).unwrap().best_ask().unwrap().quantity, 150);
+    assert_eq!(book_builder.book(2).unwrap().best_bid().unwrap().quantity, 300);
+}
+
+#[test]
+fn levels_and_queue_positions_match_a_reference_model_under_churn() {
+    let mut book_builder = BookBuilder::new(BookBuilderConfig::default());
+    let mut reference: [BTreeMap<i32, Vec<u64>>; 2] = [BTreeMap::new(), BTreeMap::new()];
+    let mut resting: Vec<(u64, usize, i32)> = Vec::new();
+    let mut seed = 28u64;
+    let mut next_random = |bound: u64| {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) % bound
+    };
+
+    for order_id in 1..=4000 {
+        if !resting.is_empty() && next_random(3) == 0 {
+            let (order_id, side_index, price) = resting.swap_remove(next_random(resting.len() as u64) as usize);
+            let level = reference[side_index].get_mut(&price).unwrap();
+            level.retain(|resting_order_id| *resting_order_id != order_id);
+
+            if level.is_empty() {
+                reference[side_index].remove(&price);
+            }
+
+            book_builder.process_message(&delete_order(order_id, 3, ['B', 'S'][side_index]), Timestamp::new(1, 1)).unwrap();
+            continue;
+        }
+
+        let side_index = next_random(2) as usize;
+        let price = 1000 + next_random(200) as i32;
+        let order_book_position = 1 + next_random(resting.len() as u64 + 2) as u32;
+
+        let orders_at_better_levels: usize = match side_index {
+            0 => reference[0].range(price + 1..).map(|(_, level)| level.len()).sum(),
+            _ => reference[1].range(..price).map(|(_, level)| level.len()).sum()
+        };
+        let level = reference[side_index].entry(price).or_default();
+        let queue_index = (order_book_position as usize).saturating_sub(orders_at_better_levels).max(1) - 1;
+        level.insert(queue_index.min(level.len()), order_id);
+        resting.push((order_id, side_index, price));
+
+        book_builder.process_message(&add_order(order_id, 3, ['B', 'S'][side_index], order_book_position, 10, price), Timestamp::new(1, 1)).unwrap();
+    }
+
+    let book = book_builder.book(3).unwrap();
+    let expected_bids: Vec<i32> = reference[0].keys().rev().copied().collect();
+    let expected_asks: Vec<i32> = reference[1].keys().copied().collect();
+
+    assert_eq!(book.bids().map(|level| level.price).collect::<Vec<_>>(), expected_bids);
+    assert_eq!(book.asks().map(|level| level.price).collect::<Vec<_>>(), expected_asks);
+
+    for (side_index, side) in ['B', 'S'].into_iter().enumerate() {
+        for (price, order_ids_at_level) in &reference[side_index] {
+            assert_eq!(&order_ids(&book_builder, 3, side, *price), order_ids_at_level);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering}};
+
+use asx_itch_data_feed_handler::{enums::itch_message::ItchMessage, models::{book::{book_builder::BookBuilder, book_builder_config::BookBuilderConfig}, data_types::{alpha::Alpha, numeric::Numeric, price::Price}, messages::{add_order_no_participant_id_message::AddOrderNoParticipantIdMessage, order_delete_message::OrderDeleteMessage, order_executed_message::OrderExecutedMessage, order_replace_message::OrderReplaceMessage}, timestamp::Timestamp}};
+
+struct CountingAllocator;
+
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ORDER_BOOK_COUNT: u32 = 8;
+
+fn add_order(order_id: u64, order_book_id: u32, side: char, price: i32) -> ItchMessage {
+    ItchMessage::AddOrderNoParticipantId(
+        AddOrderNoParticipantIdMessage::new(
+            Alpha { value: [b'A'] },
+            Numeric { value: 0 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [side as u8] },
+            Numeric { value: 1 },
+            Numeric { value: 100 },
+            Price { value: price },
+            Numeric { value: 4 },
+            Numeric { value: 1 }
+        ).unwrap()
+    )
+}
+
+fn replace_order(order_id: u64, order_book_id: u32, side: char, price: i32) -> ItchMessage {
+    ItchMessage::OrderReplace(
+        OrderReplaceMessage::new(
+            Alpha { value: [b'U'] },
+            Numeric { value: 0 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [side as u8] },
+            Numeric { value: 1 },
+            Numeric { value: 200 },
+            Price { value: price },
+            Numeric { value: 4 }
+        ).unwrap()
+    )
+}
+
+fn execute_order(order_id: u64, order_book_id: u32, side: char, executed_quantity: u64) -> ItchMessage {
+    ItchMessage::OrderExecuted(
+        OrderExecutedMessage::new(
+            Alpha { value: [b'E'] },
+            Numeric { value: 0 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [side as u8] },
+            Numeric { value: executed_quantity as u128 },
+            Numeric { value: order_id as u128 },
+            Alpha { value: [b' '; 7] },
+            Alpha { value: [b' '; 7] }
+        ).unwrap()
+    )
+}
+
+fn delete_order(order_id: u64, order_book_id: u32, side: char) -> ItchMessage {
+    ItchMessage::OrderDelete(
+        OrderDeleteMessage::new(
+            Alpha { value: [b'D'] },
+            Numeric { value: 0 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [side as u8] }
+        ).unwrap()
+    )
+}
+
+fn order_flow(first_order_id: u64, order_count: u64) -> Vec<ItchMessage> {
+    let mut messages = Vec::new();
+
+    for order_id in first_order_id..first_order_id + order_count {
+        let order_book_id = (order_id % ORDER_BOOK_COUNT as u64) as u32;
+        let side = if order_id % 2 == 0 { 'B' } else { 'S' };
+        let price = if side == 'B' { 1000 - (order_id % 50) as i32 } else { 1100 + (order_id % 50) as i32 };
+
+        messages.push(add_order(order_id, order_book_id, side, price));
+        messages.push(replace_order(order_id, order_book_id, side, price + if side == 'B' { -1 } else { 1 }));
+        messages.push(execute_order(order_id, order_book_id, side, 50));
+
+        if order_id % 3 == 0 {
+            messages.push(execute_order(order_id, order_book_id, side, 150));
+        } else {
+            messages.push(delete_order(order_id, order_book_id, side));
+        }
+    }
+
+    messages
+}
+
+#[test]
+fn steady_state_book_updates_do_not_allocate() {
+    let mut book_builder = BookBuilder::new(
+        BookBuilderConfig {
+            max_orders: 4096,
+            max_order_books: ORDER_BOOK_COUNT as usize,
+            max_price_levels: 2048
+        }
+    );
+
+    let warm_up_messages = order_flow(1, 1000);
+    let messages = order_flow(1_000_000, 10_000);
+    let resting_orders = (0..1000).map(|order_id| add_order(2_000_000 + order_id, (order_id % ORDER_BOOK_COUNT as u64) as u32, 'B', 900)).collect::<Vec<_>>();
+
+    for message in warm_up_messages.iter() {
+        book_builder.process_message(message, Timestamp::default()).unwrap();
+    }
+
+    let allocation_count_before = ALLOCATION_COUNT.load(Ordering::SeqCst);
+
+    for message in resting_orders.iter().chain(messages.iter()) {
+        book_builder.process_message(message, Timestamp::default()).unwrap();
+    }
+
+    let allocation_count_after = ALLOCATION_COUNT.load(Ordering::SeqCst);
+
+    assert_eq!(allocation_count_after - allocation_count_before, 0);
+    assert_eq!(book_builder.order_count(), 1000);
+
+    let book = book_builder.book(0).unwrap();
+    let best_bid = book.best_bid().unwrap();
+    assert_eq!(best_bid.price, 900);
+    assert_eq!(best_bid.quantity, 125 * 100);
+    assert_eq!(book.orders('B', 900).count(), 125);
+    assert!(book.best_ask().is_none());
+}
\ No newline at end of file
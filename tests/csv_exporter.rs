@@ -0,0 +1,40 @@
+use asx_itch_data_feed_handler::{enums::field_type::FieldType, models::{custom_message_decoder::CustomMessageDecoder, export::csv_exporter::CsvExporter, field_layout::FieldLayout, message_decoder_registry::MessageDecoderRegistry}};
+
+static SPREAD_UPDATE_FIELDS: [FieldLayout; 3] = [
+    FieldLayout { name: "message_type", field_type: FieldType::Alpha, offset: 0, length: 1 },
+    FieldLayout { name: "nanoseconds", field_type: FieldType::Numeric, offset: 1, length: 4 },
+    FieldLayout { name: "spread", field_type: FieldType::Price, offset: 5, length: 4 }
+];
+
+#[test]
+fn custom_messages_get_their_own_file_and_unknown_messages_are_skipped() {
+    let mut message_decoders = MessageDecoderRegistry::new();
+    message_decoders.register(CustomMessageDecoder::new(b'V', "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).unwrap();
+
+    let directory = std::env::temp_dir().join(format!("csv-exporter-{}", std::process::id()));
+    let mut csv_exporter = CsvExporter::create_per_message_type(&directory).unwrap();
+    csv_exporter.set_message_decoders(message_decoders);
+
+    csv_exporter.export_bin(&[b'T', 0, 0, 0, 10]).unwrap();
+    csv_exporter.export_bin(&[b'V', 0, 0, 0, 5, 0, 0, 0, 25]).unwrap();
+    csv_exporter.export_bin(b"Q\x01\x02").unwrap();
+
+    assert_eq!(csv_exporter.skipped_message_count(), 1);
+    assert_eq!(csv_exporter.file_name_for_type(b'V').as_deref(), Some("V_spread_update.csv"));
+    assert_eq!(csv_exporter.file_name_for_type(b'T').as_deref(), Some("T_seconds.csv"));
+    assert_eq!(csv_exporter.file_name_for_type(b'Q'), None);
+    assert_eq!(csv_exporter.finish().unwrap(), 2);
+
+    let custom_rows = std::fs::read_to_string(directory.join("V_spread_update.csv")).unwrap();
+    assert_eq!(custom_rows, "timestamp,message_type,nanoseconds,spread\n00:00:10.000000005,V,5,25\n");
+
+    let mut file_names = std::fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    file_names.sort();
+
+    assert_eq!(file_names, ["T_seconds.csv", "V_spread_update.csv"]);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
\ No newline at end of file
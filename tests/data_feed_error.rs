@@ -0,0 +1,42 @@
+use std::io::{Error, ErrorKind};
+
+use asx_itch_data_feed_handler::{enums::data_feed_error::{DataFeedError, DataFeedErrorKind}, models::error_context::ErrorContext};
+
+#[test]
+fn broken_pipes_are_detected_with_and_without_context() {
+    let error = DataFeedError::from(Error::from(ErrorKind::BrokenPipe));
+
+    assert!(error.is_broken_pipe());
+    assert!(error.with_byte_offset(64).with_sequence_number(3).is_broken_pipe());
+    assert!(!DataFeedError::from(Error::from(ErrorKind::UnexpectedEof)).with_byte_offset(64).is_broken_pipe());
+    assert!(!DataFeedError::from(DataFeedErrorKind::MalformedCapture("truncated".into())).is_broken_pipe());
+}
+
+#[test]
+fn context_is_kept_alongside_the_kind() {
+    let error = DataFeedError::from(DataFeedErrorKind::InvalidSideValue('X'))
+        .with_field("side")
+        .with_message_type('A')
+        .with_message_type('F')
+        .with_byte_offset(128);
+
+    assert!(matches!(error.kind(), DataFeedErrorKind::InvalidSideValue('X')));
+    assert_eq!(error.variant_name(), "InvalidSideValue");
+    assert_eq!(error.field_name(), Some("side"));
+    assert_eq!(error.context(), ErrorContext {
+        message_type: Some('A'),
+        field_name: Some("side"),
+        byte_offset: Some(128),
+        sequence_number: None
+    });
+    assert_eq!(error.to_string(), "Message validation error: The specified side value 'X' is not valid. (message type 'A', field 'side', byte offset 128)");
+}
+
+#[test]
+fn errors_without_context_display_only_the_kind() {
+    let error = DataFeedError::from(DataFeedErrorKind::InvalidFieldValue("price", "Too large.".into()));
+
+    assert_eq!(error.context(), ErrorContext::default());
+    assert_eq!(error.field_name(), Some("price"));
+    assert_eq!(error.to_string(), "Field error: The value of 'price' is invalid. Too large.");
+}
\ No newline at end of file
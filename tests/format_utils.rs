@@ -0,0 +1,31 @@
+use asx_itch_data_feed_handler::util::format_utils;
+
+#[test]
+fn prices_format_with_the_instrument_decimals() {
+    assert_eq!(format_utils::format_price(12345, 0), "12345");
+    assert_eq!(format_utils::format_price(12345, 2), "123.45");
+    assert_eq!(format_utils::format_price(-5, 3), "-0.005");
+    assert_eq!(format_utils::format_price(i32::MIN, 19), "-0.0000000002147483648");
+}
+
+#[test]
+fn prices_with_more_decimals_than_u64_can_scale_fall_back_to_the_raw_integer() {
+    assert_eq!(format_utils::format_price(12345, 20), "12345");
+    assert_eq!(format_utils::format_price(-7, u16::MAX), "-7");
+}
+
+#[test]
+fn prices_parse_with_the_instrument_decimals() {
+    assert_eq!(format_utils::parse_price("123.45", 2), Some(12345));
+    assert_eq!(format_utils::parse_price("123.4", 2), Some(12340));
+    assert_eq!(format_utils::parse_price("-.5", 1), Some(-5));
+    assert_eq!(format_utils::parse_price("1.234", 2), None);
+    assert_eq!(format_utils::parse_price("21474836.48", 2), None);
+}
+
+#[test]
+fn parsing_prices_with_unscalable_decimals_is_rejected() {
+    assert_eq!(format_utils::parse_price("1", 19), None);
+    assert_eq!(format_utils::parse_price("0.1", 20), None);
+    assert_eq!(format_utils::parse_price("0", u16::MAX), None);
+}
\ No newline at end of file
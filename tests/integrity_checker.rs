@@ -0,0 +1,144 @@
+use std::sync::{Arc, Mutex};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::DataFeedErrorKind, integrity_anomaly_kind::IntegrityAnomalyKind, integrity_check_mode::IntegrityCheckMode, itch_message::ItchMessage}, models::{book::{book_builder::BookBuilder, book_builder_config::BookBuilderConfig}, integrity::integrity_checker::IntegrityChecker}, util::text_format};
+
+fn message(line: &str) -> ItchMessage {
+    text_format::parse_message(line).unwrap().unwrap()
+}
+
+fn add_order(order_id: u64, side: char, quantity: u64, price: i32) -> ItchMessage {
+    message(&format!("A nanoseconds={order_id} order_id={order_id} order_book_id=7 side={side} order_book_position=1 quantity={quantity} price={price} exchange_order_type=4 lot_type=1"))
+}
+
+fn order_executed(order_id: u64, side: char, executed_quantity: u64) -> ItchMessage {
+    message(&format!("E nanoseconds=10 order_id={order_id} order_book_id=7 side={side} executed_quantity={executed_quantity} match_id=1 owner_participant_id=P1 counterparty_participant_id=P2"))
+}
+
+fn order_book_state(state_name: &str) -> ItchMessage {
+    message(&format!("O nanoseconds=1 order_book_id=7 state_name={state_name}"))
+}
+
+fn checked_handler(integrity_checker: IntegrityChecker) -> DataFeedHandler {
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.enable_book_builder(BookBuilder::new(BookBuilderConfig::default()));
+    data_feed_handler.enable_integrity_checker(integrity_checker);
+    data_feed_handler
+}
+
+fn anomaly_kinds(messages: &[ItchMessage]) -> Vec<IntegrityAnomalyKind> {
+    let mut data_feed_handler = checked_handler(IntegrityChecker::new(IntegrityCheckMode::LogAndContinue));
+
+    for message in messages {
+        data_feed_handler.process_message(message).unwrap();
+    }
+
+    data_feed_handler.integrity_checker().unwrap().recent_anomalies().map(|anomaly| anomaly.kind).collect()
+}
+
+#[test]
+fn unknown_order_id_is_reported() {
+    assert_eq!(anomaly_kinds(&[order_executed(5, 'B', 10)]), [IntegrityAnomalyKind::UnknownOrderId(5)]);
+}
+
+#[test]
+fn duplicate_order_id_is_reported() {
+    assert_eq!(anomaly_kinds(&[add_order(1, 'B', 100, 1000), add_order(1, 'B', 100, 1000)]), [IntegrityAnomalyKind::DuplicateOrderId(1)]);
+}
+
+#[test]
+fn execution_exceeding_remaining_quantity_is_reported() {
+    assert_eq!(
+        anomaly_kinds(&[add_order(1, 'B', 100, 1000), order_executed(1, 'B', 60), order_executed(1, 'B', 60)]),
+        [IntegrityAnomalyKind::ExecutionExceedsRemainingQuantity(1, 60, 40)]
+    );
+}
+
+#[test]
+fn side_mismatch_is_reported() {
+    assert_eq!(anomaly_kinds(&[add_order(1, 'B', 100, 1000), order_executed(1, 'S', 10)]), [IntegrityAnomalyKind::SideMismatch(1, 'B', 'S')]);
+}
+
+#[test]
+fn order_ids_repeated_in_another_book_or_on_the_other_side_are_not_anomalies() {
+    let mut data_feed_handler = checked_handler(IntegrityChecker::new(IntegrityCheckMode::FailFast));
+
+    for line in [
+        "A nanoseconds=1 order_id=1 order_book_id=7 side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1",
+        "A nanoseconds=2 order_id=1 order_book_id=7 side=S order_book_position=1 quantity=20 price=1010 exchange_order_type=4 lot_type=1",
+        "A nanoseconds=3 order_id=1 order_book_id=8 side=B order_book_position=1 quantity=30 price=1000 exchange_order_type=4 lot_type=1",
+        "E nanoseconds=4 order_id=1 order_book_id=7 side=B executed_quantity=60 match_id=1 owner_participant_id=P1 counterparty_participant_id=P2",
+        "E nanoseconds=5 order_id=1 order_book_id=8 side=B executed_quantity=30 match_id=2 owner_participant_id=P1 counterparty_participant_id=P2",
+        "D nanoseconds=6 order_id=1 order_book_id=7 side=S"
+    ] {
+        data_feed_handler.process_message(&message(line)).unwrap();
+    }
+
+    assert_eq!(data_feed_handler.integrity_checker().unwrap().anomaly_count(), 0);
+    assert_eq!(data_feed_handler.book_builder().unwrap().order(7, 'B', 1).unwrap().quantity, 40);
+}
+
+#[test]
+fn anomalies_are_recorded_without_a_callback() {
+    let mut data_feed_handler = checked_handler(IntegrityChecker::new(IntegrityCheckMode::LogAndContinue));
+    data_feed_handler.process_message(&order_executed(3, 'B', 10)).unwrap();
+
+    let integrity_checker = data_feed_handler.integrity_checker().unwrap();
+
+    assert_eq!(integrity_checker.anomaly_count(), 1);
+    assert_eq!(integrity_checker.recent_anomalies().map(|anomaly| anomaly.kind).collect::<Vec<_>>(), [IntegrityAnomalyKind::UnknownOrderId(3)]);
+}
+
+#[test]
+fn crossed_book_is_reported_in_continuous_trading_and_without_a_trading_state() {
+    assert_eq!(
+        anomaly_kinds(&[add_order(1, 'B', 100, 1010), add_order(2, 'S', 100, 1000)]),
+        [IntegrityAnomalyKind::CrossedBook(7, 1010, 1000)]
+    );
+
+    assert_eq!(
+        anomaly_kinds(&[order_book_state("CONTINUOUS"), add_order(1, 'B', 100, 1010), add_order(2, 'S', 100, 1000)]),
+        [IntegrityAnomalyKind::CrossedBook(7, 1010, 1000)]
+    );
+}
+
+#[test]
+fn crossed_book_is_not_reported_during_an_auction() {
+    assert!(anomaly_kinds(&[order_book_state("PRE_OPEN"), add_order(1, 'B', 100, 1010), add_order(2, 'S', 100, 1000)]).is_empty());
+}
+
+#[test]
+fn anomalies_are_emitted_to_the_callback_and_only_the_most_recent_are_kept() {
+    let emitted_order_ids = Arc::new(Mutex::new(Vec::new()));
+    let callback_order_ids = Arc::clone(&emitted_order_ids);
+
+    let mut integrity_checker = IntegrityChecker::with_recent_anomaly_limit(IntegrityCheckMode::LogAndContinue, 2);
+    integrity_checker.set_anomaly_callback(move |anomaly| {
+        if let IntegrityAnomalyKind::UnknownOrderId(order_id) = anomaly.kind {
+            callback_order_ids.lock().unwrap().push(order_id);
+        }
+    });
+
+    let mut data_feed_handler = checked_handler(integrity_checker);
+
+    for order_id in 1..=5 {
+        data_feed_handler.process_message(&order_executed(order_id, 'B', 10)).unwrap();
+    }
+
+    let integrity_checker = data_feed_handler.integrity_checker().unwrap();
+
+    assert_eq!(*emitted_order_ids.lock().unwrap(), [1, 2, 3, 4, 5]);
+    assert_eq!(integrity_checker.anomaly_count(), 5);
+    assert_eq!(
+        integrity_checker.recent_anomalies().map(|anomaly| anomaly.kind).collect::<Vec<_>>(),
+        [IntegrityAnomalyKind::UnknownOrderId(4), IntegrityAnomalyKind::UnknownOrderId(5)]
+    );
+}
+
+#[test]
+fn fail_fast_returns_an_integrity_violation() {
+    let mut data_feed_handler = checked_handler(IntegrityChecker::new(IntegrityCheckMode::FailFast));
+    let error = data_feed_handler.process_message(&order_executed(9, 'B', 10)).unwrap_err();
+
+    assert!(matches!(error.kind(), DataFeedErrorKind::IntegrityViolation(_)));
+    assert_eq!(data_feed_handler.integrity_checker().unwrap().anomaly_count(), 1);
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use std::{io::Cursor, path::PathBuf};
+
+use asx_itch_data_feed_handler::models::journal::{journal_index_entry::JournalIndexEntry, journal_reader::JournalReader, journal_writer::JournalWriter};
+
+fn journal_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{name}-{}.jnl", std::process::id()))
+}
+
+fn write_entries(path: &PathBuf, sequence_numbers: std::ops::Range<u64>) {
+    let mut journal_writer = JournalWriter::open_with_index_interval(path, 2).unwrap();
+
+    for sequence_number in sequence_numbers {
+        journal_writer.write(sequence_number * 10, sequence_number, &[b'T', 0, 0, 0, sequence_number as u8]).unwrap();
+    }
+
+    journal_writer.finish().unwrap();
+}
+
+#[test]
+fn reopening_a_journal_appends_entries_and_rebuilds_the_index() {
+    let path = journal_path("journal-append");
+
+    write_entries(&path, 1..4);
+    write_entries(&path, 4..6);
+
+    let mut journal_reader = JournalReader::open(&path).unwrap();
+
+    let index_entry = |sequence_number: u64| JournalIndexEntry {
+        receive_timestamp: sequence_number * 10,
+        sequence_number,
+        byte_offset: 8 + (sequence_number - 1) * 23
+    };
+
+    assert_eq!(journal_reader.index(), [index_entry(1), index_entry(3), index_entry(5)]);
+
+    let entries = journal_reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(entries.iter().map(|entry| entry.sequence_number).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(entries.iter().map(|entry| entry.byte_offset).collect::<Vec<_>>(), (1..6).map(|sequence_number| index_entry(sequence_number).byte_offset).collect::<Vec<_>>());
+
+    journal_reader.seek_to_sequence(4).unwrap();
+    assert_eq!(journal_reader.next_entry().unwrap().unwrap().bytes, [b'T', 0, 0, 0, 4]);
+
+    std::fs::remove_file(JournalWriter::index_path(&path)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn journal_writer_writes_to_any_writer() {
+    let mut journal_writer = JournalWriter::new(Vec::new(), Vec::new(), 1).unwrap();
+
+    journal_writer.write(100, 7, b"T\0\0\0\x01").unwrap();
+    journal_writer.write(200, 8, b"T\0\0\0\x02").unwrap();
+    assert_eq!(journal_writer.entry_count(), 2);
+
+    let (journal, index) = journal_writer.finish().unwrap();
+    assert_eq!(index.len(), 8 + 2 * 24);
+
+    let entries = JournalReader::new(Cursor::new(journal)).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+
+    assert_eq!(entries.iter().map(|entry| (entry.receive_timestamp, entry.sequence_number)).collect::<Vec<_>>(), [(100, 7), (200, 8)]);
+    assert_eq!(entries[1].bytes, b"T\0\0\0\x02");
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::io::Cursor;
+
+use asx_itch_data_feed_handler::{enums::{data_feed_error::DataFeedErrorKind, itch_message::ItchMessage, json_value::JsonValue}, models::{data_types::{alpha::Alpha, numeric::Numeric, price::Price}, export::{ndjson_reader::NdjsonReader, ndjson_writer::NdjsonWriter}}, traits::json::TJson, util::{json_utils, text_format}};
+
+const MESSAGE_LINES: [&str; 14] = [
+    "A nanoseconds=1 order_id=11 order_book_id=7 side=B order_book_position=2 quantity=100 price=1050 exchange_order_type=4 lot_type=1",
+    "F nanoseconds=2 order_id=18446744073709551615 order_book_id=4294967295 side=S order_book_position=1 quantity=200 price=-2147483648 exchange_order_type=8 lot_type=1 participant_id=PARTY1",
+    "M nanoseconds=3 order_book_id=8 symbol=BHP_SPREAD long_name=\"BHP calendar spread\" isin=AU000000BHP4 financial_product=5 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0 leg_1_symbol=BHPH5 leg_1_side=B leg_1_ratio=1 leg_2_symbol=BHPM5 leg_2_side=C leg_2_ratio=0 leg_3_symbol=\"\" leg_3_side=? leg_3_ratio=0 leg_4_symbol=\"\" leg_4_side=? leg_4_ratio=0",
+    "Z nanoseconds=4 order_book_id=7 bid_quantity=300 ask_quantity=250 equilibrium_price=1040 best_bid_price=1045 best_ask_price=1035 best_bid_quantity=100 best_ask_quantity=90",
+    "R nanoseconds=5 order_book_id=7 symbol=BHP long_name=\"Soci\u{e9}t\u{e9} \\\"BHP\\\"\" isin=AU000000BHP4 financial_product=1 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0",
+    "O nanoseconds=6 order_book_id=7 state_name=CONTINUOUS",
+    "D nanoseconds=7 order_id=11 order_book_id=7 side=B",
+    "E nanoseconds=8 order_id=11 order_book_id=7 side=B executed_quantity=40 match_id=79228162514264337593543950335 owner_participant_id=PARTY1 counterparty_participant_id=PARTY2",
+    "C nanoseconds=9 order_id=11 order_book_id=7 side=B executed_quantity=10 match_id=2 owner_participant_id=PARTY1 counterparty_participant_id=PARTY2 trade_price=1049 occurred_at_cross=N printable=Y",
+    "U nanoseconds=10 order_id=11 order_book_id=7 side=B new_order_book_position=3 quantity=60 price=1051 exchange_order_type=4",
+    "T second=36000",
+    "S nanoseconds=11 event_code=O",
+    "L nanoseconds=12 order_book_id=7 tick_size=5 price_from=0 price_to=100000",
+    "P nanoseconds=13 match_id=3 side=S quantity=70 order_book_id=7 trade_price=-1048 owner_participant_id=PARTY3 counterparty_participant_id=PARTY4 printable=Y occurred_at_cross=Y"
+];
+
+fn messages() -> Vec<ItchMessage> {
+    MESSAGE_LINES
+        .iter()
+        .map(|line| text_format::parse_message(line).unwrap().unwrap())
+        .collect()
+}
+
+fn with_member(json: &JsonValue, key: &str, value: JsonValue) -> JsonValue {
+    let JsonValue::Object(members) = json else {
+        panic!("Expected a JSON object but found {json}.");
+    };
+
+    JsonValue::Object(
+        members
+            .iter()
+            .map(|(member_key, member_value)| (member_key.clone(), if member_key == key { value.clone() } else { member_value.clone() }))
+            .collect()
+    )
+}
+
+#[test]
+fn every_message_type_round_trips_through_json() {
+    let mut message_types = Vec::new();
+
+    for message in messages() {
+        let json = message.to_json().unwrap();
+        let text = json.to_string();
+        let parsed_json = json_utils::parse(&text).unwrap();
+
+        assert_eq!(parsed_json, json, "{text}");
+        assert_eq!(ItchMessage::from_json(&parsed_json).unwrap(), message, "{text}");
+        assert_eq!(ItchMessage::from_json(&parsed_json).unwrap().to_json().unwrap().to_string(), text);
+
+        message_types.push(message.message_type() as u8);
+    }
+
+    message_types.sort();
+
+    let mut expected_message_types = ItchMessage::message_types().collect::<Vec<_>>();
+    expected_message_types.sort();
+
+    assert_eq!(message_types, expected_message_types);
+}
+
+#[test]
+fn every_message_type_round_trips_through_ndjson() {
+    let mut ndjson_writer = NdjsonWriter::new(Vec::new());
+
+    for message in messages() {
+        ndjson_writer.write_bin(&message.to_bin().unwrap()).unwrap();
+    }
+
+    assert_eq!(ndjson_writer.message_count(), MESSAGE_LINES.len() as u64);
+
+    let mut ndjson = ndjson_writer.finish().unwrap();
+    ndjson.extend_from_slice(b"\n  \n");
+
+    let mut ndjson_reader = NdjsonReader::new(Cursor::new(ndjson));
+    let read_messages = ndjson_reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+
+    assert_eq!(read_messages, messages());
+    assert_eq!(ndjson_reader.line_number(), MESSAGE_LINES.len() as u64 + 2);
+}
+
+#[test]
+fn alpha_values_round_trip_through_json_as_trimmed_strings() {
+    for (alpha, text) in [(Alpha::new(*b"BHP   "), "BHP"), (Alpha::new(*b"      "), ""), (Alpha::new(*b"caf\xe9 \""), "caf\u{e9} \"")] {
+        let json = alpha.to_json().unwrap();
+
+        assert_eq!(json, JsonValue::String(text.into()));
+        assert_eq!(Alpha::<6>::from_json(&json_utils::parse(&json.to_string()).unwrap()).unwrap(), alpha);
+    }
+
+    assert!(Alpha::<2>::from_json(&JsonValue::String("BHP".into())).is_err());
+    assert!(Alpha::<4>::from_json(&JsonValue::String("\u{20ac}".into())).is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::NonISO88591Value(_))));
+    assert!(Alpha::<4>::from_json(&JsonValue::Number("1".into())).is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::ParseError(_))));
+}
+
+#[test]
+fn numeric_values_round_trip_through_json_at_their_limits() {
+    for value in [0, 1, u32::MAX as u128] {
+        let numeric = Numeric::<4>::new(value).unwrap();
+        assert_eq!(Numeric::<4>::from_json(&json_utils::parse(&numeric.to_json().unwrap().to_string()).unwrap()).unwrap(), numeric);
+    }
+
+    let widest = Numeric::<12>::new((1u128 << 96) - 1).unwrap();
+    assert_eq!(widest.to_json().unwrap(), JsonValue::Number("79228162514264337593543950335".into()));
+    assert_eq!(Numeric::<12>::from_json(&widest.to_json().unwrap()).unwrap(), widest);
+
+    assert!(Numeric::<2>::from_json(&JsonValue::Number("65536".into())).is_err());
+    assert!(Numeric::<12>::from_json(&JsonValue::Number("79228162514264337593543950336".into())).is_err());
+
+    for json in ["-1", "1.5", "1e3", "\"7\"", "null"] {
+        assert!(Numeric::<8>::from_json(&json_utils::parse(json).unwrap()).is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::ParseError(_))), "{json}");
+    }
+}
+
+#[test]
+fn price_values_round_trip_through_json_at_their_limits() {
+    for value in [i32::MIN, -1, 0, 1050, i32::MAX] {
+        let price = Price::new(value).unwrap();
+        let json = price.to_json().unwrap();
+
+        assert_eq!(json, JsonValue::Number(value.to_string()));
+        assert_eq!(Price::from_json(&json_utils::parse(&json.to_string()).unwrap()).unwrap(), price);
+    }
+
+    for json in ["2147483648", "-2147483649", "10.5", "\"1050\""] {
+        assert!(Price::from_json(&json_utils::parse(json).unwrap()).is_err(), "{json}");
+    }
+}
+
+#[test]
+fn invalid_field_values_are_rejected_when_parsing_json_and_text() {
+    let add_order_json = messages()[0].to_json().unwrap();
+
+    let error = ItchMessage::from_json(&with_member(&add_order_json, "side", JsonValue::String("X".into()))).unwrap_err();
+    assert!(matches!(error.kind(), DataFeedErrorKind::InvalidSideValue('X')));
+    assert_eq!(error.field_name(), Some("side"));
+
+    let error = ItchMessage::from_json(&with_member(&add_order_json, "exchange_order_type", JsonValue::Number("1".into()))).unwrap_err();
+    assert_eq!(error.field_name(), Some("exchange_order_type"));
+
+    let error = ItchMessage::from_json(&with_member(&add_order_json, "lot_type", JsonValue::Number("9".into()))).unwrap_err();
+    assert_eq!(error.field_name(), Some("lot_type"));
+
+    let system_event_json = messages()[11].to_json().unwrap();
+    assert!(ItchMessage::from_json(&with_member(&system_event_json, "event_code", JsonValue::String("Q".into()))).is_err_and(|error| error.field_name() == Some("event_code")));
+
+    let trade_json = messages()[13].to_json().unwrap();
+    assert!(ItchMessage::from_json(&with_member(&trade_json, "printable", JsonValue::String("Q".into()))).is_err_and(|error| error.field_name() == Some("printable")));
+
+    let mut ndjson_reader = NdjsonReader::new(Cursor::new(format!("{}\n", with_member(&add_order_json, "side", JsonValue::String("X".into())))));
+    assert!(ndjson_reader.next_message().is_err());
+
+    assert!(text_format::parse_message("A nanoseconds=1 order_id=11 order_book_id=7 side=X order_book_position=2 quantity=100 price=1050 exchange_order_type=4 lot_type=1").is_err_and(|error| error.field_name() == Some("side")));
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use asx_itch_data_feed_handler::{enums::json_value::JsonValue, util::json_utils};
+
+#[test]
+fn surrogate_pairs_decode_to_a_single_character() {
+    assert_eq!(json_utils::parse(r#""\ud83d\ude00""#).unwrap(), JsonValue::String("\u{1F600}".into()));
+    assert_eq!(json_utils::parse(r#""\udbff\udfff""#).unwrap(), JsonValue::String("\u{10FFFF}".into()));
+}
+
+#[test]
+fn high_surrogates_must_be_followed_by_a_low_surrogate() {
+    assert!(json_utils::parse(r#""\ud83d""#).is_err());
+    assert!(json_utils::parse(r#""\ud83dx""#).is_err());
+    assert!(json_utils::parse(r#""\ud83d\u0041""#).is_err());
+    assert!(json_utils::parse(r#""\ud83d\ud83d""#).is_err());
+    assert!(json_utils::parse(r#""\ud83d\ue000""#).is_err());
+}
+
+#[test]
+fn lone_low_surrogates_are_rejected() {
+    assert!(json_utils::parse(r#""\ude00""#).is_err());
+}
\ No newline at end of file
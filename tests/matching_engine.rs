@@ -0,0 +1,187 @@
+use asx_itch_data_feed_handler::{enums::{itch_message::ItchMessage, order_instruction::OrderInstruction}, exchange_simulator::ExchangeSimulator, models::{book::book_builder::BookBuilder, simulator::{matching_engine::MatchingEngine, random_order_flow::RandomOrderFlow, random_order_flow_config::RandomOrderFlowConfig, resting_order::RestingOrder, simulated_instrument::SimulatedInstrument}, timestamp::Timestamp}};
+
+const ORDER_BOOK_ID: u32 = 7;
+
+struct Engine {
+    matching_engine: MatchingEngine,
+    book_builder: BookBuilder,
+    next_match_id: u64
+}
+
+impl Engine {
+    fn new() -> Self {
+        Engine {
+            matching_engine: MatchingEngine::new(SimulatedInstrument::new(ORDER_BOOK_ID, "BHP")),
+            book_builder: BookBuilder::default(),
+            next_match_id: 1
+        }
+    }
+
+    fn add(&mut self, order_id: u64, side: char, price: i32, quantity: u64) -> Vec<ItchMessage> {
+        let mut messages = Vec::new();
+
+        self.matching_engine.add_order(
+            1,
+            RestingOrder {
+                order_id,
+                side,
+                price,
+                quantity,
+                participant_id: None
+            },
+            &mut self.next_match_id,
+            &mut messages
+        ).unwrap();
+
+        for message in messages.iter() {
+            self.book_builder.process_message(message, Timestamp::new(1, 1)).unwrap();
+        }
+
+        messages
+    }
+}
+
+fn executions(messages: &[ItchMessage]) -> Vec<(u64, u64)> {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            ItchMessage::OrderExecuted(message) => Some((message.order_id.value as u64, message.executed_quantity.value as u64)),
+            _ => None
+        })
+        .collect()
+}
+
+fn added_positions(messages: &[ItchMessage]) -> Vec<(u64, u32)> {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            ItchMessage::AddOrderNoParticipantId(message) => Some((message.order_id.value as u64, message.order_book_position.value as u32)),
+            _ => None
+        })
+        .collect()
+}
+
+fn book_builder_orders(book_builder: &BookBuilder, order_book_id: u32) -> Vec<(char, i32, u64, u64)> {
+    let Some(book) = book_builder.book(order_book_id) else {
+        return Vec::new();
+    };
+
+    let bids = book.bids().flat_map(|level| book.orders('B', level.price));
+    let asks = book.asks().flat_map(|level| book.orders('S', level.price));
+
+    bids
+        .chain(asks)
+        .map(|order| (order.side, order.price, order.order_id, order.quantity))
+        .collect()
+}
+
+fn matching_engine_orders(matching_engine: &MatchingEngine) -> Vec<(char, i32, u64, u64)> {
+    matching_engine
+        .orders()
+        .map(|order| (order.side, order.price, order.order_id, order.quantity))
+        .collect()
+}
+
+#[test]
+fn incoming_orders_match_by_price_then_time() {
+    let mut engine = Engine::new();
+
+    engine.add(1, 'S', 101, 10);
+    engine.add(2, 'S', 100, 10);
+    engine.add(3, 'S', 100, 10);
+
+    let messages = engine.add(4, 'B', 101, 25);
+
+    assert_eq!(executions(&messages), [(2, 10), (3, 10), (1, 5)]);
+    assert!(added_positions(&messages).is_empty());
+    assert_eq!(engine.matching_engine.order(1).unwrap().quantity, 5);
+    assert_eq!(matching_engine_orders(&engine.matching_engine), [('S', 101, 1, 5)]);
+    assert_eq!(book_builder_orders(&engine.book_builder, ORDER_BOOK_ID), [('S', 101, 1, 5)]);
+}
+
+#[test]
+fn partial_fills_execute_the_resting_order_and_record_the_trade() {
+    let mut engine = Engine::new();
+
+    engine.add(1, 'S', 100, 10);
+
+    let messages = engine.add(2, 'B', 100, 4);
+
+    assert_eq!(executions(&messages), [(1, 4)]);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(engine.matching_engine.last_trade_price(), Some(100));
+    assert_eq!(engine.matching_engine.order(1).unwrap().quantity, 6);
+
+    let messages = engine.add(3, 'B', 100, 10);
+
+    assert_eq!(executions(&messages), [(1, 6)]);
+    assert_eq!(added_positions(&messages), [(3, 1)]);
+
+    let book = engine.book_builder.book(ORDER_BOOK_ID).unwrap();
+    let last_trade = book.last_trade().unwrap();
+
+    assert_eq!((last_trade.price, last_trade.quantity), (100, 6));
+    assert_eq!(book_builder_orders(&engine.book_builder, ORDER_BOOK_ID), [('B', 100, 3, 4)]);
+}
+
+#[test]
+fn added_orders_carry_their_rank_within_the_book_side() {
+    let mut engine = Engine::new();
+    let mut positions = Vec::new();
+
+    for (order_id, price) in [(1, 100), (2, 101), (3, 100), (4, 99), (5, 101)] {
+        positions.extend(added_positions(&engine.add(order_id, 'B', price, 10)));
+    }
+
+    positions.extend(added_positions(&engine.add(6, 'S', 103, 10)));
+    positions.extend(added_positions(&engine.add(7, 'S', 102, 10)));
+
+    assert_eq!(positions, [(1, 1), (2, 1), (3, 3), (4, 4), (5, 2), (6, 1), (7, 1)]);
+    assert_eq!((1..=7).map(|order_id| engine.matching_engine.order_book_position(order_id).unwrap()).collect::<Vec<_>>(), [3, 1, 4, 5, 2, 2, 1]);
+    assert_eq!(book_builder_orders(&engine.book_builder, ORDER_BOOK_ID), matching_engine_orders(&engine.matching_engine));
+}
+
+#[test]
+fn book_builder_agrees_with_the_simulated_books() {
+    let order_book_ids = vec![1, 2, 3];
+    let mut simulator = ExchangeSimulator::new();
+    let mut book_builder = BookBuilder::default();
+    let start_time = Timestamp::new(36_000, 0);
+
+    for order_book_id in order_book_ids.iter().copied() {
+        simulator.submit(start_time, OrderInstruction::AddInstrument(SimulatedInstrument::new(order_book_id, "SIM"))).unwrap();
+        simulator.submit(start_time, OrderInstruction::SetTradingState { order_book_id, state_name: "PRE_OPEN".into() }).unwrap();
+    }
+
+    let mut order_flow = RandomOrderFlow::new(RandomOrderFlowConfig { seed: 44, ..RandomOrderFlowConfig::default() }, order_book_ids.clone(), start_time);
+
+    for instruction_index in 0..6_000 {
+        if instruction_index == 1_000 {
+            let time = simulator.current_time().unwrap();
+
+            for order_book_id in order_book_ids.iter().copied() {
+                simulator.submit(time, OrderInstruction::SetTradingState { order_book_id, state_name: "CONTINUOUS".into() }).unwrap();
+            }
+        }
+
+        let (time, instruction) = order_flow.next_instruction(&simulator);
+        simulator.submit(time, instruction).unwrap();
+
+        for (timestamp, message) in simulator.drain_messages() {
+            book_builder.process_message(&message, timestamp).unwrap();
+        }
+    }
+
+    for engine in simulator.engines() {
+        let orders = book_builder_orders(&book_builder, engine.order_book_id());
+
+        assert!(orders.len() > 10);
+        assert_eq!(orders, matching_engine_orders(engine));
+
+        for side in ['B', 'S'] {
+            for (rank, order) in orders.iter().filter(|order| order.0 == side).enumerate() {
+                assert_eq!(engine.order_book_position(order.2), Some(rank as u32 + 1));
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use asx_itch_data_feed_handler::{enums::{data_feed_error::DataFeedErrorKind, itch_message::ItchMessage}, models::{data_types::numeric::Numeric, messages::{add_order_no_participant_id_message::AddOrderNoParticipantIdMessage, order_executed_message::OrderExecutedMessage}}, traits::message::TMessage};
+
+fn add_order_no_participant_id_bytes(order_book_position: u32) -> [u8; 37] {
+    let mut binary = [0u8; 37];
+    binary[0] = b'A';
+    binary[1..5].copy_from_slice(&1_000u32.to_be_bytes());
+    binary[5..13].copy_from_slice(&42u64.to_be_bytes());
+    binary[13..17].copy_from_slice(&7u32.to_be_bytes());
+    binary[17] = b'B';
+    binary[18..22].copy_from_slice(&order_book_position.to_be_bytes());
+    binary[22..30].copy_from_slice(&500u64.to_be_bytes());
+    binary[30..34].copy_from_slice(&1_234i32.to_be_bytes());
+    binary[34..36].copy_from_slice(&4u16.to_be_bytes());
+    binary[36] = 1;
+    binary
+}
+
+#[test]
+fn add_order_no_participant_id_reads_a_four_byte_order_book_position() {
+    let binary = add_order_no_participant_id_bytes(3);
+    let message = AddOrderNoParticipantIdMessage::from_bin(&binary).unwrap();
+
+    assert_eq!(message.order_book_position.value, 3);
+    assert_eq!(message.quantity.value, 500);
+    assert_eq!(message.price.value, 1_234);
+}
+
+#[test]
+fn order_executed_reads_a_twelve_byte_match_id() {
+    let match_id = (1u128 << 90) | 99;
+    let mut binary = [b' '; 52];
+    binary[0] = b'E';
+    binary[1..5].copy_from_slice(&1_000u32.to_be_bytes());
+    binary[5..13].copy_from_slice(&42u64.to_be_bytes());
+    binary[13..17].copy_from_slice(&7u32.to_be_bytes());
+    binary[17] = b'S';
+    binary[18..26].copy_from_slice(&200u64.to_be_bytes());
+    binary[26..38].copy_from_slice(&match_id.to_be_bytes()[4..]);
+
+    let message = OrderExecutedMessage::from_bin(&binary).unwrap();
+
+    assert_eq!(message.executed_quantity.value, 200);
+    assert_eq!(message.match_id.value, match_id);
+}
+
+#[test]
+fn twelve_byte_numeric_rejects_values_wider_than_ninety_six_bits() {
+    assert!(Numeric::<12>::new((1u128 << 96) - 1).is_ok());
+    assert!(Numeric::<12>::new(1u128 << 96).is_err());
+}
+
+#[test]
+fn from_bin_dispatches_on_the_message_type_byte() {
+    let binary = add_order_no_participant_id_bytes(3);
+
+    assert!(matches!(ItchMessage::from_bin(&binary), Ok(ItchMessage::AddOrderNoParticipantId(_))));
+    assert!(ItchMessage::from_bin(&binary[..36]).is_err());
+    assert!(matches!(ItchMessage::from_bin(b"Qxyz"), Ok(ItchMessage::Unknown(_))));
+    assert!(matches!(ItchMessage::from_bin(b""), Err(error) if matches!(error.kind(), DataFeedErrorKind::InvalidMessageSize(1, 0))));
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use asx_itch_data_feed_handler::{enums::field_type::FieldType, global_constants, models::field_layout::FieldLayout, util::message_layout};
+
+const SPANS: [usize; 3] = [1, 4, 1];
+
+const fn layout(numeric_length: usize) -> [FieldLayout; 3] {
+    [
+        FieldLayout { name: "message_type", field_type: FieldType::Alpha, offset: message_layout::field_offset(&SPANS, 0), length: 1 },
+        FieldLayout { name: "nanoseconds", field_type: FieldType::Numeric, offset: message_layout::field_offset(&SPANS, 1), length: numeric_length },
+        FieldLayout { name: "event_code", field_type: FieldType::Alpha, offset: message_layout::field_offset(&SPANS, 2), length: 1 }
+    ]
+}
+
+const _: () = message_layout::verify_layout(&layout(4), &SPANS, global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT);
+
+#[test]
+fn field_offsets_accumulate_the_spans_before_them() {
+    assert_eq!(layout(4).map(|field| field.offset), [0, 1, 5]);
+}
+
+#[test]
+#[should_panic(expected = "The message fields do not add up to the byte count in global_constants.")]
+fn layouts_that_disagree_with_the_byte_count_are_rejected() {
+    message_layout::verify_layout(&layout(4), &SPANS, global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT + 1);
+}
+
+#[test]
+#[should_panic(expected = "A numeric field length must be one of VALID_NUMERIC_SIZES.")]
+fn numeric_fields_of_unsupported_sizes_are_rejected() {
+    message_layout::verify_layout(&layout(3), &SPANS, global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT);
+}
+
+#[test]
+#[should_panic(expected = "A field must have a length between 1 and its span.")]
+fn fields_longer_than_their_span_are_rejected() {
+    message_layout::verify_layout(&layout(8), &SPANS, global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT);
+}
+
+#[test]
+#[should_panic(expected = "A message layout needs one span per field.")]
+fn layouts_need_one_span_per_field() {
+    message_layout::verify_layout(&layout(4), &SPANS[..2], global_constants::SYSTEM_EVENT_MESSAGE_BYTE_COUNT);
+}
\ No newline at end of file
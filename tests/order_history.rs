@@ -0,0 +1,112 @@
+use asx_itch_data_feed_handler::{enums::{itch_message::ItchMessage, order_event_kind::OrderEventKind}, models::{history::order_history::OrderHistory, timestamp::Timestamp}, util::text_format};
+
+fn message(line: &str) -> ItchMessage {
+    text_format::parse_message(line).unwrap().unwrap()
+}
+
+fn add_order(order_id: u64, quantity: u64) -> ItchMessage {
+    message(&format!("A nanoseconds={order_id} order_id={order_id} order_book_id=7 side=B order_book_position=1 quantity={quantity} price=1000 exchange_order_type=4 lot_type=1"))
+}
+
+fn delete_order(order_id: u64) -> ItchMessage {
+    message(&format!("D nanoseconds=900 order_id={order_id} order_book_id=7 side=B"))
+}
+
+fn process(order_history: &mut OrderHistory, messages: &[ItchMessage]) {
+    for (index, message) in messages.iter().enumerate() {
+        order_history.process_message(message, Timestamp::new(1, index as u32));
+    }
+}
+
+#[test]
+fn order_lifecycle_is_recorded_from_add_to_fill() {
+    let mut order_history = OrderHistory::new();
+
+    process(&mut order_history, &[
+        add_order(1, 100),
+        message("U nanoseconds=2 order_id=1 order_book_id=7 side=B new_order_book_position=2 quantity=80 price=1010 exchange_order_type=4"),
+        message("E nanoseconds=3 order_id=1 order_book_id=7 side=B executed_quantity=30 match_id=11 owner_participant_id=P1 counterparty_participant_id=P2"),
+        message("C nanoseconds=4 order_id=1 order_book_id=7 side=B executed_quantity=50 match_id=12 owner_participant_id=P1 counterparty_participant_id=P2 trade_price=1005 occurred_at_cross=N printable=Y"),
+        add_order(2, 10)
+    ]);
+
+    let record = order_history.history(7, 'B', 1).unwrap();
+    assert_eq!((record.order_book_id, record.side, record.remaining_quantity, record.is_completed), (7, 'B', 0, true));
+
+    let events = order_history.events(7, 'B', 1).unwrap();
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[0].kind, OrderEventKind::Added { quantity: 100, price: 1000, .. }));
+    assert!(matches!(events[1].kind, OrderEventKind::Replaced { new_order_book_position: 2, quantity: 80, price: 1010, .. }));
+    assert_eq!(events[2].kind, OrderEventKind::Executed { executed_quantity: 30, match_id: 11, trade_price: None });
+    assert_eq!(events[3].kind, OrderEventKind::Executed { executed_quantity: 50, match_id: 12, trade_price: Some(1005) });
+    assert_eq!(events.iter().map(|event| event.remaining_quantity).collect::<Vec<_>>(), [100, 80, 50, 0]);
+    assert_eq!(events[3].timestamp, Timestamp::new(1, 3));
+
+    assert!(!order_history.history(7, 'B', 2).unwrap().is_completed);
+    assert_eq!(order_history.completed_order_count(), 1);
+    assert!(order_history.history(7, 'B', 3).is_none());
+}
+
+#[test]
+fn bounded_history_evicts_the_oldest_completed_orders_only() {
+    let mut order_history = OrderHistory::with_completed_order_limit(2);
+
+    process(&mut order_history, &[
+        add_order(1, 100),
+        add_order(2, 100),
+        add_order(3, 100),
+        add_order(4, 100),
+        delete_order(1),
+        delete_order(2),
+        delete_order(3)
+    ]);
+
+    assert!(order_history.history(7, 'B', 1).is_none());
+    assert!(order_history.history(7, 'B', 2).is_some());
+    assert!(order_history.history(7, 'B', 3).is_some());
+    assert!(!order_history.history(7, 'B', 4).unwrap().is_completed);
+    assert_eq!(order_history.len(), 3);
+    assert_eq!(order_history.completed_order_count(), 2);
+
+    process(&mut order_history, &[delete_order(4)]);
+
+    assert!(order_history.history(7, 'B', 2).is_none());
+    assert_eq!(order_history.events(7, 'B', 4).unwrap().last().unwrap().kind, OrderEventKind::Deleted);
+    assert_eq!(order_history.len(), 2);
+}
+
+#[test]
+fn orders_sharing_an_id_in_other_books_or_sides_keep_separate_histories() {
+    let mut order_history = OrderHistory::new();
+
+    process(&mut order_history, &[
+        add_order(1, 100),
+        message("A nanoseconds=2 order_id=1 order_book_id=7 side=S order_book_position=1 quantity=20 price=1010 exchange_order_type=4 lot_type=1"),
+        message("A nanoseconds=3 order_id=1 order_book_id=8 side=B order_book_position=1 quantity=30 price=1000 exchange_order_type=4 lot_type=1"),
+        message("D nanoseconds=4 order_id=1 order_book_id=7 side=S")
+    ]);
+
+    assert_eq!(order_history.len(), 3);
+    assert_eq!(order_history.duplicate_add_count(), 0);
+    assert_eq!(order_history.history(7, 'B', 1).unwrap().remaining_quantity, 100);
+    assert!(order_history.history(7, 'S', 1).unwrap().is_completed);
+    assert_eq!(order_history.events(7, 'S', 1).unwrap().len(), 2);
+    assert_eq!(order_history.history(8, 'B', 1).unwrap().remaining_quantity, 30);
+    assert_eq!(order_history.events(8, 'B', 1).unwrap().len(), 1);
+}
+
+#[test]
+fn a_duplicate_add_keeps_the_earlier_history_and_is_counted() {
+    let mut order_history = OrderHistory::with_completed_order_limit(4);
+
+    process(&mut order_history, &[add_order(1, 100), delete_order(1), add_order(1, 40)]);
+
+    let record = order_history.history(7, 'B', 1).unwrap();
+    assert_eq!(order_history.duplicate_add_count(), 1);
+    assert_eq!((record.remaining_quantity, record.is_completed), (40, false));
+    assert_eq!(order_history.completed_order_count(), 0);
+    assert!(matches!(
+        record.events.iter().map(|event| &event.kind).collect::<Vec<_>>()[..],
+        [OrderEventKind::Added { quantity: 100, .. }, OrderEventKind::Deleted, OrderEventKind::Added { quantity: 40, .. }]
+    ));
+}
\ No newline at end of file
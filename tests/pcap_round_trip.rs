@@ -0,0 +1,159 @@
+use std::net::Ipv4Addr;
+
+use asx_itch_data_feed_handler::{enums::itch_message::ItchMessage, models::{capture::{capture_filter::CaptureFilter, pcap_reader::PcapReader, pcap_writer::PcapWriter, pcap_writer_config::PcapWriterConfig}, data_types::{alpha::Alpha, numeric::Numeric, price::Price}, messages::{add_order_no_participant_id_message::AddOrderNoParticipantIdMessage, order_delete_message::OrderDeleteMessage, order_executed_with_price_message::OrderExecutedWithPriceMessage, seconds_message::SecondsMessage, system_event_message::SystemEventMessage, tick_size_table_entry_message::TickSizeTableEntryMessage}}};
+
+fn seconds(second: u32) -> ItchMessage {
+    ItchMessage::Seconds(
+        SecondsMessage::new(
+            Alpha { value: [b'T'] },
+            Numeric { value: second as u128 }
+        ).unwrap()
+    )
+}
+
+fn system_event(nanoseconds: u32, event_code: char) -> ItchMessage {
+    ItchMessage::SystemEvent(
+        SystemEventMessage::new(
+            Alpha { value: [b'S'] },
+            Numeric { value: nanoseconds as u128 },
+            Alpha { value: [event_code as u8] }
+        ).unwrap()
+    )
+}
+
+fn tick_size_table_entry(nanoseconds: u32, order_book_id: u32) -> ItchMessage {
+    ItchMessage::TickSizeTableEntry(
+        TickSizeTableEntryMessage::new(
+            Alpha { value: [b'L'] },
+            Numeric { value: nanoseconds as u128 },
+            Numeric { value: order_book_id as u128 },
+            Numeric { value: 5 },
+            Price { value: 0 },
+            Price { value: -2_000_000_000 }
+        ).unwrap()
+    )
+}
+
+fn add_order(nanoseconds: u32, order_id: u64, order_book_id: u32, price: i32) -> ItchMessage {
+    ItchMessage::AddOrderNoParticipantId(
+        AddOrderNoParticipantIdMessage::new(
+            Alpha { value: [b'A'] },
+            Numeric { value: nanoseconds as u128 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [b'B'] },
+            Numeric { value: 1 },
+            Numeric { value: 100 + order_id as u128 },
+            Price { value: price },
+            Numeric { value: 4 },
+            Numeric { value: 1 }
+        ).unwrap()
+    )
+}
+
+fn execute_order_with_price(nanoseconds: u32, order_id: u64, order_book_id: u32, price: i32) -> ItchMessage {
+    ItchMessage::OrderExecutedWithPrice(
+        OrderExecutedWithPriceMessage::new(
+            Alpha { value: [b'C'] },
+            Numeric { value: nanoseconds as u128 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [b'B'] },
+            Numeric { value: 10 },
+            Numeric { value: (u64::MAX as u128) << 32 | order_id as u128 },
+            Alpha::from_str_padded("PARTY1").unwrap(),
+            Alpha::from_str_padded("PARTY2").unwrap(),
+            Price { value: price },
+            Alpha { value: [b'N'] },
+            Alpha { value: [b'Y'] }
+        ).unwrap()
+    )
+}
+
+fn delete_order(nanoseconds: u32, order_id: u64, order_book_id: u32) -> ItchMessage {
+    ItchMessage::OrderDelete(
+        OrderDeleteMessage::new(
+            Alpha { value: [b'D'] },
+            Numeric { value: nanoseconds as u128 },
+            Numeric { value: order_id as u128 },
+            Numeric { value: order_book_id as u128 },
+            Alpha { value: [b'B'] }
+        ).unwrap()
+    )
+}
+
+fn message_sequence() -> Vec<(u64, ItchMessage)> {
+    let mut messages = vec![(0, seconds(36_000)), (0, system_event(1, 'O')), (0, tick_size_table_entry(2, 7))];
+
+    for order_id in 1..=500u64 {
+        let capture_timestamp = 1_000 * (order_id / 4);
+        let nanoseconds = order_id as u32 * 10;
+
+        messages.push((capture_timestamp, add_order(nanoseconds, order_id, 7, 1_000 + order_id as i32)));
+        messages.push((capture_timestamp, execute_order_with_price(nanoseconds + 1, order_id, 7, -(order_id as i32))));
+        messages.push((capture_timestamp, delete_order(nanoseconds + 2, order_id, 7)));
+    }
+
+    messages.push((1_000_000, system_event(999, 'C')));
+    messages
+}
+
+#[test]
+fn pcap_writer_output_round_trips_through_pcap_reader() {
+    let config = PcapWriterConfig {
+        vlan_id: Some(42),
+        destination_address: Ipv4Addr::new(233, 1, 2, 3),
+        destination_port: 30001,
+        max_payload_byte_count: 512,
+        ..PcapWriterConfig::default()
+    };
+
+    let messages = message_sequence();
+    let mut pcap_writer = PcapWriter::new(Vec::new(), config).unwrap();
+
+    for (capture_timestamp, message) in messages.iter() {
+        pcap_writer.write_message(*capture_timestamp, message).unwrap();
+    }
+
+    pcap_writer.write_heartbeat(2_000_000).unwrap();
+    pcap_writer.write_end_of_session(2_000_000).unwrap();
+
+    let pcap_bytes = pcap_writer.finish().unwrap();
+    let filter = CaptureFilter::new(Some(Ipv4Addr::new(233, 1, 2, 3)), Some(30001));
+    let captured_messages = PcapReader::new(pcap_bytes.as_slice(), filter)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(captured_messages.len(), messages.len());
+
+    for (index, (captured_message, (capture_timestamp, message))) in captured_messages.iter().zip(messages.iter()).enumerate() {
+        assert_eq!(captured_message.capture_timestamp, *capture_timestamp);
+        assert_eq!(captured_message.sequence_number, config.first_sequence_number + index as u64);
+        assert_eq!(captured_message.session, "0000000001");
+        assert_eq!(&captured_message.decode().unwrap(), message);
+    }
+
+    let other_port_filter = CaptureFilter::new(None, Some(30002));
+    assert_eq!(PcapReader::new(pcap_bytes.as_slice(), other_port_filter).unwrap().count(), 0);
+}
+
+#[test]
+fn pcap_writer_rejects_message_counts_that_collide_with_end_of_session() {
+    for max_messages_per_packet in [0, u16::MAX] {
+        let config = PcapWriterConfig {
+            max_messages_per_packet,
+            ..PcapWriterConfig::default()
+        };
+
+        assert!(PcapWriter::new(Vec::new(), config).is_err());
+    }
+
+    let config = PcapWriterConfig {
+        max_messages_per_packet: u16::MAX - 1,
+        max_payload_byte_count: 65000,
+        ..PcapWriterConfig::default()
+    };
+
+    assert!(PcapWriter::new(Vec::new(), config).is_ok());
+}
\ No newline at end of file
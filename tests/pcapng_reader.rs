@@ -0,0 +1,204 @@
+use asx_itch_data_feed_handler::{enums::{capture_format::CaptureFormat, data_feed_error::{DataFeedError, DataFeedErrorKind}, itch_message::ItchMessage}, models::capture::{capture_filter::CaptureFilter, captured_message::CapturedMessage, pcap_reader::PcapReader, pcap_writer::PcapWriter, pcap_writer_config::PcapWriterConfig}, util::{packet_parser, text_format}};
+
+const SECTION_HEADER_BLOCK_TYPE: u32 = 0x0A0D_0D0A;
+const INTERFACE_DESCRIPTION_BLOCK_TYPE: u32 = 1;
+const ENHANCED_PACKET_BLOCK_TYPE: u32 = 6;
+
+struct PcapNgBuilder {
+    is_big_endian: bool,
+    bytes: Vec<u8>
+}
+
+impl PcapNgBuilder {
+    fn new(is_big_endian: bool) -> Self {
+        let mut builder = PcapNgBuilder {
+            is_big_endian,
+            bytes: Vec::new()
+        };
+
+        let mut body = builder.u32(0x1A2B_3C4D);
+        body.extend(builder.u16(1));
+        body.extend(builder.u16(0));
+        body.extend([0xFF; 8]);
+        builder.block(SECTION_HEADER_BLOCK_TYPE, &body);
+
+        builder
+    }
+
+    fn interface(mut self, link_type: u16, timestamp_resolution: Option<u8>) -> Self {
+        let mut body = self.u16(link_type);
+        body.extend(self.u16(0));
+        body.extend(self.u32(0xFFFF));
+
+        if let Some(timestamp_resolution) = timestamp_resolution {
+            body.extend(self.u16(9));
+            body.extend(self.u16(1));
+            body.extend([timestamp_resolution, 0, 0, 0]);
+            body.extend(self.u32(0));
+        }
+
+        self.block(INTERFACE_DESCRIPTION_BLOCK_TYPE, &body);
+        self
+    }
+
+    fn enhanced_packet(mut self, interface_id: u32, timestamp: u64, frame: &[u8]) -> Self {
+        let mut body = self.u32(interface_id);
+        body.extend(self.u32((timestamp >> 32) as u32));
+        body.extend(self.u32(timestamp as u32));
+        body.extend(self.u32(frame.len() as u32));
+        body.extend(self.u32(frame.len() as u32));
+        body.extend(frame);
+
+        self.block(ENHANCED_PACKET_BLOCK_TYPE, &body);
+        self
+    }
+
+    fn block(&mut self, block_type: u32, body: &[u8]) {
+        let padded_length = body.len().div_ceil(4) * 4;
+        let block_length = self.u32(12 + padded_length as u32);
+
+        self.bytes.extend(self.u32(block_type));
+        self.bytes.extend(&block_length);
+        self.bytes.extend(body);
+        self.bytes.resize(self.bytes.len() + padded_length - body.len(), 0);
+        self.bytes.extend(&block_length);
+    }
+
+    fn u16(&self, value: u16) -> Vec<u8> {
+        if self.is_big_endian { value.to_be_bytes().to_vec() } else { value.to_le_bytes().to_vec() }
+    }
+
+    fn u32(&self, value: u32) -> Vec<u8> {
+        if self.is_big_endian { value.to_be_bytes().to_vec() } else { value.to_le_bytes().to_vec() }
+    }
+}
+
+fn message(line: &str) -> ItchMessage {
+    text_format::parse_message(line).unwrap().unwrap()
+}
+
+fn frame(config: PcapWriterConfig, message: &ItchMessage) -> Vec<u8> {
+    let mut pcap_writer = PcapWriter::new(Vec::new(), config).unwrap();
+    pcap_writer.write_message(0, message).unwrap();
+    pcap_writer.flush_packet().unwrap();
+
+    let pcap_bytes = pcap_writer.finish().unwrap();
+    let captured_length = u32::from_le_bytes(pcap_bytes[32..36].try_into().unwrap()) as usize;
+
+    pcap_bytes[40..40 + captured_length].to_vec()
+}
+
+fn read_all(bytes: &[u8]) -> Result<Vec<CapturedMessage>, DataFeedError> {
+    PcapReader::new(bytes, CaptureFilter::default())?.collect()
+}
+
+fn is_malformed(result: Result<Vec<CapturedMessage>, DataFeedError>) -> bool {
+    matches!(result, Err(error) if matches!(error.kind(), DataFeedErrorKind::MalformedCapture(_)))
+}
+
+#[test]
+fn enhanced_packets_are_read_with_their_interface_link_type_and_timestamp_resolution() {
+    let system_event = message("S nanoseconds=1 event_code=O");
+    let seconds = message("T second=36000");
+    let config = PcapWriterConfig::default();
+
+    let bytes = PcapNgBuilder::new(false)
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, None)
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, Some(9))
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, Some(0x80 | 10))
+        .enhanced_packet(0, 1_500_000, &frame(config, &system_event))
+        .enhanced_packet(1, 123, &frame(PcapWriterConfig { first_sequence_number: 2, ..config }, &seconds))
+        .enhanced_packet(2, 3 << 10, &frame(PcapWriterConfig { first_sequence_number: 3, ..config }, &seconds))
+        .bytes;
+
+    let mut pcap_reader = PcapReader::new(bytes.as_slice(), CaptureFilter::default()).unwrap();
+    assert_eq!(pcap_reader.format(), CaptureFormat::PcapNg);
+
+    let captured_messages = pcap_reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+
+    assert_eq!(pcap_reader.packet_count(), 3);
+    assert_eq!(captured_messages.iter().map(|message| message.capture_timestamp).collect::<Vec<_>>(), [1_500_000_000, 123, 3_000_000_000]);
+    assert_eq!(captured_messages.iter().map(|message| message.sequence_number).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(captured_messages[0].session, "0000000001");
+    assert_eq!(captured_messages[0].decode().unwrap(), system_event);
+    assert_eq!(captured_messages[2].decode().unwrap(), seconds);
+}
+
+#[test]
+fn big_endian_sections_are_read() {
+    let system_event = message("S nanoseconds=1 event_code=C");
+
+    let bytes = PcapNgBuilder::new(true)
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, Some(9))
+        .enhanced_packet(0, 7, &frame(PcapWriterConfig::default(), &system_event))
+        .bytes;
+
+    let captured_messages = read_all(&bytes).unwrap();
+
+    assert_eq!(captured_messages.len(), 1);
+    assert_eq!(captured_messages[0].capture_timestamp, 7);
+    assert_eq!(captured_messages[0].decode().unwrap(), system_event);
+}
+
+#[test]
+fn vlan_tagged_frames_are_unwrapped() {
+    let system_event = message("S nanoseconds=1 event_code=O");
+    let config = PcapWriterConfig {
+        vlan_id: Some(100),
+        ..PcapWriterConfig::default()
+    };
+
+    let bytes = PcapNgBuilder::new(false)
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, None)
+        .enhanced_packet(0, 1, &frame(config, &system_event))
+        .bytes;
+
+    let captured_messages = read_all(&bytes).unwrap();
+
+    assert_eq!(captured_messages.len(), 1);
+    assert_eq!(captured_messages[0].decode().unwrap(), system_event);
+}
+
+#[test]
+fn section_headers_shorter_than_their_fixed_fields_are_rejected() {
+    let short_section_header = [0x0A, 0x0D, 0x0D, 0x0A, 0x0C, 0x00, 0x00, 0x00, 0x4D, 0x3C, 0x2B, 0x1A];
+    assert!(matches!(PcapReader::new(short_section_header.as_slice(), CaptureFilter::default()), Err(error) if matches!(error.kind(), DataFeedErrorKind::MalformedCapture(_))));
+
+    let mut sixteen_byte_section_header = short_section_header.to_vec();
+    sixteen_byte_section_header[4] = 0x10;
+    sixteen_byte_section_header.extend([0x10, 0x00, 0x00, 0x00]);
+    assert!(matches!(PcapReader::new(sixteen_byte_section_header.as_slice(), CaptureFilter::default()), Err(error) if matches!(error.kind(), DataFeedErrorKind::MalformedCapture(_))));
+}
+
+#[test]
+fn short_and_truncated_blocks_are_rejected() {
+    let section = PcapNgBuilder::new(false).bytes;
+
+    let mut block_shorter_than_its_header = section.clone();
+    block_shorter_than_its_header.extend([6, 0, 0, 0, 8, 0, 0, 0]);
+    assert!(is_malformed(read_all(&block_shorter_than_its_header)));
+
+    let mut truncated_block = PcapNgBuilder::new(false)
+        .interface(packet_parser::LINK_TYPE_ETHERNET as u16, None)
+        .enhanced_packet(0, 1, &[0; 64])
+        .bytes;
+    truncated_block.truncate(truncated_block.len() - 10);
+    assert!(read_all(&truncated_block).is_err());
+
+    let mut short_interface = PcapNgBuilder::new(false);
+    short_interface.block(INTERFACE_DESCRIPTION_BLOCK_TYPE, &[1, 0, 0, 0]);
+    assert!(is_malformed(read_all(&short_interface.bytes)));
+
+    let mut short_enhanced_packet = PcapNgBuilder::new(false).interface(packet_parser::LINK_TYPE_ETHERNET as u16, None);
+    short_enhanced_packet.block(ENHANCED_PACKET_BLOCK_TYPE, &[0; 16]);
+    assert!(is_malformed(read_all(&short_enhanced_packet.bytes)));
+
+    let mut overlong_captured_length = PcapNgBuilder::new(false).interface(packet_parser::LINK_TYPE_ETHERNET as u16, None);
+    let mut body = vec![0; 24];
+    body[12] = 200;
+    overlong_captured_length.block(ENHANCED_PACKET_BLOCK_TYPE, &body);
+    assert!(is_malformed(read_all(&overlong_captured_length.bytes)));
+
+    let undeclared_interface = PcapNgBuilder::new(false).enhanced_packet(0, 1, &[0; 8]).bytes;
+    assert!(is_malformed(read_all(&undeclared_interface)));
+}
\ No newline at end of file
@@ -0,0 +1,221 @@
+use std::{sync::{Arc, Mutex}, time::Duration};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::{DataFeedError, DataFeedErrorKind}, replay_mode::ReplayMode}, models::{messages::order_delete_message, replay_config::ReplayConfig, timestamp::Timestamp, validation_policy::ValidationPolicy}, replay_engine::ReplayEngine, traits::replay_clock::ReplayClock, util::text_format};
+
+#[derive(Clone, Default)]
+struct ManualClock {
+    elapsed: Arc<Mutex<Duration>>,
+    sleeps: Arc<Mutex<Vec<Duration>>>,
+    work_per_reading: Duration
+}
+
+impl ManualClock {
+    fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.lock().unwrap().clone()
+    }
+}
+
+impl ReplayClock for ManualClock {
+    fn elapsed(&mut self) -> Duration {
+        let mut elapsed = self.elapsed.lock().unwrap();
+        *elapsed += self.work_per_reading;
+        *elapsed
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+        self.sleeps.lock().unwrap().push(duration);
+    }
+}
+
+fn message_bytes(line: &str) -> Vec<u8> {
+    text_format::parse_message(line).unwrap().unwrap().to_bin().unwrap()
+}
+
+fn source() -> Vec<Result<Vec<u8>, DataFeedError>> {
+    let mut invalid_delete = message_bytes("D nanoseconds=2 order_id=1 order_book_id=7 side=B");
+    invalid_delete[order_delete_message::offsets::side] = b'X';
+
+    vec![
+        Ok(message_bytes("T second=36000")),
+        Ok(message_bytes("A nanoseconds=1 order_id=1 order_book_id=7 side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1")),
+        Ok(invalid_delete),
+        Ok(vec![b'Q', 1, 2, 3]),
+        Ok(vec![b'A', 0]),
+        Ok(message_bytes("D nanoseconds=3 order_id=1 order_book_id=7 side=B"))
+    ]
+}
+
+fn timed_source() -> Vec<Result<Vec<u8>, DataFeedError>> {
+    vec![
+        Ok(message_bytes("T second=36000")),
+        Ok(message_bytes("A nanoseconds=1000000 order_id=1 order_book_id=7 side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1")),
+        Ok(message_bytes("A nanoseconds=3000000 order_id=2 order_book_id=7 side=B order_book_position=2 quantity=100 price=1000 exchange_order_type=4 lot_type=1")),
+        Ok(message_bytes("D nanoseconds=7000000 order_id=1 order_book_id=7 side=B")),
+        Ok(message_bytes("D nanoseconds=7000000 order_id=2 order_book_id=7 side=B"))
+    ]
+}
+
+fn replay_config(mode: ReplayMode) -> ReplayConfig {
+    ReplayConfig {
+        mode,
+        ..ReplayConfig::default()
+    }
+}
+
+fn as_fast_as_possible() -> ReplayConfig {
+    ReplayConfig {
+        mode: ReplayMode::AsFastAsPossible,
+        ..ReplayConfig::default()
+    }
+}
+
+#[test]
+fn decode_errors_are_reported_without_ending_the_replay() {
+    let reported_errors = Arc::new(Mutex::new(Vec::new()));
+    let callback_errors = Arc::clone(&reported_errors);
+
+    let mut replay_engine = ReplayEngine::new(source().into_iter(), DataFeedHandler::new(), as_fast_as_possible());
+    replay_engine.set_decode_error_callback(move |error| callback_errors.lock().unwrap().push(error.kind().variant_name()));
+    replay_engine.run().unwrap();
+
+    assert_eq!(*reported_errors.lock().unwrap(), ["InvalidSideValue", "InvalidMessageSize"]);
+    assert_eq!(replay_engine.decode_error_count(), 2);
+    assert_eq!(replay_engine.replayed_message_count(), 4);
+    assert_eq!(replay_engine.handler().unknown_message_counts().get(&'Q'), Some(&1));
+    assert!(replay_engine.is_finished());
+}
+
+#[test]
+fn replay_decodes_with_the_handlers_validation_policy() {
+    let mut handler = DataFeedHandler::new();
+    handler.set_validation_policy(ValidationPolicy::lenient());
+
+    let mut replay_engine = ReplayEngine::new(source().into_iter(), handler, as_fast_as_possible());
+    replay_engine.set_decode_error_callback(|_| {});
+
+    let mut message_types = String::new();
+
+    while let Some(message) = replay_engine.step().unwrap() {
+        message_types.push(message.message_type());
+    }
+
+    assert_eq!(message_types, "TADQD");
+    assert_eq!(replay_engine.decode_error_count(), 1);
+    assert_eq!(replay_engine.handler().validation_warning_counts().get("side"), Some(&1));
+}
+
+#[test]
+fn source_errors_still_end_the_replay() {
+    let source = vec![Ok(message_bytes("T second=1")), Err(DataFeedErrorKind::MalformedCapture("truncated".into()).into())];
+    let mut replay_engine = ReplayEngine::new(source.into_iter(), DataFeedHandler::new(), as_fast_as_possible());
+
+    assert!(replay_engine.step().unwrap().is_some());
+    assert!(matches!(replay_engine.step(), Err(error) if matches!(error.kind(), DataFeedErrorKind::MalformedCapture(_))));
+}
+
+#[test]
+fn real_time_replays_sleep_for_the_gaps_between_message_times() {
+    let clock = ManualClock::default();
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), replay_config(ReplayMode::RealTime));
+    replay_engine.set_clock(clock.clone());
+    replay_engine.run().unwrap();
+
+    assert_eq!(clock.sleeps(), [Duration::from_millis(1), Duration::from_millis(2), Duration::from_millis(4)]);
+    assert_eq!(replay_engine.replayed_message_count(), 5);
+}
+
+#[test]
+fn accelerated_replays_divide_the_gaps_by_the_multiplier() {
+    let clock = ManualClock::default();
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), replay_config(ReplayMode::Accelerated(4.0)));
+    replay_engine.set_clock(clock.clone());
+    replay_engine.run().unwrap();
+
+    assert_eq!(clock.sleeps(), [Duration::from_micros(250), Duration::from_micros(500), Duration::from_millis(1)]);
+
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), replay_config(ReplayMode::Accelerated(0.0)));
+    replay_engine.set_clock(ManualClock::default());
+    assert!(replay_engine.run().is_err());
+}
+
+#[test]
+fn paced_replays_only_sleep_for_the_time_not_already_spent_processing() {
+    let clock = ManualClock {
+        work_per_reading: Duration::from_micros(1500),
+        ..ManualClock::default()
+    };
+
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), replay_config(ReplayMode::RealTime));
+    replay_engine.set_clock(clock.clone());
+    replay_engine.run().unwrap();
+
+    assert_eq!(clock.sleeps(), [Duration::from_micros(2500)]);
+}
+
+#[test]
+fn step_mode_delivers_one_message_per_step_without_pacing() {
+    let clock = ManualClock::default();
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), replay_config(ReplayMode::Step));
+    replay_engine.set_clock(clock.clone());
+
+    assert!(replay_engine.run().is_err());
+
+    let first_message = replay_engine.step().unwrap().unwrap();
+    assert_eq!(first_message.message_type(), 'T');
+    assert_eq!(replay_engine.replayed_message_count(), 1);
+    assert_eq!(replay_engine.current_time(), Some(Timestamp::new(36000, 0)));
+
+    let second_message = replay_engine.step().unwrap().unwrap();
+    assert_eq!(second_message.message_type(), 'A');
+    assert_eq!(replay_engine.replayed_message_count(), 2);
+    assert_eq!(replay_engine.current_time(), Some(Timestamp::new(36000, 1_000_000)));
+    assert!(!replay_engine.is_finished());
+
+    while replay_engine.step().unwrap().is_some() {}
+
+    assert_eq!(replay_engine.replayed_message_count(), 5);
+    assert!(replay_engine.is_finished());
+    assert!(clock.sleeps().is_empty());
+}
+
+#[test]
+fn time_of_day_window_applies_earlier_messages_silently_and_stops_after_the_stop_time() {
+    let clock = ManualClock::default();
+    let config = ReplayConfig {
+        mode: ReplayMode::RealTime,
+        start_time: Some(Timestamp::new(36000, 2_000_000)),
+        stop_time: Some(Timestamp::new(36000, 5_000_000))
+    };
+
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), config);
+    replay_engine.set_clock(clock.clone());
+
+    let message = replay_engine.step().unwrap().unwrap();
+    assert_eq!(message.message_type(), 'A');
+    assert_eq!(message.nanoseconds(), Some(3_000_000));
+    assert_eq!(replay_engine.replayed_message_count(), 1);
+    assert_eq!(replay_engine.handler().session_statistics().message_counts_by_type.get(&'A'), Some(&2));
+    assert_eq!(replay_engine.handler().session_statistics().message_counts_by_type.get(&'T'), Some(&1));
+
+    assert!(replay_engine.step().unwrap().is_none());
+    assert!(replay_engine.is_finished());
+    assert_eq!(replay_engine.current_time(), Some(Timestamp::new(36000, 3_000_000)));
+    assert_eq!(replay_engine.handler().session_statistics().message_counts_by_type.get(&'D'), None);
+
+    let mut replay_engine = ReplayEngine::new(timed_source().into_iter(), DataFeedHandler::new(), config);
+    replay_engine.set_clock(clock.clone());
+    replay_engine.run().unwrap();
+
+    assert_eq!(replay_engine.replayed_message_count(), 1);
+    assert!(clock.sleeps().is_empty());
+}
+
+#[test]
+fn decode_errors_are_counted_when_no_callback_is_set() {
+    let mut replay_engine = ReplayEngine::new(source().into_iter(), DataFeedHandler::new(), as_fast_as_possible());
+    replay_engine.run().unwrap();
+
+    assert_eq!(replay_engine.decode_error_count(), 2);
+    assert_eq!(replay_engine.replayed_message_count(), 4);
+}
\ No newline at end of file
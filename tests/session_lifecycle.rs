@@ -0,0 +1,156 @@
+use std::{io::{BufWriter, Write}, sync::{Arc, Mutex}};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::DataFeedError, itch_message::ItchMessage, session_state::SessionState}, models::{book::book_builder::BookBuilder, export::text_writer::TextWriter, timestamp::Timestamp}, traits::message_sink::TMessageSink, util::text_format};
+
+#[derive(Clone, Default)]
+struct SharedOutput {
+    bytes: Arc<Mutex<Vec<u8>>>,
+    flush_count: Arc<Mutex<u32>>
+}
+
+impl SharedOutput {
+    fn text(&self) -> String {
+        String::from_utf8(self.bytes.lock().unwrap().clone()).unwrap()
+    }
+
+    fn flush_count(&self) -> u32 {
+        *self.flush_count.lock().unwrap()
+    }
+}
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.bytes.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        *self.flush_count.lock().unwrap() += 1;
+        Ok(())
+    }
+}
+
+struct FailingOutput;
+
+impl TMessageSink for FailingOutput {
+    fn write_message(&mut self, _message: &ItchMessage) -> Result<(), DataFeedError> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), DataFeedError> {
+        Err(std::io::Error::other("disk full").into())
+    }
+}
+
+fn process_lines(data_feed_handler: &mut DataFeedHandler, lines: &[&str]) {
+    for line in lines {
+        data_feed_handler.process_message(&text_format::parse_message(line).unwrap().unwrap()).unwrap();
+    }
+}
+
+const FIRST_DAY: [&str; 8] = [
+    "T second=36000",
+    "S nanoseconds=1 event_code=O",
+    "R nanoseconds=5 order_book_id=7 symbol=BHP long_name=\"BHP Group\" isin=AU000000BHP4 financial_product=1 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0",
+    "L nanoseconds=6 order_book_id=7 tick_size=5 price_from=0 price_to=100000",
+    "A nanoseconds=10 order_id=1 order_book_id=7 side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1",
+    "A nanoseconds=11 order_id=2 order_book_id=7 side=S order_book_position=1 quantity=100 price=1010 exchange_order_type=4 lot_type=1",
+    "E nanoseconds=12 order_id=1 order_book_id=7 side=B executed_quantity=40 match_id=1 owner_participant_id=P1 counterparty_participant_id=P2",
+    "S nanoseconds=20 event_code=C"
+];
+
+const SECOND_DAY: [&str; 5] = [
+    "T second=122400",
+    "S nanoseconds=1 event_code=O",
+    "R nanoseconds=5 order_book_id=9 symbol=CBA long_name=\"CBA\" isin=AU000000CBA7 financial_product=1 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0",
+    "A nanoseconds=10 order_id=1 order_book_id=9 side=B order_book_position=1 quantity=50 price=2000 exchange_order_type=4 lot_type=1",
+    "S nanoseconds=20 event_code=C"
+];
+
+#[test]
+fn two_sessions_produce_clean_per_day_state_summaries_and_flushed_outputs() {
+    let output = SharedOutput::default();
+    let end_of_day_outputs = Arc::new(Mutex::new(Vec::new()));
+    let callback_outputs = Arc::clone(&end_of_day_outputs);
+    let callback_output = output.clone();
+
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.enable_book_builder(BookBuilder::default());
+    data_feed_handler.add_output(TextWriter::new(BufWriter::new(output.clone())));
+    data_feed_handler.set_end_of_day_callback(move |_| callback_outputs.lock().unwrap().push(callback_output.text()));
+
+    process_lines(&mut data_feed_handler, &FIRST_DAY[..7]);
+
+    assert_eq!(data_feed_handler.session_state(), SessionState::Open);
+    assert_eq!(data_feed_handler.instrument_directory().len(), 1);
+    assert_eq!(data_feed_handler.tick_size_tables().len(), 1);
+    assert_eq!(data_feed_handler.book_builder().unwrap().order_count(), 2);
+    assert!(output.text().is_empty());
+
+    process_lines(&mut data_feed_handler, &FIRST_DAY[7..]);
+
+    assert_eq!(data_feed_handler.session_state(), SessionState::Closed);
+    assert_eq!(output.text().lines().count(), 8);
+    assert_eq!(output.flush_count(), 1);
+    assert_eq!(end_of_day_outputs.lock().unwrap()[0], output.text());
+
+    process_lines(&mut data_feed_handler, &SECOND_DAY[..2]);
+
+    assert_eq!(data_feed_handler.instrument_directory().len(), 0);
+    assert!(data_feed_handler.tick_size_tables().is_empty());
+    assert_eq!(data_feed_handler.book_builder().unwrap().order_count(), 0);
+    assert_eq!(data_feed_handler.book_builder().unwrap().book_count(), 0);
+
+    process_lines(&mut data_feed_handler, &SECOND_DAY[2..]);
+
+    assert_eq!(output.flush_count(), 2);
+    assert_eq!(output.text().lines().count(), 13);
+    assert_eq!(end_of_day_outputs.lock().unwrap().len(), 2);
+
+    let session_summaries = data_feed_handler.session_summaries();
+    assert_eq!(session_summaries.len(), 2);
+
+    assert_eq!(session_summaries[0].session_start, Some(Timestamp::new(36000, 1)));
+    assert_eq!(session_summaries[0].session_end, Timestamp::new(36000, 20));
+    assert_eq!(session_summaries[0].statistics.message_counts_by_type.get(&'A'), Some(&2));
+    assert_eq!(session_summaries[0].statistics.execution_count, 1);
+    assert_eq!(session_summaries[0].statistics.executed_quantity, 40);
+    assert_eq!((session_summaries[0].instrument_count, session_summaries[0].order_book_count, session_summaries[0].resting_order_count), (1, 1, 2));
+
+    assert_eq!(session_summaries[1].session_start, Some(Timestamp::new(122400, 1)));
+    assert_eq!(session_summaries[1].session_end, Timestamp::new(122400, 20));
+    assert_eq!(session_summaries[1].statistics.message_counts_by_type.get(&'A'), Some(&1));
+    assert_eq!(session_summaries[1].statistics.message_counts_by_type.get(&'L'), None);
+    assert_eq!(session_summaries[1].statistics.execution_count, 0);
+    assert_eq!((session_summaries[1].instrument_count, session_summaries[1].order_book_count, session_summaries[1].resting_order_count), (1, 1, 1));
+}
+
+#[test]
+fn a_missing_end_of_messages_is_closed_by_the_next_start_of_messages() {
+    let output = SharedOutput::default();
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.add_output(TextWriter::new(BufWriter::new(output.clone())));
+
+    process_lines(&mut data_feed_handler, &FIRST_DAY[..7]);
+    process_lines(&mut data_feed_handler, &SECOND_DAY[..2]);
+
+    assert_eq!(output.flush_count(), 1);
+    assert_eq!(data_feed_handler.session_summaries().len(), 1);
+    assert_eq!(data_feed_handler.session_summaries()[0].session_end, Timestamp::new(122400, 1));
+    assert_eq!(data_feed_handler.session_state(), SessionState::Open);
+}
+
+#[test]
+fn output_flush_failures_are_returned_at_the_end_of_the_day() {
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.add_output(FailingOutput);
+
+    process_lines(&mut data_feed_handler, &FIRST_DAY[..7]);
+
+    let end_of_messages = text_format::parse_message(FIRST_DAY[7]).unwrap().unwrap();
+    assert!(data_feed_handler.process_message(&end_of_messages).is_err());
+    assert!(data_feed_handler.session_summaries().is_empty());
+
+    assert_eq!(data_feed_handler.take_outputs().len(), 1);
+    assert!(data_feed_handler.flush_outputs().is_ok());
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{itch_message::ItchMessage, order_event_kind::OrderEventKind}, models::{history::order_history::OrderHistory, messages::order_delete_message, validation_policy::ValidationPolicy}, sharded_data_feed_handler::ShardedDataFeedHandler, util::text_format};
+
+const SHARD_COUNT: usize = 3;
+const ORDER_BOOK_COUNT: u32 = 7;
+const REPLACE_COUNT: u32 = 50;
+
+fn message(line: &str) -> ItchMessage {
+    text_format::parse_message(line).unwrap().unwrap()
+}
+
+fn history_handler(_: usize) -> DataFeedHandler {
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.enable_order_history(OrderHistory::new());
+    data_feed_handler
+}
+
+#[test]
+fn messages_for_one_order_book_reach_the_same_shard_in_order() {
+    let sharded_handler = ShardedDataFeedHandler::new(SHARD_COUNT, 4, history_handler).unwrap();
+
+    for order_book_id in 0..ORDER_BOOK_COUNT {
+        let order_id = order_book_id as u64 + 1;
+        sharded_handler.route(message(&format!("A nanoseconds=0 order_id={order_id} order_book_id={order_book_id} side=B order_book_position=1 quantity=1 price=1000 exchange_order_type=4 lot_type=1"))).unwrap();
+    }
+
+    for nanoseconds in 1..=REPLACE_COUNT {
+        for order_book_id in 0..ORDER_BOOK_COUNT {
+            let order_id = order_book_id as u64 + 1;
+            let replace_message = message(&format!("U nanoseconds={nanoseconds} order_id={order_id} order_book_id={order_book_id} side=B new_order_book_position=1 quantity={} price=1000 exchange_order_type=4", nanoseconds + 1));
+
+            if nanoseconds % 2 == 0 {
+                sharded_handler.route_bin(&replace_message.to_bin().unwrap()).unwrap();
+            } else {
+                sharded_handler.route(replace_message).unwrap();
+            }
+        }
+    }
+
+    let shard_for = (0..ORDER_BOOK_COUNT).map(|order_book_id| sharded_handler.shard_for(order_book_id)).collect::<Vec<_>>();
+    let handlers = sharded_handler.join().unwrap();
+
+    for order_book_id in 0..ORDER_BOOK_COUNT {
+        let order_id = order_book_id as u64 + 1;
+
+        for (shard_index, handler) in handlers.iter().enumerate() {
+            let events = handler.order_history().unwrap().events(order_book_id, 'B', order_id);
+
+            if shard_index != shard_for[order_book_id as usize] {
+                assert!(events.is_none());
+                continue;
+            }
+
+            let quantities = events
+                .unwrap()
+                .iter()
+                .map(|event| match event.kind {
+                    OrderEventKind::Added { quantity, .. } | OrderEventKind::Replaced { quantity, .. } => quantity,
+                    _ => panic!("Unexpected event {:?}.", event.kind)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(quantities, (1..=REPLACE_COUNT as u64 + 1).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn route_bin_decodes_with_each_shards_validation_policy() {
+    let sharded_handler = ShardedDataFeedHandler::new(2, 4, |shard_index| {
+        let mut data_feed_handler = DataFeedHandler::new();
+
+        if shard_index == 1 {
+            data_feed_handler.set_validation_policy(ValidationPolicy::lenient());
+        }
+
+        data_feed_handler
+    }).unwrap();
+
+    let mut delete_bytes = message("D nanoseconds=1 order_id=1 order_book_id=1 side=B").to_bin().unwrap();
+    delete_bytes[order_delete_message::offsets::side] = b'X';
+
+    sharded_handler.route_bin(&delete_bytes).unwrap();
+
+    let handlers = sharded_handler.join().unwrap();
+    assert_eq!(handlers[1].validation_warning_counts().get("side"), Some(&1));
+
+    let sharded_handler = ShardedDataFeedHandler::new(2, 4, |_| DataFeedHandler::new()).unwrap();
+    sharded_handler.route_bin(&delete_bytes).unwrap();
+
+    assert!(sharded_handler.join().is_err());
+}
+
+#[test]
+fn a_failed_shard_reports_its_original_error() {
+    let sharded_handler = ShardedDataFeedHandler::new(2, 1, |_| DataFeedHandler::new()).unwrap();
+
+    let mut delete_bytes = message("D nanoseconds=1 order_id=1 order_book_id=1 side=B").to_bin().unwrap();
+    delete_bytes[order_delete_message::offsets::side] = b'X';
+
+    sharded_handler.route_bin(&delete_bytes).unwrap();
+
+    let error = (0..1_000)
+        .find_map(|_| sharded_handler.route(message("D nanoseconds=2 order_id=1 order_book_id=1 side=B")).err())
+        .unwrap();
+
+    assert_ne!(error.variant_name(), "ShardDisconnected");
+    assert_eq!(error.field_name(), Some("side"));
+
+    let sharded_handler = ShardedDataFeedHandler::new(2, 4, |_| DataFeedHandler::new()).unwrap();
+    sharded_handler.route_bin(&delete_bytes).unwrap();
+
+    let error = sharded_handler.join().err().unwrap();
+    assert_eq!(error.field_name(), Some("side"));
+}
\ No newline at end of file
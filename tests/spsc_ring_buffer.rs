@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use asx_itch_data_feed_handler::util::spsc_ring_buffer::{self, spsc_ring_buffer};
+
+#[test]
+fn values_wrap_around_the_ring_in_order() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(4);
+    let mut popped = Vec::new();
+
+    for round in 0..10 {
+        assert_eq!(producer.push_batch(&mut (round * 3..round * 3 + 3)), 3);
+        assert_eq!(consumer.pop_batch(8, |value| popped.push(value)), 3);
+    }
+
+    assert_eq!(popped, (0..30).collect::<Vec<_>>());
+
+    let statistics = producer.statistics();
+    assert_eq!((statistics.capacity, statistics.pushed_count, statistics.popped_count, statistics.full_count), (4, 30, 30, 0));
+    assert_eq!(statistics.high_watermark, 3);
+}
+
+#[test]
+fn high_watermark_tracks_the_occupancy_after_each_push() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(8);
+
+    for value in 0..20 {
+        producer.try_push(value).unwrap();
+        assert_eq!(consumer.try_pop(), Some(value));
+    }
+
+    assert_eq!(producer.statistics().high_watermark, 1);
+
+    for value in 0..5 {
+        producer.try_push(value).unwrap();
+    }
+
+    assert_eq!(producer.statistics().high_watermark, 5);
+}
+
+#[test]
+fn full_ring_rejects_pushes_and_counts_them() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(2);
+
+    producer.try_push(1).unwrap();
+    producer.try_push(2).unwrap();
+
+    assert_eq!(producer.try_push(3), Err(3));
+    assert_eq!(producer.push_batch(&mut (4..6)), 0);
+    assert_eq!(producer.statistics().full_count, 2);
+
+    assert_eq!(consumer.try_pop(), Some(1));
+    assert_eq!(producer.try_push(3), Ok(()));
+    assert_eq!(producer.statistics().high_watermark, 2);
+}
+
+#[test]
+fn push_returns_the_value_once_the_consumer_is_closed() {
+    let (mut producer, consumer) = spsc_ring_buffer::<Arc<()>>(2);
+    let value = Arc::new(());
+
+    producer.push(value.clone()).unwrap();
+    producer.push(value.clone()).unwrap();
+    drop(consumer);
+
+    assert!(producer.is_consumer_closed());
+    assert!(producer.push(value.clone()).is_err());
+
+    drop(producer);
+
+    assert_eq!(Arc::strong_count(&value), 1);
+}
+
+#[test]
+fn consumer_finishes_after_draining_a_closed_producer() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(4);
+
+    producer.try_push(7).unwrap();
+    drop(producer);
+
+    assert!(consumer.is_producer_closed());
+    assert!(!consumer.is_finished());
+    assert_eq!(consumer.try_pop(), Some(7));
+    assert!(consumer.is_finished());
+}
+
+#[test]
+fn high_watermark_stays_exact_while_the_consumer_keeps_up_in_batches() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(16);
+
+    for round in 0..50 {
+        assert_eq!(producer.push_batch(&mut (round * 4..round * 4 + 4)), 4);
+        assert_eq!(consumer.pop_batch(8, drop), 4);
+    }
+
+    let statistics = consumer.statistics();
+    assert_eq!((statistics.pushed_count, statistics.popped_count, statistics.high_watermark), (200, 200, 4));
+
+    assert_eq!(producer.push_batch(&mut (0..6)), 6);
+    assert_eq!(consumer.statistics().high_watermark, 6);
+}
+
+#[test]
+fn statistics_are_published_across_threads() {
+    let (mut producer, mut consumer) = spsc_ring_buffer::<u32>(8);
+
+    let producer_thread = std::thread::spawn(move || {
+        for value in 0..10_000 {
+            producer.push(value).unwrap();
+        }
+
+        producer.statistics()
+    });
+
+    let mut expected_value = 0;
+    let mut spins = 0;
+
+    while !consumer.is_finished() {
+        let popped_count = consumer.pop_batch(4, |value| {
+            assert_eq!(value, expected_value);
+            expected_value += 1;
+        });
+
+        if popped_count == 0 {
+            spsc_ring_buffer::backoff(&mut spins);
+        }
+    }
+
+    let producer_statistics = producer_thread.join().unwrap();
+    let consumer_statistics = consumer.statistics();
+
+    assert_eq!(expected_value, 10_000);
+    assert_eq!(producer_statistics.pushed_count, 10_000);
+    assert_eq!(consumer_statistics.pushed_count, 10_000);
+    assert_eq!(consumer_statistics.popped_count, 10_000);
+    assert_eq!(consumer_statistics.full_count, producer_statistics.full_count);
+    assert!((1..=8).contains(&consumer_statistics.high_watermark));
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, models::{message_decoder_registry::MessageDecoderRegistry, messages::add_order_no_participant_id_message, subscription_filter::SubscriptionFilter}, util::text_format};
+
+fn message_bytes(line: &str) -> Vec<u8> {
+    text_format::parse_message(line).unwrap().unwrap().to_bin().unwrap()
+}
+
+fn directory_line(order_book_id: u32, symbol: &str, isin: &str, financial_product: u8) -> String {
+    format!("R nanoseconds=5 order_book_id={order_book_id} symbol={symbol} long_name=\"{symbol} Ltd\" isin={isin} financial_product={financial_product} trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0")
+}
+
+fn add_order_line(order_book_id: u32, order_id: u64) -> String {
+    format!("A nanoseconds=10 order_id={order_id} order_book_id={order_book_id} side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1")
+}
+
+#[test]
+fn symbol_isin_and_financial_product_subscriptions_resolve_once_the_directory_arrives() {
+    let mut subscription_filter = SubscriptionFilter::new();
+    subscription_filter.subscribe_symbol("BHP");
+    subscription_filter.subscribe_isin("AU000000CBA7");
+    subscription_filter.subscribe_financial_product(5);
+
+    assert!((1..=4).all(|order_book_id| !subscription_filter.is_subscribed(order_book_id)));
+
+    for line in [directory_line(1, "BHP", "AU000000BHP4", 1), directory_line(2, "CBA", "AU000000CBA7", 1), directory_line(3, "XJO", "AU000000XJO0", 5), directory_line(4, "NAB", "AU000000NAB4", 1)] {
+        subscription_filter.process_directory_message(&text_format::parse_message(&line).unwrap().unwrap());
+    }
+
+    let mut subscribed_order_book_ids = subscription_filter.subscribed_order_book_ids().collect::<Vec<_>>();
+    subscribed_order_book_ids.sort();
+
+    assert_eq!(subscribed_order_book_ids, [1, 2, 3]);
+    assert!(subscription_filter.accepts(&text_format::parse_message(&add_order_line(3, 1)).unwrap().unwrap()));
+    assert!(!subscription_filter.accepts(&text_format::parse_message(&add_order_line(4, 1)).unwrap().unwrap()));
+    assert!(subscription_filter.accepts(&text_format::parse_message("T second=1").unwrap().unwrap()));
+
+    subscription_filter.unsubscribe_isin("AU000000CBA7");
+    subscription_filter.unsubscribe_financial_product(5);
+
+    assert_eq!(subscription_filter.subscribed_order_book_ids().collect::<Vec<_>>(), [1]);
+
+    subscription_filter.clear_directory();
+
+    assert!(!subscription_filter.is_subscribed(1));
+}
+
+#[test]
+fn subscriptions_can_be_added_and_removed_while_the_handler_runs() {
+    let mut data_feed_handler = DataFeedHandler::new();
+    let subscription_handle = data_feed_handler.enable_subscription_filter(SubscriptionFilter::new());
+
+    data_feed_handler.process_bin(&message_bytes(&directory_line(1, "BHP", "AU000000BHP4", 1))).unwrap();
+    data_feed_handler.process_bin(&message_bytes(&directory_line(2, "CBA", "AU000000CBA7", 1))).unwrap();
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(1, 1))).unwrap();
+
+    assert_eq!(data_feed_handler.filtered_message_count(), 3);
+
+    let update_thread_handle = subscription_handle.clone();
+    std::thread::spawn(move || update_thread_handle.subscribe_symbol("BHP")).join().unwrap();
+
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(1, 2))).unwrap();
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(2, 3))).unwrap();
+
+    assert_eq!(data_feed_handler.filtered_message_count(), 4);
+    assert!(data_feed_handler.subscription_filter().unwrap().is_subscribed(1));
+
+    subscription_handle.subscribe_order_book_id(2);
+    subscription_handle.unsubscribe_symbol("BHP");
+
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(1, 4))).unwrap();
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(2, 5))).unwrap();
+
+    assert_eq!(data_feed_handler.filtered_message_count(), 5);
+    assert_eq!(data_feed_handler.subscription_filter().unwrap().subscribed_order_book_ids().collect::<Vec<_>>(), [2]);
+
+    subscription_handle.clear_subscriptions();
+    data_feed_handler.process_bin(&message_bytes(&add_order_line(2, 6))).unwrap();
+
+    assert_eq!(data_feed_handler.filtered_message_count(), 6);
+    assert_eq!(data_feed_handler.session_statistics().message_counts_by_type.get(&'A'), Some(&2));
+}
+
+#[test]
+fn unsubscribed_binary_messages_are_dropped_without_a_full_decode() {
+    let mut subscription_filter = SubscriptionFilter::new();
+    subscription_filter.subscribe_order_book_id(1);
+
+    let mut unsubscribed_add_order = message_bytes(&add_order_line(2, 1));
+    unsubscribed_add_order[add_order_no_participant_id_message::offsets::side] = b'X';
+
+    let message_decoders = MessageDecoderRegistry::new();
+
+    assert!(subscription_filter.accepts_bin(&message_bytes(&add_order_line(1, 1)), &message_decoders));
+    assert!(!subscription_filter.accepts_bin(&unsubscribed_add_order, &message_decoders));
+    assert!(!subscription_filter.accepts_bin(&unsubscribed_add_order[..add_order_no_participant_id_message::offsets::order_book_id + 4], &message_decoders));
+    assert!(subscription_filter.accepts_bin(&message_bytes("T second=1"), &message_decoders));
+
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.enable_subscription_filter(subscription_filter);
+    data_feed_handler.process_bin(&unsubscribed_add_order).unwrap();
+    data_feed_handler.process_bin(&unsubscribed_add_order[..add_order_no_participant_id_message::offsets::order_book_id + 4]).unwrap();
+
+    assert_eq!(data_feed_handler.filtered_message_count(), 2);
+    assert_eq!(data_feed_handler.validation_warning_count(), 0);
+
+    let mut subscribed_add_order = message_bytes(&add_order_line(1, 2));
+    subscribed_add_order[add_order_no_participant_id_message::offsets::side] = b'X';
+
+    assert!(data_feed_handler.process_bin(&subscribed_add_order).is_err());
+}
\ No newline at end of file
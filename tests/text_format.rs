@@ -0,0 +1,72 @@
+use asx_itch_data_feed_handler::{enums::itch_message::ItchMessage, util::text_format};
+
+const MESSAGE_LINES: [&str; 14] = [
+    "A nanoseconds=1 order_id=11 order_book_id=7 side=B order_book_position=2 quantity=100 price=1050 exchange_order_type=4 lot_type=1",
+    "F nanoseconds=2 order_id=12 order_book_id=7 side=S order_book_position=1 quantity=200 price=-25 exchange_order_type=8 lot_type=1 participant_id=PARTY1",
+    "M nanoseconds=3 order_book_id=8 symbol=BHP_SPREAD long_name=\"BHP calendar spread\" isin=AU000000BHP4 financial_product=5 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0 leg_1_symbol=BHPH5 leg_1_side=B leg_1_ratio=1 leg_2_symbol=BHPM5 leg_2_side=C leg_2_ratio=0 leg_3_symbol=\"\" leg_3_side=? leg_3_ratio=0 leg_4_symbol=\"\" leg_4_side=? leg_4_ratio=0",
+    "Z nanoseconds=4 order_book_id=7 bid_quantity=300 ask_quantity=250 equilibrium_price=1040 best_bid_price=1045 best_ask_price=1035 best_bid_quantity=100 best_ask_quantity=90",
+    "R nanoseconds=5 order_book_id=7 symbol=BHP long_name=\"BHP Group\" isin=AU000000BHP4 financial_product=1 trading_currency=AUD number_of_decimals_in_price=2 number_of_decimals_in_nominal_value=0 odd_lot_size=1 round_lot_size=1 block_lot_size=100 nominal_value=0",
+    "O nanoseconds=6 order_book_id=7 state_name=CONTINUOUS",
+    "D nanoseconds=7 order_id=11 order_book_id=7 side=B",
+    "E nanoseconds=8 order_id=11 order_book_id=7 side=B executed_quantity=40 match_id=79228162514264337593543950335 owner_participant_id=PARTY1 counterparty_participant_id=PARTY2",
+    "C nanoseconds=9 order_id=11 order_book_id=7 side=B executed_quantity=10 match_id=2 owner_participant_id=PARTY1 counterparty_participant_id=PARTY2 trade_price=1049 occurred_at_cross=N printable=Y",
+    "U nanoseconds=10 order_id=11 order_book_id=7 side=B new_order_book_position=3 quantity=60 price=1051 exchange_order_type=4",
+    "T second=36000",
+    "S nanoseconds=11 event_code=O",
+    "L nanoseconds=12 order_book_id=7 tick_size=5 price_from=0 price_to=100000",
+    "P nanoseconds=13 match_id=3 side=S quantity=70 order_book_id=7 trade_price=1048 owner_participant_id=PARTY3 counterparty_participant_id=PARTY4 printable=Y occurred_at_cross=Y"
+];
+
+#[test]
+fn every_message_type_round_trips_through_text() {
+    let mut message_types = Vec::new();
+
+    for line in MESSAGE_LINES {
+        let message = text_format::parse_message(line).unwrap().unwrap();
+        let formatted_line = text_format::format_message(&message).unwrap();
+
+        assert_eq!(text_format::parse_message(&formatted_line).unwrap().as_ref(), Some(&message), "{formatted_line}");
+        assert_eq!(ItchMessage::from_bin(&message.to_bin().unwrap()).unwrap(), message);
+
+        message_types.push(message.message_type() as u8);
+    }
+
+    message_types.sort();
+
+    let mut expected_message_types = ItchMessage::message_types().collect::<Vec<_>>();
+    expected_message_types.sort();
+
+    assert_eq!(message_types, expected_message_types);
+}
+
+#[test]
+fn every_formatted_key_names_a_single_field() {
+    for line in MESSAGE_LINES {
+        let message = text_format::parse_message(line).unwrap().unwrap();
+        let fields = message.fields();
+
+        for field in fields {
+            let alias = text_format::alias_for(field.name);
+            let matching_fields = fields.iter().filter(|other| other.name == alias || text_format::alias_for(other.name) == alias).count();
+
+            assert_eq!(matching_fields, 1, "'{alias}' in '{}'", message.message_type());
+        }
+    }
+}
+
+#[test]
+fn aliases_are_formatted_and_parsed() {
+    let message = text_format::parse_message(MESSAGE_LINES[8]).unwrap().unwrap();
+
+    assert_eq!(
+        text_format::format_message(&message).unwrap(),
+        "C ts=9 oid=11 book=7 side=B exec_qty=10 match_id=2 owner_participant_id=PARTY1 counterparty_participant_id=PARTY2 trade_px=1049 occurred_at_cross=N printable=Y"
+    );
+
+    assert_eq!(
+        text_format::parse_message("U ts=10 oid=11 book=7 side=B new_pos=3 qty=60 px=1051 type=4").unwrap(),
+        text_format::parse_message(MESSAGE_LINES[9]).unwrap()
+    );
+
+    assert!(text_format::parse_message("U ts=10 oid=11 book=7 side=B pos=3 qty=60 px=1051 type=4").is_err());
+}
\ No newline at end of file
@@ -0,0 +1,174 @@
+use std::{io::Cursor, sync::{Arc, Mutex}};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{field_type::FieldType, field_value::FieldValue, itch_message::ItchMessage}, models::{capture::{capture_filter::CaptureFilter, capture_reader::CaptureReader, raw_stream_writer::RawStreamWriter}, custom_message_decoder::CustomMessageDecoder, field_layout::FieldLayout, field_validator::FieldValidator, message_decoder_registry::MessageDecoderRegistry, validation_policy::ValidationPolicy}, sharded_data_feed_handler::ShardedDataFeedHandler, traits::json::TJson, util::{json_utils, text_format}};
+
+static SPREAD_UPDATE_FIELDS: [FieldLayout; 4] = [
+    FieldLayout { name: "message_type", field_type: FieldType::Alpha, offset: 0, length: 1 },
+    FieldLayout { name: "nanoseconds", field_type: FieldType::Numeric, offset: 1, length: 4 },
+    FieldLayout { name: "order_book_id", field_type: FieldType::Numeric, offset: 5, length: 4 },
+    FieldLayout { name: "spread", field_type: FieldType::Price, offset: 9, length: 4 }
+];
+
+static QUOTE_COUNT_FIELDS: [FieldLayout; 5] = [
+    FieldLayout { name: "message_type", field_type: FieldType::Alpha, offset: 0, length: 1 },
+    FieldLayout { name: "nanoseconds", field_type: FieldType::Numeric, offset: 1, length: 4 },
+    FieldLayout { name: "bid_count", field_type: FieldType::Numeric, offset: 5, length: 2 },
+    FieldLayout { name: "ask_count", field_type: FieldType::Numeric, offset: 7, length: 2 },
+    FieldLayout { name: "quote_count", field_type: FieldType::Numeric, offset: 9, length: 4 }
+];
+
+fn spread_update_decoders(message_type: u8) -> MessageDecoderRegistry {
+    let mut message_decoders = MessageDecoderRegistry::new();
+    message_decoders.register(CustomMessageDecoder::new(message_type, "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).unwrap();
+    message_decoders
+}
+
+fn spread_update_bytes(message_type: u8, order_book_id: u32, spread: i32) -> Vec<u8> {
+    let mut binary = vec![message_type];
+    binary.extend_from_slice(&500u32.to_be_bytes());
+    binary.extend_from_slice(&order_book_id.to_be_bytes());
+    binary.extend_from_slice(&spread.to_be_bytes());
+    binary
+}
+
+fn write_raw_capture(messages: &[Vec<u8>]) -> Vec<u8> {
+    let mut raw_stream_writer = RawStreamWriter::new(Vec::new());
+
+    for message in messages {
+        raw_stream_writer.write_bin(message).unwrap();
+    }
+
+    raw_stream_writer.finish().unwrap()
+}
+
+#[test]
+fn unknown_message_types_in_a_capture_are_skipped_counted_and_passed_to_the_callback() {
+    let message_decoders = spread_update_decoders(b'V');
+
+    let capture = write_raw_capture(&[
+        vec![b'S', 0, 0, 0, 1, b'O'],
+        b"Q\x01\x02\x03".to_vec(),
+        spread_update_bytes(b'V', 7, 25),
+        b"Q\x04".to_vec(),
+        vec![b'S', 0, 0, 0, 2, b'C']
+    ]);
+
+    let messages = CaptureReader::new(Box::new(Cursor::new(capture)), CaptureFilter::default())
+        .unwrap()
+        .map(|captured_message| captured_message.unwrap().decode_with(&mut FieldValidator::new(&ValidationPolicy::strict(), &mut Vec::new()), &message_decoders).unwrap())
+        .collect::<Vec<_>>();
+
+    let message_types = messages.iter().map(ItchMessage::message_type).collect::<String>();
+    assert_eq!(message_types, "SQVQS");
+
+    let ItchMessage::Unknown(unknown_message) = &messages[1] else {
+        panic!("Expected an unknown message but decoded {:?}.", messages[1]);
+    };
+    assert_eq!(unknown_message.bytes, b"Q\x01\x02\x03");
+
+    let ItchMessage::Custom(custom_message) = &messages[2] else {
+        panic!("Expected a custom message but decoded {:?}.", messages[2]);
+    };
+    assert_eq!(custom_message.name, "spread_update");
+    assert_eq!(custom_message.field("spread"), Some(FieldValue::Price(25)));
+    assert_eq!(messages[2].order_book_id(), Some(7));
+    assert_eq!(messages[2].nanoseconds(), Some(500));
+
+    let unknown_messages = Arc::new(Mutex::new(Vec::new()));
+    let callback_unknown_messages = Arc::clone(&unknown_messages);
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.register_message_decoder(CustomMessageDecoder::new(b'V', "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).unwrap();
+    data_feed_handler.set_unknown_message_callback(move |unknown_message| callback_unknown_messages.lock().unwrap().push(unknown_message.bytes.clone()));
+
+    for message in messages.iter() {
+        data_feed_handler.process_bin(&message.to_bin().unwrap()).unwrap();
+    }
+
+    assert_eq!(data_feed_handler.unknown_message_count(), 2);
+    assert_eq!(data_feed_handler.unknown_message_counts().get(&'Q'), Some(&2));
+    assert_eq!(*unknown_messages.lock().unwrap(), vec![b"Q\x01\x02\x03".to_vec(), b"Q\x04".to_vec()]);
+    let session_summaries = data_feed_handler.session_summaries();
+    assert_eq!(session_summaries.len(), 1);
+    assert_eq!(session_summaries[0].statistics.message_counts_by_type.get(&'V'), Some(&1));
+    assert_eq!(session_summaries[0].statistics.message_counts_by_type.get(&'Q'), None);
+}
+
+#[test]
+fn custom_and_unknown_messages_round_trip_through_text_and_json() {
+    let message_decoders = spread_update_decoders(b'W');
+
+    for binary in [spread_update_bytes(b'W', 9, -3), b"Y\x00\xff".to_vec()] {
+        let message = message_decoders.decode(&binary).unwrap();
+        let line = text_format::format_message(&message).unwrap();
+
+        assert_eq!(text_format::parse_message_with(&line, &message_decoders).unwrap(), Some(message.clone()), "{line}");
+        assert_eq!(message.to_bin().unwrap(), binary);
+
+        let json = message.to_json().unwrap().to_string();
+        assert_eq!(message_decoders.decode_json(&json_utils::parse(&json).unwrap()).unwrap(), message, "{json}");
+    }
+
+    assert_eq!(text_format::format_message(&ItchMessage::from_bin(b"Y\x00\xff").unwrap()).unwrap(), "Y bytes=5900ff");
+    assert!(text_format::parse_message("Y bytes=5a00").is_err());
+
+    let unknown_json = json_utils::parse(r#"{"message_type":"W","bytes":[87,0,0,1,244,0,0,0,9,255,255,255,253]}"#).unwrap();
+    assert_eq!(message_decoders.decode_json(&unknown_json).unwrap(), message_decoders.decode(&spread_update_bytes(b'W', 9, -3)).unwrap());
+    assert!(matches!(ItchMessage::from_json(&unknown_json), Ok(ItchMessage::Unknown(_))));
+}
+
+#[test]
+fn custom_decoder_registration_rejects_built_in_and_duplicate_types() {
+    assert!(CustomMessageDecoder::new(b'A', "add_order", &SPREAD_UPDATE_FIELDS).is_err());
+
+    let mut message_decoders = spread_update_decoders(b'X');
+    assert!(message_decoders.register(CustomMessageDecoder::new(b'X', "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).is_err());
+    assert!(message_decoders.decode(&spread_update_bytes(b'X', 1, 1)[..12]).is_err());
+
+    assert!(message_decoders.unregister(b'X').is_some());
+    assert!(matches!(message_decoders.decode(&spread_update_bytes(b'X', 1, 1)), Ok(ItchMessage::Unknown(_))));
+}
+
+#[test]
+fn handlers_decode_the_same_message_type_with_their_own_decoders() {
+    let mut spread_handler = DataFeedHandler::new();
+    spread_handler.register_message_decoder(CustomMessageDecoder::new(b'V', "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).unwrap();
+
+    let mut quote_handler = DataFeedHandler::new();
+    quote_handler.register_message_decoder(CustomMessageDecoder::new(b'V', "quote_count", &QUOTE_COUNT_FIELDS).unwrap()).unwrap();
+
+    let plain_handler = DataFeedHandler::new();
+    let binary = spread_update_bytes(b'V', 7, 25);
+
+    let spread_message = spread_handler.decode_bin(&binary).unwrap();
+    assert_eq!(spread_message.name(), "spread_update");
+    assert_eq!(spread_message.order_book_id(), Some(7));
+    assert_eq!(spread_handler.message_decoders().peek_order_book_id(&binary), Some(7));
+
+    let quote_message = quote_handler.decode_bin(&binary).unwrap();
+    assert_eq!(quote_message.name(), "quote_count");
+    assert_eq!(quote_message.order_book_id(), None);
+    assert_eq!(quote_handler.message_decoders().peek_order_book_id(&binary), None);
+
+    assert!(plain_handler.message_decoders().decoder(b'V').is_none());
+    assert!(matches!(ItchMessage::from_bin(&binary), Ok(ItchMessage::Unknown(_))));
+    assert!(text_format::parse_message("V nanoseconds=500 order_book_id=7 spread=25").is_err());
+}
+
+#[test]
+fn sharded_handlers_route_custom_messages_by_their_order_book_id() {
+    let sharded_data_feed_handler = ShardedDataFeedHandler::new(2, 16, |_| {
+        let mut data_feed_handler = DataFeedHandler::new();
+        data_feed_handler.register_message_decoder(CustomMessageDecoder::new(b'V', "spread_update", &SPREAD_UPDATE_FIELDS).unwrap()).unwrap();
+        data_feed_handler
+    }).unwrap();
+
+    sharded_data_feed_handler.route_bin(&spread_update_bytes(b'V', 3, 25)).unwrap();
+    sharded_data_feed_handler.route_bin(&spread_update_bytes(b'V', 4, 26)).unwrap();
+    sharded_data_feed_handler.route_bin(&spread_update_bytes(b'V', 5, 27)).unwrap();
+
+    let handlers = sharded_data_feed_handler.join().unwrap();
+
+    assert_eq!(handlers[0].session_statistics().message_counts_by_type.get(&'V'), Some(&1));
+    assert_eq!(handlers[1].session_statistics().message_counts_by_type.get(&'V'), Some(&2));
+    assert_eq!(handlers.iter().map(DataFeedHandler::unknown_message_count).sum::<u64>(), 0);
+}
\ No newline at end of file
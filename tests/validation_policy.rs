@@ -0,0 +1,137 @@
+use std::sync::{Arc, Mutex};
+
+use asx_itch_data_feed_handler::{data_feed_handler::DataFeedHandler, enums::{data_feed_error::DataFeedErrorKind, itch_message::ItchMessage, validation_category::ValidationCategory, validation_mode::ValidationMode}, models::{error_context::ErrorContext, field_validator::FieldValidator, messages::add_order_no_participant_id_message::offsets, validation_policy::ValidationPolicy}, util::text_format};
+
+fn add_order_bytes(side: u8, exchange_order_type: u16, lot_type: u8) -> Vec<u8> {
+    let mut binary = text_format::parse_message("A nanoseconds=1 order_id=1 order_book_id=7 side=B order_book_position=1 quantity=100 price=1000 exchange_order_type=4 lot_type=1")
+        .unwrap()
+        .unwrap()
+        .to_bin()
+        .unwrap();
+
+    binary[offsets::side] = side;
+    binary[offsets::exchange_order_type..offsets::exchange_order_type + 2].copy_from_slice(&exchange_order_type.to_be_bytes());
+    binary[offsets::lot_type] = lot_type;
+    binary
+}
+
+fn decode(binary_data: &[u8], policy: &ValidationPolicy) -> (Result<ItchMessage, &'static str>, Vec<(&'static str, ErrorContext)>) {
+    let mut warnings = Vec::new();
+    let result = ItchMessage::from_bin_with(binary_data, &mut FieldValidator::new(policy, &mut warnings)).map_err(|error| error.variant_name());
+
+    (result, warnings.iter().map(|warning| (warning.variant_name(), warning.context())).collect())
+}
+
+fn field_context(field_name: &'static str) -> ErrorContext {
+    ErrorContext {
+        message_type: Some('A'),
+        field_name: Some(field_name),
+        ..ErrorContext::default()
+    }
+}
+
+#[test]
+fn strict_validation_rejects_the_first_invalid_field_with_its_context() {
+    let mut warnings = Vec::new();
+    let error = ItchMessage::from_bin_with(&add_order_bytes(b'X', 1, 9), &mut FieldValidator::new(&ValidationPolicy::strict(), &mut warnings)).unwrap_err();
+
+    assert!(matches!(error.kind(), DataFeedErrorKind::InvalidSideValue('X')));
+    assert_eq!(error.context(), field_context("side"));
+    assert!(warnings.is_empty());
+
+    assert!(ItchMessage::from_bin(&add_order_bytes(b'B', 1, 1)).is_err_and(|error| error.field_name() == Some("exchange_order_type")));
+    assert!(ItchMessage::from_bin(&add_order_bytes(b'B', 4, 9)).is_err_and(|error| error.field_name() == Some("lot_type")));
+    assert!(ItchMessage::from_bin(&add_order_bytes(b'S', 4 | 8192, 0)).is_ok());
+}
+
+#[test]
+fn lenient_validation_keeps_every_invalid_field_as_a_warning() {
+    let binary = add_order_bytes(b'X', 1, 9);
+    let (result, warnings) = decode(&binary, &ValidationPolicy::lenient());
+
+    assert_eq!(result.unwrap().to_bin().unwrap(), binary);
+    assert_eq!(warnings, [
+        ("InvalidSideValue", field_context("side")),
+        ("InvalidExchangeOrderTypeValue", field_context("exchange_order_type")),
+        ("InvalidLotTypeValue", field_context("lot_type"))
+    ]);
+}
+
+#[test]
+fn off_mode_skips_validation_without_warnings() {
+    let binary = add_order_bytes(b'X', 1, 9);
+    let (result, warnings) = decode(&binary, &ValidationPolicy::off());
+
+    assert_eq!(result.unwrap().to_bin().unwrap(), binary);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn category_overrides_apply_only_to_their_own_fields() {
+    let policy = ValidationPolicy::strict().with(ValidationCategory::ExchangeOrderType, ValidationMode::Lenient);
+
+    let (result, warnings) = decode(&add_order_bytes(b'B', 1, 1), &policy);
+    assert!(result.is_ok());
+    assert_eq!(warnings, [("InvalidExchangeOrderTypeValue", field_context("exchange_order_type"))]);
+
+    let (result, warnings) = decode(&add_order_bytes(b'B', 1, 9), &policy);
+    assert_eq!(result.unwrap_err(), "InvalidLotTypeValue");
+    assert_eq!(warnings.len(), 1);
+
+    let (result, warnings) = decode(&add_order_bytes(b'X', 4, 1), &policy);
+    assert_eq!(result.unwrap_err(), "InvalidSideValue");
+    assert!(warnings.is_empty());
+
+    let policy = ValidationPolicy::strict().with(ValidationCategory::ExchangeOrderType, ValidationMode::Off);
+    let (result, warnings) = decode(&add_order_bytes(b'B', 1, 1), &policy);
+    assert!(result.is_ok());
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn policies_parse_from_uniform_modes_and_per_category_overrides() {
+    assert_eq!("".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::strict());
+    assert_eq!("lenient".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::lenient());
+    assert_eq!(" off ".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::off());
+    assert_eq!("exchange_order_type=lenient".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::strict().with(ValidationCategory::ExchangeOrderType, ValidationMode::Lenient));
+    assert_eq!("off, side = strict, lot_type=lenient".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::off().with(ValidationCategory::Side, ValidationMode::Strict).with(ValidationCategory::LotType, ValidationMode::Lenient));
+    assert_eq!("side=off,lenient".parse::<ValidationPolicy>().unwrap(), ValidationPolicy::lenient());
+
+    for category in ValidationCategory::ALL {
+        let policy = format!("lenient,{category}=off").parse::<ValidationPolicy>().unwrap();
+        assert!(ValidationCategory::ALL.iter().all(|other| policy.mode(*other) == if *other == category { ValidationMode::Off } else { ValidationMode::Lenient }));
+    }
+
+    for text in ["loose", "side=loose", "colour=strict", "side="] {
+        assert!(text.parse::<ValidationPolicy>().is_err_and(|error| matches!(error.kind(), DataFeedErrorKind::ParseError(_))), "{text}");
+    }
+}
+
+#[test]
+fn handlers_count_lenient_warnings_and_pass_them_to_the_warning_callback() {
+    let reported_warnings = Arc::new(Mutex::new(Vec::new()));
+    let callback_warnings = Arc::clone(&reported_warnings);
+
+    let mut data_feed_handler = DataFeedHandler::new();
+    data_feed_handler.set_validation_policy("lenient,side=strict".parse().unwrap());
+    data_feed_handler.set_validation_warning_callback(move |warning| callback_warnings.lock().unwrap().push((warning.variant_name(), warning.context())));
+
+    data_feed_handler.process_bin(&add_order_bytes(b'B', 1, 9)).unwrap();
+    data_feed_handler.process_bin(&add_order_bytes(b'B', 1, 1)).unwrap();
+    assert!(data_feed_handler.process_bin(&add_order_bytes(b'X', 4, 1)).is_err());
+
+    assert_eq!(data_feed_handler.validation_warning_count(), 3);
+    assert_eq!(data_feed_handler.validation_warning_counts().get("exchange_order_type"), Some(&2));
+    assert_eq!(data_feed_handler.validation_warning_counts().get("lot_type"), Some(&1));
+    assert_eq!(*reported_warnings.lock().unwrap(), [
+        ("InvalidExchangeOrderTypeValue", field_context("exchange_order_type")),
+        ("InvalidLotTypeValue", field_context("lot_type")),
+        ("InvalidExchangeOrderTypeValue", field_context("exchange_order_type"))
+    ]);
+
+    data_feed_handler.clear_validation_warning_callback();
+    data_feed_handler.process_bin(&add_order_bytes(b'B', 1, 1)).unwrap();
+
+    assert_eq!(data_feed_handler.validation_warning_count(), 4);
+    assert_eq!(reported_warnings.lock().unwrap().len(), 3);
+}
\ No newline at end of file